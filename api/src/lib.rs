@@ -1,9 +1,15 @@
-pub use self::client::Client;
+pub use self::client::{Client, ClientConfig, StatusError};
+pub use self::provider::DataProvider;
+pub use self::replay::Replay;
 
 mod client;
 pub mod model;
+mod provider;
+mod record;
+mod replay;
+pub mod stream;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Interval {
     Minute1,
     Minute2,
@@ -44,6 +50,34 @@ impl std::fmt::Display for Interval {
     }
 }
 
+impl std::str::FromStr for Interval {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Interval::*;
+
+        match s {
+            "1m" => Ok(Minute1),
+            "2m" => Ok(Minute2),
+            "5m" => Ok(Minute5),
+            "15m" => Ok(Minute15),
+            "30m" => Ok(Minute30),
+            "60m" => Ok(Minute60),
+            "90m" => Ok(Minute90),
+            "1h" => Ok(Hour1),
+            "1d" => Ok(Day1),
+            "5d" => Ok(Day5),
+            "1wk" => Ok(Week1),
+            "1mo" => Ok(Month1),
+            "3mo" => Ok(Month3),
+            _ => Err(
+                "Valid intervals are: '1m', '2m', '5m', '15m', '30m', '60m', '90m', '1h', '1d', \
+                 '5d', '1wk', '1mo', '3mo'",
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum Range {
     Day1,
@@ -80,3 +114,33 @@ impl std::fmt::Display for Range {
         write!(f, "{}", s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_from_str() {
+        let intervals = [
+            Interval::Minute1,
+            Interval::Minute2,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Minute60,
+            Interval::Minute90,
+            Interval::Hour1,
+            Interval::Day1,
+            Interval::Day5,
+            Interval::Week1,
+            Interval::Month1,
+            Interval::Month3,
+        ];
+
+        for interval in intervals {
+            assert_eq!(interval.to_string().parse(), Ok(interval));
+        }
+
+        assert!("1w".parse::<Interval>().is_err());
+    }
+}
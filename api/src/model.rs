@@ -36,6 +36,8 @@ pub struct Error {
 #[serde(rename_all = "camelCase")]
 pub struct ChartData {
     pub meta: ChartMeta,
+    // Left out when there's no data for the requested period
+    #[serde(default)]
     pub timestamp: Vec<i64>,
     pub indicators: ChartIndicators,
 }
@@ -89,15 +91,15 @@ pub struct ChartAdjClose {
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChartQuote {
-    #[serde(deserialize_with = "deserialize_vec")]
+    #[serde(default, deserialize_with = "deserialize_vec")]
     pub close: Vec<f64>,
-    #[serde(deserialize_with = "deserialize_vec")]
+    #[serde(default, deserialize_with = "deserialize_vec")]
     pub volume: Vec<u64>,
-    #[serde(deserialize_with = "deserialize_vec")]
+    #[serde(default, deserialize_with = "deserialize_vec")]
     pub high: Vec<f64>,
-    #[serde(deserialize_with = "deserialize_vec")]
+    #[serde(default, deserialize_with = "deserialize_vec")]
     pub low: Vec<f64>,
-    #[serde(deserialize_with = "deserialize_vec")]
+    #[serde(default, deserialize_with = "deserialize_vec")]
     pub open: Vec<f64>,
 }
 
@@ -145,6 +147,8 @@ pub struct CompanyPrice {
     pub regular_market_previous_close: CompanyMarketPrice,
     pub post_market_price: OptionalCompanyMarketPrice,
     pub regular_market_volume: OptionalCompanyMarketPrice,
+    #[serde(default)]
+    pub average_daily_volume_3_month: OptionalCompanyMarketPrice,
     pub currency: Option<String>,
 }
 
@@ -156,7 +160,7 @@ pub struct CompanyMarketPrice {
     pub fmt: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionalCompanyMarketPrice {
     #[serde(rename = "raw")]
@@ -189,11 +193,14 @@ pub struct OptionsHeader {
 #[serde(rename_all = "camelCase")]
 pub struct OptionsQuote {
     pub regular_market_price: f64,
+    #[serde(default)]
+    pub regular_market_time: Option<i64>,
 }
 
 impl Hash for OptionsQuote {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.regular_market_price.to_bits().hash(state);
+        self.regular_market_time.hash(state);
     }
 }
 
@@ -238,6 +245,49 @@ impl Hash for OptionsContract {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Quotes {
+    pub quote_response: QuotesStatus,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotesStatus {
+    pub result: Option<Vec<QuoteData>>,
+    pub error: Option<Error>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteData {
+    pub symbol: String,
+    pub regular_market_price: Option<f64>,
+    pub post_market_price: Option<f64>,
+    pub regular_market_volume: Option<u64>,
+    pub average_daily_volume_3_month: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolSearch {
+    #[serde(default)]
+    pub quotes: Vec<SearchResult>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct SearchResult {
+    pub symbol: String,
+    #[serde(rename = "shortname")]
+    pub short_name: Option<String>,
+    #[serde(rename = "longname")]
+    pub long_name: Option<String>,
+    #[serde(rename = "exchDisp")]
+    pub exchange: Option<String>,
+    #[serde(rename = "typeDisp")]
+    pub quote_type: Option<String>,
+}
+
 fn deserialize_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
 where
     D: Deserializer<'de>,
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use futures::future::BoxFuture;
+
+use crate::model::{ChartData, CompanyData, OptionsHeader, QuoteData, SearchResult};
+use crate::{Interval, Range};
+
+/// Source of market data consumed by tickrs.
+///
+/// Implementations are responsible for any authentication their backend
+/// requires, so callers only ever supply the symbol and query parameters.
+pub trait DataProvider: Send + Sync {
+    /// Historical prices for `symbol` over `range`, sampled every `interval`
+    fn get_chart_data<'a>(
+        &'a self,
+        symbol: &'a str,
+        interval: Interval,
+        range: Range,
+        include_pre_post: bool,
+    ) -> BoxFuture<'a, Result<ChartData>>;
+
+    /// Historical prices for `symbol` from `since` (unix timestamp) up to now,
+    /// sampled every `interval`. Used to fetch just the data that's missing
+    /// from a cached series.
+    fn get_chart_data_since<'a>(
+        &'a self,
+        symbol: &'a str,
+        interval: Interval,
+        since: i64,
+        include_pre_post: bool,
+    ) -> BoxFuture<'a, Result<ChartData>>;
+
+    /// Historical prices for `symbol` between `start` and `end` (unix
+    /// timestamps), sampled every `interval`
+    fn get_chart_data_between<'a>(
+        &'a self,
+        symbol: &'a str,
+        interval: Interval,
+        start: i64,
+        end: i64,
+        include_pre_post: bool,
+    ) -> BoxFuture<'a, Result<ChartData>>;
+
+    /// Quote and company profile for `symbol`
+    fn get_company_data<'a>(&'a self, symbol: &'a str) -> BoxFuture<'a, Result<CompanyData>>;
+
+    /// Latest quote for each of `symbols`, fetched together. Unknown symbols
+    /// are left out of the result rather than failing the whole batch.
+    fn get_quotes<'a>(&'a self, symbols: &'a [String]) -> BoxFuture<'a, Result<Vec<QuoteData>>>;
+
+    /// All available options expiration dates for `symbol`
+    fn get_options_expiration_dates<'a>(
+        &'a self,
+        symbol: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<i64>>>;
+
+    /// Options chain for `symbol` expiring on `expiration_date`
+    fn get_options_for_expiration_date<'a>(
+        &'a self,
+        symbol: &'a str,
+        expiration_date: i64,
+    ) -> BoxFuture<'a, Result<OptionsHeader>>;
+
+    /// Symbols matching `query` by ticker or company name, best match first
+    fn search_symbols<'a>(&'a self, query: &'a str) -> BoxFuture<'a, Result<Vec<SearchResult>>>;
+}
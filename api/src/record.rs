@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use http::{StatusCode, Uri};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{error_description, StatusError};
+
+/// Query parameters that identify the user and shouldn't end up in a recording
+const REDACTED_PARAMS: [&str; 1] = ["crumb"];
+
+/// A single API response captured to disk along with the request that
+/// produced it
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Recording<T> {
+    pub timestamp: i64,
+    /// HTTP status of the response, so errors can be replayed too
+    #[serde(default = "default_status")]
+    pub status: u16,
+    pub path: String,
+    pub params: BTreeMap<String, String>,
+    pub response: T,
+}
+
+/// Recordings made before the status was captured only ever held successful
+/// responses
+fn default_status() -> u16 {
+    200
+}
+
+/// Writes every response to a session archive directory, one file per response
+#[derive(Debug)]
+pub(crate) struct Recorder {
+    dir: PathBuf,
+    sequence: AtomicU64,
+}
+
+impl Recorder {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Recorder {
+            dir: dir.into(),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    pub fn record(&self, url: &Uri, status: StatusCode, body: &[u8]) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+
+        let path = url.path().trim_start_matches('/').to_string();
+
+        let params = url
+            .query()
+            .map(serde_urlencoded::from_str::<Vec<(String, String)>>)
+            .transpose()?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(key, _)| !REDACTED_PARAMS.contains(&key.as_str()))
+            .collect();
+
+        // Keep the body even if it isn't valid JSON, since that's exactly the
+        // kind of response we want to be able to inspect later
+        let response = serde_json::from_slice(body).unwrap_or_else(|_| {
+            serde_json::Value::String(String::from_utf8_lossy(body).into_owned())
+        });
+
+        let recording = Recording {
+            timestamp: now.as_secs() as i64,
+            status: status.as_u16(),
+            path,
+            params,
+            response,
+        };
+
+        let file_name = format!(
+            "{}-{:04}-{}.json",
+            now.as_millis(),
+            self.sequence.fetch_add(1, Ordering::Relaxed),
+            slug(&recording.path)
+        );
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(
+            self.dir.join(file_name),
+            serde_json::to_vec_pretty(&recording)?,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Returns the response of the most recent recording in `dir` whose request
+/// path ends with `path` and includes all of `params`. A recorded error
+/// response is returned as the [`StatusError`] the API responded with
+pub(crate) fn find<T: DeserializeOwned>(
+    dir: &Path,
+    path: &str,
+    params: &[(&str, String)],
+) -> Result<T> {
+    let suffix = format!("{}.json", slug(path));
+
+    let mut candidates = fs::read_dir(dir)
+        .with_context(|| format!("No recordings at {}", dir.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|file| {
+            file.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(&suffix))
+        })
+        .collect::<Vec<_>>();
+
+    // File names are prefixed by capture time, so newest sorts first
+    candidates.sort_unstable_by(|a, b| b.cmp(a));
+
+    for file in candidates {
+        let Ok(bytes) = fs::read(&file) else {
+            continue;
+        };
+
+        let Ok(recording) = serde_json::from_slice::<Recording<serde_json::Value>>(&bytes) else {
+            continue;
+        };
+
+        let matches = recording.path.ends_with(path)
+            && params
+                .iter()
+                .all(|(key, value)| recording.params.get(*key) == Some(value));
+
+        if !matches {
+            continue;
+        }
+
+        let status = StatusCode::from_u16(recording.status)
+            .with_context(|| format!("Invalid status in recording at {}", file.display()))?;
+
+        if !status.is_success() {
+            let body = match &recording.response {
+                serde_json::Value::String(body) => body.clone().into_bytes(),
+                response => serde_json::to_vec(response)?,
+            };
+
+            bail!(StatusError {
+                status,
+                retry_after: None,
+                description: error_description(&body),
+            });
+        }
+
+        return serde_json::from_value(recording.response)
+            .with_context(|| format!("Failed to parse recording at {}", file.display()));
+    }
+
+    bail!("No recording of {} in {}", path, dir.display());
+}
+
+fn slug(path: &str) -> String {
+    path.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_find() {
+        let dir = std::env::temp_dir().join(format!("tickrs-record-{}", std::process::id()));
+
+        let recorder = Recorder::new(&dir);
+
+        let url = "https://query1.finance.yahoo.com/v10/finance/quoteSummary/SPY?modules=price&crumb=secret"
+            .parse::<Uri>()
+            .unwrap();
+        recorder
+            .record(&url, StatusCode::OK, br#"{"old":true}"#)
+            .unwrap();
+        recorder
+            .record(&url, StatusCode::OK, br#"{"old":false}"#)
+            .unwrap();
+
+        let url = "https://query1.finance.yahoo.com/v8/finance/chart/SPY?range=1d&interval=1m"
+            .parse::<Uri>()
+            .unwrap();
+        recorder.record(&url, StatusCode::OK, b"not json").unwrap();
+
+        let url = "https://query1.finance.yahoo.com/v7/finance/options/SPY"
+            .parse::<Uri>()
+            .unwrap();
+        recorder
+            .record(
+                &url,
+                StatusCode::NOT_FOUND,
+                br#"{"finance":{"error":{"description":"No data found"}}}"#,
+            )
+            .unwrap();
+
+        let latest: serde_json::Value = find(&dir, "finance/quoteSummary/SPY", &[]).unwrap();
+        assert_eq!(latest, serde_json::json!({ "old": false }));
+
+        let chart: String = find(
+            &dir,
+            "finance/chart/SPY",
+            &[("range", "1d".to_string()), ("interval", "1m".to_string())],
+        )
+        .unwrap();
+        assert_eq!(chart, "not json");
+
+        assert!(find::<String>(&dir, "finance/chart/SPY", &[("range", "5y".to_string())]).is_err());
+
+        let err = find::<serde_json::Value>(&dir, "finance/options/SPY", &[]).unwrap_err();
+        let status = err.downcast_ref::<StatusError>().unwrap();
+        assert_eq!(status.status, StatusCode::NOT_FOUND);
+        assert_eq!(status.description.as_deref(), Some("No data found"));
+
+        for entry in fs::read_dir(&dir).unwrap().flatten() {
+            let contents = fs::read_to_string(entry.path()).unwrap();
+            assert!(!contents.contains("secret"));
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
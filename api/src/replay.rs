@@ -0,0 +1,694 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{bail, format_err, Context, Result};
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+
+use crate::model::{
+    Chart, ChartData, Company, CompanyData, Options, OptionsHeader, QuoteData, Quotes,
+    SearchResult, SymbolSearch,
+};
+use crate::{record, DataProvider, Interval, Range, StatusError};
+
+/// Serves previously captured responses from disk instead of the network.
+///
+/// Fixtures are stored per symbol:
+///
+/// ```text
+/// <dir>/<SYMBOL>/chart_<range>_<interval>.json    ChartData
+/// <dir>/<SYMBOL>/company.json                     CompanyData
+/// <dir>/<SYMBOL>/options_<expiration date>.json   OptionsHeader
+/// ```
+///
+/// When a fixture is missing, `dir` is also searched for a session archive
+/// captured with `Client::record_to`, so user recordings can be replayed as is.
+#[derive(Debug)]
+pub struct Replay {
+    dir: PathBuf,
+    playback: Option<Playback>,
+}
+
+/// Recorded ranges to look through for data since a point in time, longest first
+const RANGES: [Range; 11] = [
+    Range::Max,
+    Range::Year10,
+    Range::Year5,
+    Range::Year2,
+    Range::Year1,
+    Range::Ytd,
+    Range::Month6,
+    Range::Month3,
+    Range::Month1,
+    Range::Day5,
+    Range::Day1,
+];
+
+/// Simulated clock used to "play" a 1D session from its first data point
+#[derive(Debug)]
+struct Playback {
+    started: Instant,
+    speed: f64,
+}
+
+impl Replay {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Replay {
+            dir: dir.into(),
+            playback: None,
+        }
+    }
+
+    /// Reveal 1D chart data gradually, advancing `speed` seconds of the
+    /// recorded session for every real second elapsed
+    pub fn with_playback(mut self, speed: f64) -> Self {
+        self.playback = Some(Playback {
+            started: Instant::now(),
+            speed,
+        });
+        self
+    }
+
+    fn symbol_dir(&self, symbol: &str) -> PathBuf {
+        self.dir.join(symbol)
+    }
+
+    fn read<T: DeserializeOwned>(&self, path: &Path) -> Result<T> {
+        let bytes =
+            fs::read(path).with_context(|| format!("No recording at {}", path.display()))?;
+
+        serde_json::from_slice(&bytes)
+            .with_context(|| format!("Failed to parse recording at {}", path.display()))
+    }
+
+    fn chart_data(&self, symbol: &str, interval: Interval, range: Range) -> Result<ChartData> {
+        let path = self
+            .symbol_dir(symbol)
+            .join(format!("chart_{}_{}.json", range, interval));
+
+        let mut chart_data: ChartData = self.read(&path).or_else(|err| {
+            let chart: Chart = self.recorded(
+                &format!("finance/chart/{}", symbol),
+                &[
+                    ("interval", interval.to_string()),
+                    ("range", range.to_string()),
+                ],
+                err,
+            )?;
+
+            single(chart.chart.result, symbol)
+        })?;
+
+        if let (Range::Day1, Some(playback)) = (range, self.playback.as_ref()) {
+            playback.truncate(&mut chart_data);
+        }
+
+        Ok(chart_data)
+    }
+
+    /// Serves the longest recording for `interval`, minus anything outside of
+    /// `start` to `end`
+    fn chart_data_between(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start: i64,
+        end: i64,
+    ) -> Result<ChartData> {
+        let mut chart_data = RANGES
+            .iter()
+            .find_map(|range| self.chart_data(symbol, interval, *range).ok())
+            .with_context(|| format!("No {} chart recordings for {}", interval, symbol))?;
+
+        let timestamps = chart_data.timestamp.iter();
+        let first = timestamps.clone().take_while(|t| **t < start).count();
+        let last = timestamps.take_while(|t| **t <= end).count().max(first);
+
+        retain_points(&mut chart_data, first, last);
+
+        Ok(chart_data)
+    }
+
+    fn company_data(&self, symbol: &str) -> Result<CompanyData> {
+        let mut company_data: CompanyData = self
+            .read(&self.symbol_dir(symbol).join("company.json"))
+            .or_else(|err| {
+                let company: Company =
+                    self.recorded(&format!("finance/quoteSummary/{}", symbol), &[], err)?;
+
+                single(company.company.result, symbol)
+            })?;
+
+        // Keep the quote in step with the session being played back
+        if self.playback.is_some() {
+            if let Ok(chart_data) = self.chart_data(symbol, Interval::Minute1, Range::Day1) {
+                let price = chart_data.meta.regular_market_price;
+
+                company_data.price.regular_market_price.price = price;
+                company_data.price.regular_market_price.fmt = format!("{:.2}", price);
+            }
+        }
+
+        Ok(company_data)
+    }
+
+    fn quotes(&self, symbols: &[String]) -> Result<Vec<QuoteData>> {
+        match record::find::<Quotes>(
+            &self.dir,
+            "finance/quote",
+            &[("symbols", symbols.join(","))],
+        ) {
+            Ok(quotes) => return Ok(quotes.quote_response.result.unwrap_or_default()),
+            Err(err) if err.is::<StatusError>() => return Err(err),
+            Err(_) => {}
+        }
+
+        // Derived from company data so playback stays in step, skipping
+        // symbols without recordings just like the live API does
+        let quotes = symbols
+            .iter()
+            .filter_map(|symbol| self.company_data(symbol).ok())
+            .map(|company| QuoteData {
+                symbol: company.price.symbol,
+                regular_market_price: Some(company.price.regular_market_price.price),
+                post_market_price: company.price.post_market_price.price,
+                regular_market_volume: company.price.regular_market_volume.price.map(|v| v as u64),
+                average_daily_volume_3_month: company
+                    .price
+                    .average_daily_volume_3_month
+                    .price
+                    .map(|v| v as u64),
+            })
+            .collect();
+
+        Ok(quotes)
+    }
+
+    fn options_expiration_dates(&self, symbol: &str) -> Result<Vec<i64>> {
+        let dir = self.symbol_dir(symbol);
+
+        let mut dates = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                entry
+                    .file_name()
+                    .to_str()?
+                    .strip_prefix("options_")?
+                    .strip_suffix(".json")?
+                    .parse::<i64>()
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        // The symbol may only have chart or company fixtures, with its options
+        // in the session archive
+        if dates.is_empty() {
+            let options: Options = self.recorded(
+                &format!("finance/options/{}", symbol),
+                &[],
+                format_err!(
+                    "No recorded options data for {} at {}",
+                    symbol,
+                    dir.display()
+                ),
+            )?;
+
+            return Ok(single(options.option_chain.result, symbol)?.expiration_dates);
+        }
+
+        dates.sort_unstable();
+
+        Ok(dates)
+    }
+
+    fn options_for_expiration_date(
+        &self,
+        symbol: &str,
+        expiration_date: i64,
+    ) -> Result<OptionsHeader> {
+        self.read(
+            &self
+                .symbol_dir(symbol)
+                .join(format!("options_{}.json", expiration_date)),
+        )
+        .or_else(|err| {
+            let options: Options = self.recorded(
+                &format!("finance/options/{}", symbol),
+                &[("date", expiration_date.to_string())],
+                err,
+            )?;
+
+            single(options.option_chain.result, symbol)
+        })
+    }
+
+    fn symbol_search(&self, query: &str) -> Result<Vec<SearchResult>> {
+        match record::find::<SymbolSearch>(&self.dir, "finance/search", &[("q", query.to_string())])
+        {
+            Ok(search) => return Ok(search.quotes),
+            Err(err) if err.is::<StatusError>() => return Err(err),
+            Err(_) => {}
+        }
+
+        let query = query.to_ascii_uppercase();
+
+        let entries = fs::read_dir(&self.dir)
+            .with_context(|| format!("No recordings at {}", self.dir.display()))?;
+
+        // Any symbol with recorded company data is searchable by ticker or name
+        let mut results = entries
+            .flatten()
+            .filter_map(|entry| {
+                let company: CompanyData = self.read(&entry.path().join("company.json")).ok()?;
+
+                let price = company.price;
+
+                let matches = price.symbol.starts_with(&query)
+                    || price.short_name.to_ascii_uppercase().contains(&query)
+                    || price
+                        .long_name
+                        .as_ref()
+                        .is_some_and(|name| name.to_ascii_uppercase().contains(&query));
+
+                matches.then_some(SearchResult {
+                    symbol: price.symbol,
+                    short_name: Some(price.short_name),
+                    long_name: price.long_name,
+                    exchange: None,
+                    quote_type: None,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Exact ticker matches first, then alphabetically
+        results.sort_by(|a, b| {
+            (a.symbol != query)
+                .cmp(&(b.symbol != query))
+                .then_with(|| a.symbol.cmp(&b.symbol))
+        });
+
+        Ok(results)
+    }
+
+    /// Looks through the session archive for when a fixture is missing. A
+    /// recorded error response is returned as the API responded, otherwise
+    /// `missing`
+    fn recorded<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+        missing: impl Into<anyhow::Error>,
+    ) -> Result<T> {
+        record::find(&self.dir, path, params).map_err(|err| {
+            if err.is::<StatusError>() {
+                err
+            } else {
+                missing.into()
+            }
+        })
+    }
+}
+
+/// Unwraps the lone result of a recorded API response
+fn single<T>(result: Option<Vec<T>>, symbol: &str) -> Result<T> {
+    match result {
+        Some(mut result) if result.len() == 1 => Ok(result.remove(0)),
+        _ => bail!("Recorded response for {} has no result", symbol),
+    }
+}
+
+impl Playback {
+    fn truncate(&self, chart_data: &mut ChartData) {
+        let Some(first) = chart_data.timestamp.first().copied() else {
+            return;
+        };
+
+        let elapsed = (self.started.elapsed().as_secs_f64() * self.speed) as i64;
+        let now = first + elapsed;
+
+        // Always reveal at least the opening data point
+        let len = chart_data
+            .timestamp
+            .iter()
+            .take_while(|t| **t <= now)
+            .count()
+            .max(1);
+
+        retain_points(chart_data, 0, len);
+
+        for quote in chart_data.indicators.quote.iter() {
+            if let Some(close) = quote.close.iter().rev().find(|c| **c > 0.0) {
+                chart_data.meta.regular_market_price = *close;
+            }
+        }
+    }
+}
+
+/// Keeps only the data points from index `start` up to `end`
+fn retain_points(chart_data: &mut ChartData, start: usize, end: usize) {
+    fn retain<T>(values: &mut Vec<T>, start: usize, end: usize) {
+        values.truncate(end);
+        values.drain(..start.min(values.len()));
+    }
+
+    retain(&mut chart_data.timestamp, start, end);
+
+    for quote in chart_data.indicators.quote.iter_mut() {
+        retain(&mut quote.close, start, end);
+        retain(&mut quote.volume, start, end);
+        retain(&mut quote.high, start, end);
+        retain(&mut quote.low, start, end);
+        retain(&mut quote.open, start, end);
+    }
+
+    if let Some(adjclose) = chart_data.indicators.adjclose.as_mut() {
+        for adjclose in adjclose.iter_mut() {
+            retain(&mut adjclose.adjclose, start, end);
+        }
+    }
+}
+
+impl DataProvider for Replay {
+    fn get_chart_data<'a>(
+        &'a self,
+        symbol: &'a str,
+        interval: Interval,
+        range: Range,
+        _include_pre_post: bool,
+    ) -> BoxFuture<'a, Result<ChartData>> {
+        Box::pin(async move { self.chart_data(symbol, interval, range) })
+    }
+
+    fn get_chart_data_since<'a>(
+        &'a self,
+        symbol: &'a str,
+        interval: Interval,
+        since: i64,
+        _include_pre_post: bool,
+    ) -> BoxFuture<'a, Result<ChartData>> {
+        Box::pin(async move { self.chart_data_between(symbol, interval, since, i64::MAX) })
+    }
+
+    fn get_chart_data_between<'a>(
+        &'a self,
+        symbol: &'a str,
+        interval: Interval,
+        start: i64,
+        end: i64,
+        _include_pre_post: bool,
+    ) -> BoxFuture<'a, Result<ChartData>> {
+        Box::pin(async move { self.chart_data_between(symbol, interval, start, end) })
+    }
+
+    fn get_company_data<'a>(&'a self, symbol: &'a str) -> BoxFuture<'a, Result<CompanyData>> {
+        Box::pin(async move { self.company_data(symbol) })
+    }
+
+    fn get_quotes<'a>(&'a self, symbols: &'a [String]) -> BoxFuture<'a, Result<Vec<QuoteData>>> {
+        Box::pin(async move { self.quotes(symbols) })
+    }
+
+    fn get_options_expiration_dates<'a>(
+        &'a self,
+        symbol: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<i64>>> {
+        Box::pin(async move { self.options_expiration_dates(symbol) })
+    }
+
+    fn get_options_for_expiration_date<'a>(
+        &'a self,
+        symbol: &'a str,
+        expiration_date: i64,
+    ) -> BoxFuture<'a, Result<OptionsHeader>> {
+        Box::pin(async move { self.options_for_expiration_date(symbol, expiration_date) })
+    }
+
+    fn search_symbols<'a>(&'a self, query: &'a str) -> BoxFuture<'a, Result<Vec<SearchResult>>> {
+        Box::pin(async move { self.symbol_search(query) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use super::*;
+
+    fn fixtures() -> Replay {
+        Replay::new(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/replay"))
+    }
+
+    #[async_std::test]
+    async fn test_company_data() {
+        let provider = fixtures();
+
+        let data = provider.get_company_data("SPY").await.unwrap();
+
+        assert_eq!(data.price.symbol, "SPY");
+        assert!(data.price.regular_market_price.price > 0.0);
+
+        assert!(provider.get_company_data("NOPE").await.is_err());
+    }
+
+    #[async_std::test]
+    async fn test_quotes() {
+        let provider = fixtures();
+
+        let symbols = vec!["SPY".to_string(), "NOPE".to_string()];
+
+        let quotes = provider.get_quotes(&symbols).await.unwrap();
+
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].symbol, "SPY");
+        assert!(quotes[0].regular_market_price.is_some());
+    }
+
+    #[async_std::test]
+    async fn test_options_data() {
+        let provider = fixtures();
+
+        let dates = provider.get_options_expiration_dates("SPY").await.unwrap();
+
+        assert!(!dates.is_empty());
+
+        for date in dates {
+            let options = provider
+                .get_options_for_expiration_date("SPY", date)
+                .await
+                .unwrap();
+
+            assert_eq!(options.options.len(), 1);
+            assert_eq!(options.options[0].expiration_date, date);
+        }
+    }
+
+    #[async_std::test]
+    async fn test_chart_data() {
+        let provider = fixtures();
+
+        let combinations = [
+            (Range::Day1, Interval::Minute1),
+            (Range::Year1, Interval::Day1),
+        ];
+
+        for (range, interval) in combinations {
+            let data = provider
+                .get_chart_data("SPY", interval, range, true)
+                .await
+                .unwrap();
+
+            assert_eq!(data.timestamp.len(), data.indicators.quote[0].close.len());
+        }
+
+        assert!(provider
+            .get_chart_data("SPY", Interval::Minute5, Range::Day5, false)
+            .await
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn test_chart_data_since() {
+        let provider = fixtures();
+
+        let full = provider
+            .get_chart_data("SPY", Interval::Day1, Range::Year1, false)
+            .await
+            .unwrap();
+
+        let since = full.timestamp[full.timestamp.len() - 10];
+
+        let tail = provider
+            .get_chart_data_since("SPY", Interval::Day1, since, false)
+            .await
+            .unwrap();
+
+        assert_eq!(tail.timestamp, full.timestamp[full.timestamp.len() - 10..]);
+        assert_eq!(tail.indicators.quote[0].close.len(), 10);
+
+        assert!(provider
+            .get_chart_data_since("SPY", Interval::Minute5, since, false)
+            .await
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn test_chart_data_between() {
+        let provider = fixtures();
+
+        let full = provider
+            .get_chart_data("SPY", Interval::Day1, Range::Year1, false)
+            .await
+            .unwrap();
+
+        let start = full.timestamp[10];
+        let end = full.timestamp[19];
+
+        let between = provider
+            .get_chart_data_between("SPY", Interval::Day1, start, end, false)
+            .await
+            .unwrap();
+
+        assert_eq!(between.timestamp, full.timestamp[10..20]);
+        assert_eq!(between.indicators.quote[0].close.len(), 10);
+
+        let empty = provider
+            .get_chart_data_between("SPY", Interval::Day1, end, start, false)
+            .await
+            .unwrap();
+
+        assert!(empty.timestamp.is_empty());
+    }
+
+    #[async_std::test]
+    async fn test_search_symbols() {
+        let provider = fixtures();
+
+        for query in ["spy", "S&P 500"] {
+            let results = provider.search_symbols(query).await.unwrap();
+
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].symbol, "SPY");
+        }
+
+        assert!(provider.search_symbols("NOPE").await.unwrap().is_empty());
+    }
+
+    #[async_std::test]
+    async fn test_playback() {
+        let provider = fixtures().with_playback(60.0 * 30.0);
+
+        let full = fixtures()
+            .get_chart_data("SPY", Interval::Minute1, Range::Day1, false)
+            .await
+            .unwrap();
+        let played = provider
+            .get_chart_data("SPY", Interval::Minute1, Range::Day1, false)
+            .await
+            .unwrap();
+
+        assert!(!played.timestamp.is_empty());
+        assert!(played.timestamp.len() < full.timestamp.len());
+        assert_eq!(
+            played.indicators.quote[0].close.len(),
+            played.timestamp.len()
+        );
+
+        let company = provider.get_company_data("SPY").await.unwrap();
+        assert_eq!(
+            company.price.regular_market_price.price,
+            played.meta.regular_market_price
+        );
+    }
+
+    #[async_std::test]
+    async fn test_session_archive() {
+        let dir = std::env::temp_dir().join(format!("tickrs-replay-{}", std::process::id()));
+
+        let fixture: serde_json::Value = serde_json::from_slice(
+            &fs::read(fixtures().symbol_dir("SPY").join("chart_1d_1m.json")).unwrap(),
+        )
+        .unwrap();
+        let response = serde_json::json!({ "chart": { "result": [fixture], "error": null } });
+
+        let url = "https://query1.finance.yahoo.com/v8/finance/chart/SPY?interval=1m&range=1d"
+            .parse()
+            .unwrap();
+        record::Recorder::new(&dir)
+            .record(
+                &url,
+                StatusCode::OK,
+                &serde_json::to_vec(&response).unwrap(),
+            )
+            .unwrap();
+
+        let url = "https://query1.finance.yahoo.com/v10/finance/quoteSummary/SPY?modules=price"
+            .parse()
+            .unwrap();
+        record::Recorder::new(&dir)
+            .record(&url, StatusCode::SERVICE_UNAVAILABLE, b"")
+            .unwrap();
+
+        let provider = Replay::new(&dir);
+
+        let data = provider
+            .get_chart_data("SPY", Interval::Minute1, Range::Day1, false)
+            .await
+            .unwrap();
+        assert_eq!(data.timestamp.len(), 390);
+
+        assert!(provider
+            .get_chart_data("SPY", Interval::Day1, Range::Year1, false)
+            .await
+            .is_err());
+
+        let err = provider.get_company_data("SPY").await.unwrap_err();
+        let status = err.downcast_ref::<StatusError>().unwrap();
+        assert_eq!(status.status, StatusCode::SERVICE_UNAVAILABLE);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[async_std::test]
+    async fn test_session_archive_options() {
+        let dir =
+            std::env::temp_dir().join(format!("tickrs-replay-options-{}", std::process::id()));
+
+        // Symbol directory with company data but no options fixtures
+        fs::create_dir_all(dir.join("SPY")).unwrap();
+        fs::copy(
+            fixtures().symbol_dir("SPY").join("company.json"),
+            dir.join("SPY").join("company.json"),
+        )
+        .unwrap();
+
+        let mut fixture: serde_json::Value = serde_json::from_slice(
+            &fs::read(fixtures().symbol_dir("SPY").join("options_1710979200.json")).unwrap(),
+        )
+        .unwrap();
+        fixture["expirationDates"] = serde_json::json!([1710979200, 1711584000]);
+        let response = serde_json::json!({ "optionChain": { "result": [fixture], "error": null } });
+
+        let url = "https://query1.finance.yahoo.com/v7/finance/options/SPY"
+            .parse()
+            .unwrap();
+        record::Recorder::new(&dir)
+            .record(
+                &url,
+                StatusCode::OK,
+                &serde_json::to_vec(&response).unwrap(),
+            )
+            .unwrap();
+
+        let provider = Replay::new(&dir);
+
+        let dates = provider.get_options_expiration_dates("SPY").await.unwrap();
+        assert_eq!(dates, vec![1710979200, 1711584000]);
+
+        assert!(provider.get_options_expiration_dates("QQQ").await.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,391 @@
+use std::str::FromStr;
+
+use anyhow::{bail, format_err, Context, Result};
+use async_tungstenite::async_std::{connect_async, ConnectStream};
+use async_tungstenite::tungstenite::client::IntoClientRequest;
+use async_tungstenite::tungstenite::Message;
+use async_tungstenite::WebSocketStream;
+use base64::Engine;
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+
+/// Yahoo! Finance's streamer, which pushes base64 encoded protobuf messages
+pub const YAHOO_STREAM_URL: &str = "wss://streamer.finance.yahoo.com/?version=2";
+
+const YAHOO_ORIGIN: &str = "https://finance.yahoo.com";
+
+/// Wire format of the messages pushed by a quote stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum StreamFormat {
+    /// Yahoo! Finance `PricingData` protobuf messages, base64 encoded
+    #[serde(rename = "yahoo")]
+    Yahoo,
+    /// One JSON object per message, see [`JsonTick`] for the fields
+    #[serde(rename = "json")]
+    Json,
+}
+
+impl FromStr for StreamFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use StreamFormat::*;
+
+        match s {
+            "yahoo" => Ok(Yahoo),
+            "json" => Ok(Json),
+            _ => Err("Valid stream formats are: 'yahoo', 'json'"),
+        }
+    }
+}
+
+/// Market session a tick was traded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum Session {
+    #[serde(rename = "pre")]
+    Pre,
+    #[default]
+    #[serde(rename = "regular")]
+    Regular,
+    #[serde(rename = "post")]
+    Post,
+    #[serde(rename = "extended")]
+    Extended,
+}
+
+/// A single real-time price update
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    pub symbol: String,
+    pub price: f64,
+    /// Unix timestamp in seconds
+    pub time: i64,
+    pub session: Session,
+    /// Total volume traded so far today
+    pub day_volume: Option<u64>,
+}
+
+/// Message format of the generic JSON feed, for custom providers
+///
+/// `{"symbol": "SPY", "price": 512.3, "time": 1710979200, "volume": 1000, "session": "regular"}`
+///
+/// `volume` is the total traded so far today, and both it and `session` are
+/// optional. Any message that doesn't match is ignored
+#[derive(Debug, Deserialize)]
+pub struct JsonTick {
+    #[serde(alias = "id")]
+    pub symbol: String,
+    pub price: f64,
+    pub time: i64,
+    #[serde(default, alias = "day_volume")]
+    pub volume: Option<u64>,
+    #[serde(default)]
+    pub session: Session,
+}
+
+/// Websocket connection pushing real-time quotes for subscribed symbols
+pub struct QuoteStream {
+    socket: WebSocketStream<ConnectStream>,
+    format: StreamFormat,
+}
+
+impl QuoteStream {
+    pub async fn connect(url: &str, format: StreamFormat) -> Result<Self> {
+        let mut request = url
+            .into_client_request()
+            .with_context(|| format!("Invalid stream url: {}", url))?;
+
+        // Yahoo's streamer refuses connections that don't come from its site
+        if format == StreamFormat::Yahoo {
+            request
+                .headers_mut()
+                .insert("Origin", YAHOO_ORIGIN.parse()?);
+        }
+
+        let (socket, _) = connect_async(request)
+            .await
+            .with_context(|| format!("Failed to connect to {}", url))?;
+
+        Ok(QuoteStream { socket, format })
+    }
+
+    pub async fn subscribe(&mut self, symbols: &[String]) -> Result<()> {
+        self.send("subscribe", symbols).await
+    }
+
+    pub async fn unsubscribe(&mut self, symbols: &[String]) -> Result<()> {
+        self.send("unsubscribe", symbols).await
+    }
+
+    /// Waits for the next tick, skipping any messages that aren't one.
+    /// Returns `None` once the server closes the stream
+    pub async fn next_tick(&mut self) -> Result<Option<Tick>> {
+        while let Some(message) = self.socket.next().await {
+            let tick = match message? {
+                Message::Text(text) => parse_message(text.as_bytes(), self.format),
+                Message::Binary(bytes) => parse_message(&bytes, self.format),
+                Message::Close(_) => return Ok(None),
+                _ => continue,
+            };
+
+            if let Some(tick) = tick {
+                return Ok(Some(tick));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn send(&mut self, action: &str, symbols: &[String]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        let message = serde_json::json!({ action: symbols }).to_string();
+
+        self.socket.send(Message::Text(message)).await?;
+
+        Ok(())
+    }
+}
+
+/// Parses a tick from a stream message, returning `None` for anything else
+/// the server sends such as heartbeats and acknowledgements
+pub fn parse_message(message: &[u8], format: StreamFormat) -> Option<Tick> {
+    match format {
+        StreamFormat::Yahoo => {
+            // Version 2 of the streamer wraps the payload in a JSON envelope,
+            // while version 1 sends it bare
+            let payload = match serde_json::from_slice::<serde_json::Value>(message) {
+                Ok(envelope) => envelope.get("message")?.as_str()?.to_string(),
+                Err(_) => String::from_utf8(message.to_vec()).ok()?,
+            };
+
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(payload.trim())
+                .ok()?;
+
+            decode_pricing_data(&bytes).ok()
+        }
+        StreamFormat::Json => {
+            let tick = serde_json::from_slice::<JsonTick>(message).ok()?;
+
+            Some(Tick {
+                symbol: tick.symbol,
+                price: tick.price,
+                time: to_seconds(tick.time),
+                session: tick.session,
+                day_volume: tick.volume,
+            })
+        }
+    }
+}
+
+/// Decodes the fields we use from Yahoo's `PricingData` protobuf message
+///
+/// 1: id (string), 2: price (float), 3: time (sint64, ms),
+/// 7: market hours (enum), 9: day volume (sint64)
+fn decode_pricing_data(mut buf: &[u8]) -> Result<Tick> {
+    let mut symbol = None;
+    let mut price = None;
+    let mut time = 0;
+    let mut market_hours = 0;
+    let mut day_volume = None;
+
+    while !buf.is_empty() {
+        let key = read_varint(&mut buf)?;
+        let field = key >> 3;
+
+        match key & 0x7 {
+            // Varint
+            0 => {
+                let value = read_varint(&mut buf)?;
+
+                match field {
+                    3 => time = zigzag(value),
+                    7 => market_hours = value,
+                    9 => day_volume = Some(zigzag(value).max(0) as u64),
+                    _ => {}
+                }
+            }
+            // 64-bit
+            1 => {
+                take(&mut buf, 8)?;
+            }
+            // Length delimited
+            2 => {
+                let len = read_varint(&mut buf)? as usize;
+                let bytes = take(&mut buf, len)?;
+
+                if field == 1 {
+                    symbol = Some(String::from_utf8(bytes.to_vec())?);
+                }
+            }
+            // 32-bit
+            5 => {
+                let bytes = take(&mut buf, 4)?;
+
+                if field == 2 {
+                    price = Some(f32::from_le_bytes(bytes.try_into()?) as f64);
+                }
+            }
+            wire_type => bail!("Unsupported protobuf wire type {}", wire_type),
+        }
+    }
+
+    let session = match market_hours {
+        0 => Session::Pre,
+        1 => Session::Regular,
+        2 => Session::Post,
+        _ => Session::Extended,
+    };
+
+    Ok(Tick {
+        symbol: symbol.ok_or_else(|| format_err!("Tick is missing a symbol"))?,
+        price: price.ok_or_else(|| format_err!("Tick is missing a price"))?,
+        time: to_seconds(time),
+        session,
+        day_volume,
+    })
+}
+
+fn read_varint(buf: &mut &[u8]) -> Result<u64> {
+    let mut value = 0;
+
+    for shift in (0..64).step_by(7) {
+        let byte = take(buf, 1)?[0];
+
+        value |= ((byte & 0x7f) as u64) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    bail!("Malformed protobuf varint");
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        bail!("Truncated protobuf message");
+    }
+
+    let (bytes, rest) = buf.split_at(len);
+    *buf = rest;
+
+    Ok(bytes)
+}
+
+fn zigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Feeds disagree on seconds vs milliseconds, but a timestamp in seconds
+/// won't reach 10^11 for a few thousand years
+fn to_seconds(time: i64) -> i64 {
+    if time >= 100_000_000_000 {
+        time / 1000
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn varint(mut value: u64, out: &mut Vec<u8>) {
+        while value >= 0x80 {
+            out.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn pricing_data(symbol: &str, price: f32, time: i64, hours: u64, volume: i64) -> Vec<u8> {
+        let mut out = vec![];
+
+        varint(1 << 3 | 2, &mut out);
+        varint(symbol.len() as u64, &mut out);
+        out.extend(symbol.as_bytes());
+
+        varint(2 << 3 | 5, &mut out);
+        out.extend(price.to_le_bytes());
+
+        varint(3 << 3, &mut out);
+        varint(((time << 1) ^ (time >> 63)) as u64, &mut out);
+
+        // Unused field that should be skipped
+        varint(4 << 3 | 2, &mut out);
+        varint(3, &mut out);
+        out.extend(b"USD");
+
+        varint(7 << 3, &mut out);
+        varint(hours, &mut out);
+
+        varint(9 << 3, &mut out);
+        varint(((volume << 1) ^ (volume >> 63)) as u64, &mut out);
+
+        out
+    }
+
+    #[test]
+    fn test_parse_yahoo() {
+        let bytes = pricing_data("SPY", 512.25, 1_710_979_260_000, 1, 1_234_567);
+        let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+
+        let expected = Tick {
+            symbol: "SPY".to_string(),
+            price: 512.25,
+            time: 1_710_979_260,
+            session: Session::Regular,
+            day_volume: Some(1_234_567),
+        };
+
+        let bare = parse_message(encoded.as_bytes(), StreamFormat::Yahoo);
+        assert_eq!(bare.as_ref(), Some(&expected));
+
+        let envelope = serde_json::json!({ "type": "pricing", "message": encoded }).to_string();
+        let wrapped = parse_message(envelope.as_bytes(), StreamFormat::Yahoo);
+        assert_eq!(wrapped.as_ref(), Some(&expected));
+
+        let post = pricing_data("SPY", 511.0, 1_710_979_260_000, 2, 0);
+        let post = base64::engine::general_purpose::STANDARD.encode(post);
+        let post = parse_message(post.as_bytes(), StreamFormat::Yahoo).unwrap();
+        assert_eq!(post.session, Session::Post);
+
+        assert!(parse_message(br#"{"type":"heartbeat"}"#, StreamFormat::Yahoo).is_none());
+        assert!(parse_message(b"AAAA", StreamFormat::Yahoo).is_none());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let tick = parse_message(
+            br#"{"symbol":"AMD","price":180.5,"time":1710979260,"volume":42,"session":"post"}"#,
+            StreamFormat::Json,
+        )
+        .unwrap();
+
+        assert_eq!(
+            tick,
+            Tick {
+                symbol: "AMD".to_string(),
+                price: 180.5,
+                time: 1_710_979_260,
+                session: Session::Post,
+                day_volume: Some(42),
+            }
+        );
+
+        let minimal = parse_message(
+            br#"{"id":"AMD","price":1,"time":1710979260000}"#,
+            StreamFormat::Json,
+        )
+        .unwrap();
+        assert_eq!(minimal.time, 1_710_979_260);
+        assert_eq!(minimal.session, Session::Regular);
+        assert_eq!(minimal.day_volume, None);
+
+        assert!(parse_message(br#"{"status":"subscribed"}"#, StreamFormat::Json).is_none());
+    }
+}
@@ -0,0 +1,443 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::{fmt, fs, thread};
+
+use anyhow::{format_err, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::{ALERT_LOG, OPTS};
+
+/// Oldest fired alerts are dropped from the log past this many
+const MAX_LOG_LEN: usize = 1000;
+
+/// What a stock has to do for an alert to fire
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    /// Price at or above
+    Above(f64),
+    /// Price at or below
+    Below(f64),
+    /// Up at least this percent on the day
+    Up(f64),
+    /// Down at least this percent on the day
+    Down(f64),
+    /// Day volume at least this multiple of the average daily volume
+    Volume(f64),
+}
+
+impl Condition {
+    /// Whether a stock at `price` meets the condition. Percent changes are
+    /// from `prev_close`, and volume is compared to `avg_volume`
+    pub fn is_met(
+        self,
+        price: f64,
+        prev_close: Option<f64>,
+        volume: Option<u64>,
+        avg_volume: Option<u64>,
+    ) -> bool {
+        if price <= 0.0 {
+            return false;
+        }
+
+        let day_change = || {
+            let prev_close = prev_close.filter(|prev_close| *prev_close > 0.0)?;
+
+            Some((price / prev_close - 1.0) * 100.0)
+        };
+
+        match self {
+            Condition::Above(target) => price >= target,
+            Condition::Below(target) => price <= target,
+            Condition::Up(pct) => day_change().is_some_and(|change| change >= pct),
+            Condition::Down(pct) => day_change().is_some_and(|change| -change >= pct),
+            Condition::Volume(multiple) => match (volume, avg_volume) {
+                (Some(volume), Some(avg_volume)) if avg_volume > 0 => {
+                    volume as f64 >= avg_volume as f64 * multiple
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::Above(price) => write!(f, "above {}", price),
+            Condition::Below(price) => write!(f, "below {}", price),
+            Condition::Up(pct) => write!(f, "up {}%", pct),
+            Condition::Down(pct) => write!(f, "down {}%", pct),
+            Condition::Volume(multiple) => write!(f, "volume {}x", multiple),
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid alert '{}', expected 'above 180', 'below 150', 'up 2%', 'down 2%' or 'volume 2x'",
+                s.trim()
+            )
+        };
+
+        let (kind, amount) = s.trim().split_once(' ').ok_or_else(invalid)?;
+        let kind = kind.to_ascii_lowercase();
+        let amount = amount.trim();
+
+        let amount = match kind.as_str() {
+            "above" | "below" => Some(amount),
+            "up" | "down" => amount.strip_suffix('%'),
+            "volume" => amount.strip_suffix('x'),
+            _ => None,
+        }
+        .and_then(|amount| amount.trim().parse::<f64>().ok())
+        .filter(|amount| amount.is_finite() && *amount > 0.0)
+        .ok_or_else(invalid)?;
+
+        Ok(match kind.as_str() {
+            "above" => Condition::Above(amount),
+            "below" => Condition::Below(amount),
+            "up" => Condition::Up(amount),
+            "down" => Condition::Down(amount),
+            _ => Condition::Volume(amount),
+        })
+    }
+}
+
+/// Alert on a symbol, set in `config.yml` as `AMD above 180`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Alert {
+    pub symbol: String,
+    pub condition: Condition,
+}
+
+impl fmt::Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.symbol, self.condition)
+    }
+}
+
+impl FromStr for Alert {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (symbol, condition) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| format!("Invalid alert '{}', expected 'AMD above 180'", s.trim()))?;
+
+        Ok(Alert {
+            symbol: symbol.to_ascii_uppercase(),
+            condition: condition.parse()?,
+        })
+    }
+}
+
+impl TryFrom<String> for Alert {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Alert> for String {
+    fn from(alert: Alert) -> Self {
+        alert.to_string()
+    }
+}
+
+/// Condition watched on a stock, which fires when it's first met and only
+/// again once it's no longer met
+#[derive(Debug, Clone, Copy)]
+pub struct AlertState {
+    pub condition: Condition,
+    pub triggered: bool,
+}
+
+impl AlertState {
+    pub fn new(condition: Condition) -> AlertState {
+        AlertState {
+            condition,
+            triggered: false,
+        }
+    }
+
+    /// Records whether the condition is met, returning whether the alert
+    /// should fire because it's only just been met
+    pub fn update(&mut self, met: bool) -> bool {
+        let fire = met && !self.triggered;
+
+        self.triggered = met;
+
+        fire
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiredAlert {
+    pub alert: Alert,
+    /// Price when it fired
+    pub price: f64,
+    pub time: i64,
+    /// Whether it's been seen in the alerts pane or acknowledged from the
+    /// alert history, until then the stock's tab flashes
+    pub acknowledged: bool,
+}
+
+/// Fired alerts, oldest first, saved in the tickrs data directory so those
+/// fired while no one was watching can be looked back on
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AlertLog {
+    pub fired: Vec<FiredAlert>,
+    /// Why the log couldn't be read or last saved, shown in the alert history
+    #[serde(skip)]
+    pub error: Option<String>,
+}
+
+impl AlertLog {
+    /// Log saved by previous sessions, or an empty one if there isn't one or
+    /// it can't be read
+    pub fn load() -> AlertLog {
+        let Some(path) = log_path() else {
+            return AlertLog::default();
+        };
+
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| AlertLog {
+                error: Some(format!("Failed to read alert history: {}", e)),
+                ..Default::default()
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => AlertLog::default(),
+            Err(e) => AlertLog {
+                error: Some(format!("Failed to read alert history: {}", e)),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn save(&mut self) {
+        self.error = self
+            .write()
+            .err()
+            .map(|e| format!("Failed to save alert history: {:#}", e));
+    }
+
+    fn write(&self) -> Result<()> {
+        let path = log_path().ok_or_else(|| format_err!("Could not get data directory"))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_vec(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn push(&mut self, fired: FiredAlert) {
+        self.fired.push(fired);
+
+        let excess = self.fired.len().saturating_sub(MAX_LOG_LEN);
+        self.fired.drain(..excess);
+
+        self.save();
+    }
+
+    pub fn acknowledge(&mut self, idx: usize) {
+        if let Some(fired) = self.fired.get_mut(idx) {
+            fired.acknowledged = true;
+
+            self.save();
+        }
+    }
+
+    pub fn acknowledge_all(&mut self) {
+        if self.fired.iter().any(|fired| !fired.acknowledged) {
+            for fired in self.fired.iter_mut() {
+                fired.acknowledged = true;
+            }
+
+            self.save();
+        }
+    }
+
+    pub fn remove(&mut self, idx: usize) {
+        if idx < self.fired.len() {
+            self.fired.remove(idx);
+
+            self.save();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.fired.clear();
+
+        self.save();
+    }
+}
+
+fn log_path() -> Option<PathBuf> {
+    Some(dirs_next::data_dir()?.join("tickrs").join("alerts.json"))
+}
+
+/// Rings the terminal bell, runs `alert_command` and logs the alert
+pub fn fire(alert: Alert, price: f64) {
+    let fired = FiredAlert {
+        alert,
+        price,
+        time: Utc::now().timestamp(),
+        acknowledged: false,
+    };
+
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x07").and_then(|_| stdout.flush());
+
+    if let Some(command) = OPTS.alert_command.as_ref() {
+        run_command(command, &fired);
+    }
+
+    ALERT_LOG.write().push(fired);
+}
+
+/// Whether `symbol` has fired alerts that haven't been seen yet
+pub fn is_unacknowledged(symbol: &str) -> bool {
+    ALERT_LOG
+        .read()
+        .fired
+        .iter()
+        .any(|fired| !fired.acknowledged && fired.alert.symbol == symbol)
+}
+
+/// Runs the command through the shell with the alert in its environment,
+/// without waiting on it or letting its output into the terminal
+fn run_command(command: &str, fired: &FiredAlert) {
+    let mut command = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+
+    let child = command
+        .env("TICKRS_ALERT", fired.alert.to_string())
+        .env("TICKRS_SYMBOL", &fired.alert.symbol)
+        .env("TICKRS_CONDITION", fired.alert.condition.to_string())
+        .env("TICKRS_PRICE", fired.price.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    // Reap it once it exits
+    if let Ok(mut child) = child {
+        thread::spawn(move || child.wait());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_condition() {
+        assert_eq!("above 180".parse(), Ok(Condition::Above(180.0)));
+        assert_eq!("BELOW 150.5".parse(), Ok(Condition::Below(150.5)));
+        assert_eq!("Up 2%".parse(), Ok(Condition::Up(2.0)));
+        assert_eq!("down 2.5 %".parse(), Ok(Condition::Down(2.5)));
+        assert_eq!("volume 2x".parse(), Ok(Condition::Volume(2.0)));
+        assert_eq!("  above   180  ".parse(), Ok(Condition::Above(180.0)));
+    }
+
+    #[test]
+    fn test_parse_invalid_condition() {
+        for s in [
+            "",
+            "above",
+            "above 0",
+            "below -5",
+            "up 2",
+            "down 2x",
+            "volume 2",
+            "volume 0x",
+            "up -1%",
+            "above NaN",
+            "above inf",
+            "above abc",
+            "sideways 2",
+        ] {
+            assert!(s.parse::<Condition>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_alert() {
+        let alert = "amd Above 180".parse::<Alert>().unwrap();
+
+        assert_eq!(alert.symbol, "AMD");
+        assert_eq!(alert.condition, Condition::Above(180.0));
+        assert_eq!(alert.to_string(), "AMD above 180");
+
+        assert!("AMD".parse::<Alert>().is_err());
+        assert!("AMD up".parse::<Alert>().is_err());
+    }
+
+    #[test]
+    fn test_is_met() {
+        let is_met = |condition: &str, price: f64| {
+            condition.parse::<Condition>().unwrap().is_met(
+                price,
+                Some(100.0),
+                Some(3_000),
+                Some(1_000),
+            )
+        };
+
+        assert!(is_met("above 105", 105.0));
+        assert!(!is_met("above 105", 104.99));
+        assert!(is_met("below 95", 95.0));
+        assert!(!is_met("below 95", 95.01));
+        assert!(is_met("up 2%", 102.0));
+        assert!(!is_met("up 2%", 101.9));
+        assert!(is_met("down 2%", 98.0));
+        assert!(!is_met("down 2%", 98.1));
+        assert!(!is_met("down 2%", 102.0));
+        assert!(is_met("volume 3x", 100.0));
+        assert!(!is_met("volume 3.5x", 100.0));
+
+        // Nothing is met without a price
+        assert!(!is_met("below 95", 0.0));
+    }
+
+    #[test]
+    fn test_is_met_missing_data() {
+        assert!(!Condition::Up(2.0).is_met(110.0, None, None, None));
+        assert!(!Condition::Down(2.0).is_met(90.0, Some(0.0), None, None));
+        assert!(!Condition::Volume(2.0).is_met(100.0, None, Some(3_000), None));
+        assert!(!Condition::Volume(2.0).is_met(100.0, None, Some(3_000), Some(0)));
+    }
+
+    #[test]
+    fn test_fires_once_then_rearms() {
+        let mut alert = AlertState::new(Condition::Above(100.0));
+
+        assert!(!alert.update(false));
+        assert!(alert.update(true));
+        // Stays quiet while the condition holds
+        assert!(!alert.update(true));
+        assert!(!alert.update(false));
+        // Fires again once met after no longer being met
+        assert!(alert.update(true));
+    }
+}
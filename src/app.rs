@@ -1,16 +1,30 @@
 use crossterm::event::Event;
 
-use crate::common::{ChartType, TimeFrame};
-use crate::service::default_timestamps::DefaultTimestampService;
+use crate::common::{ChartType, CustomTimeFrame, TimeFrame};
+use crate::service::comparison::{self, ComparisonService};
+use crate::service::default_timestamps::{self, DefaultTimestampService};
+use crate::service::quote::{self, QuoteService};
+use crate::service::stock::Fetch;
+use crate::service::stream::{self, StreamService};
 use crate::service::Service;
-use crate::{widget, DEFAULT_TIMESTAMPS};
+use crate::{widget, CUSTOM_TIME_FRAMES, DEFAULT_TIMESTAMPS, OPTS};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Mode {
+    AddAlert,
+    AddComparison,
     AddStock,
+    AlertHistory,
     ConfigureChart,
+    DateRange,
+    DisplayAlerts,
     DisplayStock,
     DisplayOptions,
+    OptionsChain,
+    OptionsInterest,
+    OptionsStrategy,
+    OptionsVolatility,
+    SaveStrategy,
     DisplaySummary,
     Help,
 }
@@ -19,6 +33,9 @@ pub struct App {
     pub mode: Mode,
     pub stocks: Vec<widget::StockState>,
     pub add_stock: widget::AddStockState,
+    pub prompt: widget::PromptState,
+    pub alerts: widget::AlertsState,
+    pub alert_history: widget::AlertHistoryState,
     pub help: widget::HelpWidget,
     pub current_tab: usize,
     pub hide_help: bool,
@@ -26,19 +43,195 @@ pub struct App {
     pub previous_mode: Mode,
     pub time_frame: TimeFrame,
     pub default_timestamp_service: DefaultTimestampService,
+    pub quote_service: QuoteService,
+    pub comparison_service: ComparisonService,
+    pub stream_service: Option<StreamService>,
+    pub streaming: bool,
+    pub quotes_paused: bool,
     pub summary_scroll_state: SummaryScrollState,
     pub chart_type: ChartType,
 }
 
 impl App {
-    pub fn update(&self) {
-        let mut timestamp_updates = self.default_timestamp_service.updates();
+    pub fn update(&mut self) {
+        for update in self.default_timestamp_service.updates() {
+            match update {
+                default_timestamps::Update::Timestamps(new_defaults) => {
+                    *DEFAULT_TIMESTAMPS.write() = new_defaults;
 
-        if let Some(new_defaults) = timestamp_updates.pop() {
-            *DEFAULT_TIMESTAMPS.write() = new_defaults;
+                    for stock in self.stocks.iter_mut() {
+                        stock.clear_error(Fetch::Timestamps);
+                    }
+                }
+                default_timestamps::Update::Error(error) => {
+                    let error = format!("Failed to load default timestamps: {:#}", error);
+
+                    for stock in self.stocks.iter_mut() {
+                        stock.set_error(Fetch::Timestamps, error.clone());
+                    }
+                }
+            }
+        }
+
+        for update in self.quote_service.updates() {
+            match update {
+                quote::Update::Quotes((symbols, quotes)) => {
+                    for stock in self.stocks.iter_mut() {
+                        let quote = quotes
+                            .iter()
+                            .find(|quote| quote.symbol.eq_ignore_ascii_case(stock.symbol()));
+
+                        match quote {
+                            Some(quote) => stock.set_quote(quote),
+                            // Stocks added since the batch was requested just
+                            // haven't been fetched yet
+                            None if symbols.iter().any(|s| s == stock.symbol()) => stock.set_error(
+                                Fetch::Quote,
+                                format!("No quote found for {}", stock.symbol()),
+                            ),
+                            None => {}
+                        }
+                    }
+                }
+                quote::Update::Error(error) => {
+                    let error = format!("{:#}", error);
+
+                    for stock in self.stocks.iter_mut() {
+                        stock.set_error(Fetch::Quote, error.clone());
+                    }
+                }
+            }
+        }
+
+        for update in self.comparison_service.updates() {
+            match update {
+                comparison::Update::Prices(symbol, (time_frame, meta, prices)) => {
+                    for stock in self.stocks.iter_mut() {
+                        stock.set_comparison_prices(&symbol, time_frame, &meta, &prices);
+                    }
+                }
+                comparison::Update::Error(symbol, error) => {
+                    let error = format!("Failed to compare {}: {:#}", symbol, error);
+
+                    for stock in self.stocks.iter_mut() {
+                        stock.set_comparison_error(&symbol, error.clone());
+                    }
+                }
+            }
+        }
+
+        if let Some(stream_service) = self.stream_service.as_ref() {
+            for update in stream_service.updates() {
+                match update {
+                    stream::Update::Connected => self.streaming = true,
+                    stream::Update::Disconnected => self.streaming = false,
+                    stream::Update::Tick(tick) => {
+                        for stock in self.stocks.iter_mut() {
+                            if tick.symbol.eq_ignore_ascii_case(stock.symbol()) {
+                                stock.apply_tick(&tick);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Only poll for quotes while the stream is down, or to price stocks
+        // that haven't had a tick yet
+        let pause_quotes = self.streaming
+            && self
+                .stocks
+                .iter()
+                .all(|stock| stock.current_regular_price > 0.0);
+
+        if pause_quotes != self.quotes_paused {
+            if pause_quotes {
+                self.quote_service.pause();
+            } else {
+                self.quote_service.resume();
+            }
+
+            self.quotes_paused = pause_quotes;
+        }
+
+        // A submitted symbol is added once looking up its quote confirms it
+        // exists
+        if let Some(symbol) = self.add_stock.update() {
+            self.submit_symbol(symbol);
+        }
+    }
+
+    /// Adds the symbol entered in the prompt, either as a new stock tab or as
+    /// a comparison on the current one
+    pub fn submit_symbol(&mut self, symbol: String) {
+        if self.mode == Mode::AddComparison {
+            let comparison = self.comparison(symbol);
+
+            if let Some(stock) = self.stocks.get_mut(self.current_tab) {
+                stock.add_comparison(comparison);
+            }
+
+            self.sync_comparison_symbols();
+
+            self.add_stock.reset();
+            self.mode = self.previous_mode;
+        } else {
+            self.push_stock(widget::StockState::new(symbol, self.chart_type));
         }
     }
 
+    /// Adds a new stock tab from the Add Ticker prompt and focuses it
+    pub fn push_stock(&mut self, mut stock: widget::StockState) {
+        stock.set_time_frame(self.time_frame);
+
+        for comparison in stock.comparisons.iter_mut() {
+            *comparison = self.comparison(comparison.symbol.clone());
+        }
+
+        self.stocks.push(stock);
+        self.current_tab = self.stocks.len() - 1;
+        self.sync_quote_symbols();
+        self.sync_comparison_symbols();
+
+        self.add_stock.reset();
+        self.mode = self.previous_mode;
+    }
+
+    /// Keeps the batched quote request and stream subscriptions in line with
+    /// the open stock tabs
+    pub fn sync_quote_symbols(&self) {
+        let symbols: Vec<_> = self.stocks.iter().map(|s| s.symbol().to_string()).collect();
+
+        if let Some(stream_service) = self.stream_service.as_ref() {
+            stream_service.set_symbols(symbols.clone());
+        }
+
+        self.quote_service.set_symbols(symbols);
+    }
+
+    /// Keeps the comparison prices fetched in line with the symbols compared
+    /// against by any stock
+    pub fn sync_comparison_symbols(&mut self) {
+        let symbols = self
+            .stocks
+            .iter()
+            .flat_map(|stock| stock.comparisons.iter().map(|c| c.symbol.clone()))
+            .collect();
+
+        self.comparison_service.set_symbols(symbols);
+    }
+
+    /// Comparison against `symbol`, starting with the prices already fetched
+    /// for another stock if any
+    fn comparison(&self, symbol: String) -> widget::Comparison {
+        self.stocks
+            .iter()
+            .flat_map(|stock| stock.comparisons.iter())
+            .find(|c| c.symbol == symbol)
+            .cloned()
+            .unwrap_or_else(|| widget::Comparison::new(symbol))
+    }
+
     pub fn time_frame_up(&mut self) {
         self.set_time_frame(self.time_frame.up());
     }
@@ -53,6 +246,33 @@ impl App {
         for stock in self.stocks.iter_mut() {
             stock.set_time_frame(time_frame);
         }
+
+        self.comparison_service.set_time_frame(time_frame);
+    }
+
+    /// Switches to a date range entered from the prompt. It gets its own tab
+    /// after those from `config.yml`, replaced by the next one entered
+    pub fn set_date_range(&mut self, date_range: CustomTimeFrame) {
+        let idx = OPTS.time_frames.len();
+
+        {
+            let mut custom_time_frames = CUSTOM_TIME_FRAMES.write();
+            custom_time_frames.truncate(idx);
+            custom_time_frames.push(date_range);
+        }
+
+        let time_frame = TimeFrame::Custom(idx);
+
+        for stock in self.stocks.iter_mut() {
+            stock.prices.remove(&time_frame);
+
+            for comparison in stock.comparisons.iter_mut() {
+                comparison.prices.remove(&time_frame);
+            }
+        }
+
+        self.set_time_frame(time_frame);
+        self.mode = self.previous_mode;
     }
 }
 
@@ -0,0 +1,230 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{format_err, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{Price, TimeFrame};
+use crate::provider::ProviderKind;
+use crate::OPTS;
+
+/// Cached prices are fully refetched after this long (seconds), so adjustments
+/// to older prices such as splits still make it into the chart
+const MAX_AGE: i64 = 60 * 60 * 24 * 7;
+
+/// Prices of one symbol and time frame cached on disk, so only data newer than
+/// what's cached needs to be fetched
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PriceCache {
+    /// When the full range was last fetched
+    fetched: i64,
+    /// Number of prices in the full range, which the series is kept trimmed to
+    /// as it's extended
+    window: usize,
+    pub prices: Vec<Price>,
+    /// Close before the first cached price
+    #[serde(default)]
+    pub prev_close: Option<f64>,
+}
+
+impl PriceCache {
+    pub fn new(prices: Vec<Price>, prev_close: f64) -> PriceCache {
+        PriceCache {
+            fetched: Utc::now().timestamp(),
+            window: prices.len(),
+            prices,
+            prev_close: Some(prev_close),
+        }
+    }
+
+    /// The 1D session changes as a whole so is always fetched in full, as are
+    /// time frames that aren't a fixed length window such as YTD, MAX and
+    /// custom ones. Recorded data isn't cached so it never gets mixed in with
+    /// live data
+    pub fn enabled(time_frame: TimeFrame) -> bool {
+        !matches!(
+            time_frame,
+            TimeFrame::Day1 | TimeFrame::Ytd | TimeFrame::Max | TimeFrame::Custom(_)
+        ) && OPTS.provider.unwrap_or(ProviderKind::Yahoo) == ProviderKind::Yahoo
+    }
+
+    /// Cached prices for `symbol`, unless there are none or they're due a full
+    /// refetch
+    pub fn load(symbol: &str, time_frame: TimeFrame) -> Option<PriceCache> {
+        let bytes = fs::read(path(symbol, time_frame)?).ok()?;
+        let cache = serde_json::from_slice::<PriceCache>(&bytes).ok()?;
+
+        cache.is_usable(Utc::now().timestamp()).then_some(cache)
+    }
+
+    /// Whether there's anything cached and it isn't due a full refetch as of
+    /// `now`
+    fn is_usable(&self, now: i64) -> bool {
+        now - self.fetched <= MAX_AGE && !self.prices.is_empty()
+    }
+
+    pub fn save(&self, symbol: &str, time_frame: TimeFrame) -> Result<()> {
+        let path =
+            path(symbol, time_frame).ok_or_else(|| format_err!("Could not get cache directory"))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_vec(self)?)?;
+
+        Ok(())
+    }
+
+    /// Timestamp to fetch missing prices from. Starts a bar before the last
+    /// cached one, since that bar may have still been forming when cached
+    pub fn since(&self, time_frame: TimeFrame) -> i64 {
+        let last = self
+            .prices
+            .last()
+            .map(|price| price.date)
+            .unwrap_or_default();
+
+        last - time_frame.round_by()
+    }
+
+    /// Replaces cached prices from the start of `tail` onwards, dropping the
+    /// oldest to keep the series the same length as the full range
+    pub fn extend(&mut self, tail: Vec<Price>) {
+        let Some(start) = tail.first().map(|price| price.date) else {
+            return;
+        };
+
+        self.prices.retain(|price| price.date < start);
+        self.prices.extend(tail);
+
+        let excess = self.prices.len().saturating_sub(self.window);
+
+        if let Some(prev_close) = self.prices[..excess]
+            .iter()
+            .rev()
+            .find(|price| price.close > 0.0)
+        {
+            self.prev_close = Some(prev_close.close);
+        }
+
+        self.prices.drain(..excess);
+    }
+}
+
+fn path(symbol: &str, time_frame: TimeFrame) -> Option<PathBuf> {
+    let symbol = symbol.replace(['/', '\\'], "_");
+
+    let file_name = format!(
+        "{}_{}.json",
+        time_frame.tab_name(),
+        time_frame.api_interval()
+    );
+
+    Some(
+        dirs_next::cache_dir()?
+            .join("tickrs")
+            .join("prices")
+            .join(symbol)
+            .join(file_name),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 60 * 60 * 24;
+
+    fn price(date: i64, close: f64) -> Price {
+        Price {
+            close,
+            open: close,
+            high: close,
+            low: close,
+            volume: 100,
+            date,
+        }
+    }
+
+    fn prices(days: &[(i64, f64)]) -> Vec<Price> {
+        days.iter()
+            .map(|(day, close)| price(day * DAY, *close))
+            .collect()
+    }
+
+    fn dates_closes(cache: &PriceCache) -> Vec<(i64, f64)> {
+        cache
+            .prices
+            .iter()
+            .map(|price| (price.date / DAY, price.close))
+            .collect()
+    }
+
+    #[test]
+    fn test_extend_replaces_tail() {
+        let mut cache = PriceCache::new(prices(&[(1, 10.0), (2, 11.0), (3, 12.0)]), 9.0);
+
+        // The last cached bar was still forming, so it's replaced from the
+        // first bar fetched onwards
+        cache.extend(prices(&[(3, 12.5)]));
+
+        assert_eq!(dates_closes(&cache), vec![(1, 10.0), (2, 11.0), (3, 12.5)]);
+        assert_eq!(cache.prev_close, Some(9.0));
+    }
+
+    #[test]
+    fn test_extend_trims_to_window() {
+        let mut cache = PriceCache::new(prices(&[(1, 10.0), (2, 11.0), (3, 12.0)]), 9.0);
+
+        cache.extend(prices(&[(3, 12.5), (4, 13.0), (5, 14.0)]));
+
+        assert_eq!(dates_closes(&cache), vec![(3, 12.5), (4, 13.0), (5, 14.0)]);
+        // Close of the last bar dropped, now before the first one kept
+        assert_eq!(cache.prev_close, Some(11.0));
+    }
+
+    #[test]
+    fn test_extend_prev_close_skips_empty_bars() {
+        let mut cache = PriceCache::new(prices(&[(1, 10.0), (2, 0.0), (3, 12.0)]), 9.0);
+
+        cache.extend(prices(&[(4, 13.0), (5, 14.0)]));
+
+        assert_eq!(dates_closes(&cache), vec![(3, 12.0), (4, 13.0), (5, 14.0)]);
+        assert_eq!(cache.prev_close, Some(10.0));
+    }
+
+    #[test]
+    fn test_extend_empty_tail() {
+        let mut cache = PriceCache::new(prices(&[(1, 10.0), (2, 11.0)]), 9.0);
+
+        cache.extend(vec![]);
+
+        assert_eq!(dates_closes(&cache), vec![(1, 10.0), (2, 11.0)]);
+        assert_eq!(cache.prev_close, Some(9.0));
+    }
+
+    #[test]
+    fn test_since() {
+        let cache = PriceCache::new(prices(&[(1, 10.0), (2, 11.0)]), 9.0);
+
+        // One bar before the last cached one
+        assert_eq!(cache.since(TimeFrame::Year1), DAY);
+
+        let cache = PriceCache::new(vec![price(DAY, 10.0), price(DAY + 1800, 11.0)], 9.0);
+        assert_eq!(cache.since(TimeFrame::Month1), DAY);
+    }
+
+    #[test]
+    fn test_is_usable() {
+        let cache = PriceCache::new(prices(&[(1, 10.0)]), 9.0);
+
+        assert!(cache.is_usable(cache.fetched));
+        assert!(cache.is_usable(cache.fetched + MAX_AGE));
+        assert!(!cache.is_usable(cache.fetched + MAX_AGE + 1));
+
+        let empty = PriceCache::new(vec![], 9.0);
+        assert!(!empty.is_usable(empty.fetched));
+    }
+}
@@ -2,40 +2,75 @@ use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use std::time::Duration;
 
-use chrono::{Local, TimeZone, Utc};
+use chrono::{Local, Months, NaiveDate, TimeZone, Utc};
 use itertools::izip;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tickrs_api::Interval;
 
 use crate::api::model::ChartData;
 use crate::api::Range;
+use crate::CUSTOM_TIME_FRAMES;
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Deserialize)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Hash, Deserialize)]
 pub enum ChartType {
+    #[default]
     #[serde(rename = "line")]
     Line,
+    #[serde(rename = "area")]
+    Area,
     #[serde(rename = "candle")]
     Candlestick,
+    #[serde(rename = "ohlc")]
+    Ohlc,
+    #[serde(rename = "heikin_ashi")]
+    HeikinAshi,
     #[serde(rename = "kagi")]
     Kagi,
+    #[serde(rename = "renko")]
+    Renko,
+    #[serde(rename = "pnf")]
+    PointFigure,
 }
 
 impl ChartType {
     pub fn toggle(self) -> Self {
         match self {
-            ChartType::Line => ChartType::Candlestick,
-            ChartType::Candlestick => ChartType::Kagi,
-            ChartType::Kagi => ChartType::Line,
+            ChartType::Line => ChartType::Area,
+            ChartType::Area => ChartType::Candlestick,
+            ChartType::Candlestick => ChartType::Ohlc,
+            ChartType::Ohlc => ChartType::HeikinAshi,
+            ChartType::HeikinAshi => ChartType::Kagi,
+            ChartType::Kagi => ChartType::Renko,
+            ChartType::Renko => ChartType::PointFigure,
+            ChartType::PointFigure => ChartType::Line,
         }
     }
 
     pub fn as_str(self) -> &'static str {
         match self {
             ChartType::Line => "Line",
+            ChartType::Area => "Area",
             ChartType::Candlestick => "Candle",
+            ChartType::Ohlc => "OHLC",
+            ChartType::HeikinAshi => "Heikin",
             ChartType::Kagi => "Kagi",
+            ChartType::Renko => "Renko",
+            ChartType::PointFigure => "P&F",
         }
     }
+
+    /// Whether the x-axis is time, so volumes, overlays and oscillators line
+    /// up with it. Kagi, Renko and Point & Figure only move with price
+    pub fn is_time_based(self) -> bool {
+        matches!(
+            self,
+            ChartType::Line
+                | ChartType::Area
+                | ChartType::Candlestick
+                | ChartType::Ohlc
+                | ChartType::HeikinAshi
+        )
+    }
 }
 
 impl FromStr for ChartType {
@@ -46,12 +81,58 @@ impl FromStr for ChartType {
 
         match s {
             "line" => Ok(Line),
+            "area" => Ok(Area),
             "candle" => Ok(Candlestick),
+            "ohlc" => Ok(Ohlc),
+            "heikin_ashi" => Ok(HeikinAshi),
             "kagi" => Ok(Kagi),
-            _ => Err("Valid chart types are: 'line', 'candle', 'kagi'"),
+            "renko" => Ok(Renko),
+            "pnf" => Ok(PointFigure),
+            _ => Err(
+                "Valid chart types are: 'line', 'area', 'candle', 'ohlc', 'heikin_ashi', 'kagi', \
+                 'renko', 'pnf'",
+            ),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Deserialize)]
+pub enum Oscillator {
+    #[serde(rename = "rsi")]
+    Rsi,
+    #[serde(rename = "macd")]
+    Macd,
+    #[serde(rename = "stochastic")]
+    Stochastic,
+}
+
+impl Oscillator {
+    /// Next oscillator to show, with `None` hiding the panel
+    pub fn cycle(oscillator: Option<Self>) -> Option<Self> {
+        match oscillator {
+            None => Some(Oscillator::Rsi),
+            Some(Oscillator::Rsi) => Some(Oscillator::Macd),
+            Some(Oscillator::Macd) => Some(Oscillator::Stochastic),
+            Some(Oscillator::Stochastic) => None,
         }
     }
 }
+
+impl FromStr for Oscillator {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Oscillator::*;
+
+        match s {
+            "rsi" => Ok(Rsi),
+            "macd" => Ok(Macd),
+            "stochastic" => Ok(Stochastic),
+            _ => Err("Valid oscillators are: 'rsi', 'macd', 'stochastic'"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialOrd, Debug, Hash, PartialEq, Eq, Deserialize, Ord)]
 pub enum TimeFrame {
     #[serde(alias = "1D")]
@@ -64,10 +145,22 @@ pub enum TimeFrame {
     Month3,
     #[serde(alias = "6M")]
     Month6,
+    #[serde(alias = "YTD")]
+    Ytd,
     #[serde(alias = "1Y")]
     Year1,
+    #[serde(alias = "2Y")]
+    Year2,
     #[serde(alias = "5Y")]
     Year5,
+    #[serde(alias = "10Y")]
+    Year10,
+    #[serde(alias = "MAX")]
+    Max,
+    /// Index into `CUSTOM_TIME_FRAMES`, of a time frame from `time_frames` in
+    /// `config.yml` or the date range entered with `r`
+    #[serde(skip)]
+    Custom(usize),
 }
 
 impl FromStr for TimeFrame {
@@ -82,85 +175,123 @@ impl FromStr for TimeFrame {
             "1M" => Ok(Month1),
             "3M" => Ok(Month3),
             "6M" => Ok(Month6),
+            "YTD" => Ok(Ytd),
             "1Y" => Ok(Year1),
+            "2Y" => Ok(Year2),
             "5Y" => Ok(Year5),
-            _ => Err("Valid time frames are: '1D', '1W', '1M', '3M', '6M', '1Y', '5Y'"),
+            "10Y" => Ok(Year10),
+            "MAX" => Ok(Max),
+            _ => Err(
+                "Valid time frames are: '1D', '1W', '1M', '3M', '6M', 'YTD', '1Y', '2Y', '5Y', \
+                 '10Y', 'MAX'",
+            ),
         }
     }
 }
 
 impl TimeFrame {
-    pub fn idx(self) -> usize {
-        match self {
-            TimeFrame::Day1 => 0,
-            TimeFrame::Week1 => 1,
-            TimeFrame::Month1 => 2,
-            TimeFrame::Month3 => 3,
-            TimeFrame::Month6 => 4,
-            TimeFrame::Year1 => 5,
-            TimeFrame::Year5 => 6,
-        }
-    }
-
-    pub const fn tab_names() -> [&'static str; 7] {
-        ["1D", "1W", "1M", "3M", "6M", "1Y", "5Y"]
-    }
-
-    pub const ALL: [TimeFrame; 7] = [
+    pub const ALL: [TimeFrame; 11] = [
         TimeFrame::Day1,
         TimeFrame::Week1,
         TimeFrame::Month1,
         TimeFrame::Month3,
         TimeFrame::Month6,
+        TimeFrame::Ytd,
         TimeFrame::Year1,
+        TimeFrame::Year2,
         TimeFrame::Year5,
+        TimeFrame::Year10,
+        TimeFrame::Max,
     ];
 
-    pub fn update_interval(self) -> Duration {
+    /// Built in time frames followed by any custom ones
+    pub fn all() -> Vec<TimeFrame> {
+        let num_custom = CUSTOM_TIME_FRAMES.read().len();
+
+        TimeFrame::ALL
+            .into_iter()
+            .chain((0..num_custom).map(TimeFrame::Custom))
+            .collect()
+    }
+
+    pub fn idx(self) -> usize {
+        TimeFrame::all()
+            .iter()
+            .position(|time_frame| *time_frame == self)
+            .unwrap_or_default()
+    }
+
+    pub fn tab_names() -> Vec<String> {
+        TimeFrame::all().into_iter().map(Self::tab_name).collect()
+    }
+
+    pub fn tab_name(self) -> String {
         match self {
-            TimeFrame::Day1 => Duration::from_secs(60),
-            TimeFrame::Week1 => Duration::from_secs(60 * 5),
-            TimeFrame::Month1 => Duration::from_secs(60 * 30),
-            TimeFrame::Month3 => Duration::from_secs(60 * 60),
-            TimeFrame::Month6 => Duration::from_secs(60 * 60),
-            TimeFrame::Year1 => Duration::from_secs(60 * 60 * 24),
-            TimeFrame::Year5 => Duration::from_secs(60 * 60 * 24),
+            TimeFrame::Day1 => "1D".into(),
+            TimeFrame::Week1 => "1W".into(),
+            TimeFrame::Month1 => "1M".into(),
+            TimeFrame::Month3 => "3M".into(),
+            TimeFrame::Month6 => "6M".into(),
+            TimeFrame::Ytd => "YTD".into(),
+            TimeFrame::Year1 => "1Y".into(),
+            TimeFrame::Year2 => "2Y".into(),
+            TimeFrame::Year5 => "5Y".into(),
+            TimeFrame::Year10 => "10Y".into(),
+            TimeFrame::Max => "MAX".into(),
+            TimeFrame::Custom(_) => self.custom().map(|custom| custom.name).unwrap_or_default(),
         }
     }
 
-    pub fn up(self) -> TimeFrame {
+    pub fn custom(self) -> Option<CustomTimeFrame> {
         match self {
-            TimeFrame::Day1 => TimeFrame::Week1,
-            TimeFrame::Week1 => TimeFrame::Month1,
-            TimeFrame::Month1 => TimeFrame::Month3,
-            TimeFrame::Month3 => TimeFrame::Month6,
-            TimeFrame::Month6 => TimeFrame::Year1,
-            TimeFrame::Year1 => TimeFrame::Year5,
-            TimeFrame::Year5 => TimeFrame::Day1,
+            TimeFrame::Custom(idx) => CUSTOM_TIME_FRAMES.read().get(idx).cloned(),
+            _ => None,
         }
     }
 
-    pub fn down(self) -> TimeFrame {
-        match self {
-            TimeFrame::Day1 => TimeFrame::Year5,
-            TimeFrame::Week1 => TimeFrame::Day1,
-            TimeFrame::Month1 => TimeFrame::Week1,
-            TimeFrame::Month3 => TimeFrame::Month1,
-            TimeFrame::Month6 => TimeFrame::Month3,
-            TimeFrame::Year1 => TimeFrame::Month6,
-            TimeFrame::Year5 => TimeFrame::Year1,
+    /// Whether this is a date range that's already ended
+    pub fn has_ended(self) -> bool {
+        self.custom().is_some_and(|custom| custom.has_ended())
+    }
+
+    /// How often to refetch prices, at most daily. A date range that's already
+    /// ended is only fetched once
+    pub fn update_interval(self) -> Option<Duration> {
+        if self.has_ended() {
+            return None;
         }
+
+        Some(Duration::from_secs(self.round_by().min(60 * 60 * 24) as u64))
+    }
+
+    pub fn up(self) -> TimeFrame {
+        let all = TimeFrame::all();
+
+        all[(self.idx() + 1) % all.len()]
+    }
+
+    pub fn down(self) -> TimeFrame {
+        let all = TimeFrame::all();
+
+        all[(self.idx() + all.len() - 1) % all.len()]
     }
 
-    pub fn as_range(self) -> Range {
+    /// Range to fetch, or `None` for custom time frames which are fetched
+    /// between the dates of their span instead
+    pub fn as_range(self) -> Option<Range> {
         match self {
-            TimeFrame::Day1 => Range::Day1,
-            TimeFrame::Week1 => Range::Day5,
-            TimeFrame::Month1 => Range::Month1,
-            TimeFrame::Month3 => Range::Month3,
-            TimeFrame::Month6 => Range::Month6,
-            TimeFrame::Year1 => Range::Year1,
-            TimeFrame::Year5 => Range::Year5,
+            TimeFrame::Day1 => Some(Range::Day1),
+            TimeFrame::Week1 => Some(Range::Day5),
+            TimeFrame::Month1 => Some(Range::Month1),
+            TimeFrame::Month3 => Some(Range::Month3),
+            TimeFrame::Month6 => Some(Range::Month6),
+            TimeFrame::Ytd => Some(Range::Ytd),
+            TimeFrame::Year1 => Some(Range::Year1),
+            TimeFrame::Year2 => Some(Range::Year2),
+            TimeFrame::Year5 => Some(Range::Year5),
+            TimeFrame::Year10 => Some(Range::Year10),
+            TimeFrame::Max => Some(Range::Max),
+            TimeFrame::Custom(_) => None,
         }
     }
 
@@ -171,18 +302,52 @@ impl TimeFrame {
             TimeFrame::Month1 => Interval::Minute30,
             TimeFrame::Month3 => Interval::Minute60,
             TimeFrame::Month6 => Interval::Minute60,
+            TimeFrame::Year10 => Interval::Week1,
+            TimeFrame::Max => Interval::Month1,
+            TimeFrame::Custom(_) => self
+                .custom()
+                .map(|custom| custom.interval)
+                .unwrap_or(Interval::Day1),
             _ => Interval::Day1,
         }
     }
 
+    /// Seconds between data points
     pub fn round_by(self) -> i64 {
+        const DAY: i64 = 60 * 60 * 24;
+
+        match self.api_interval() {
+            Interval::Minute1 => 60,
+            Interval::Minute2 => 60 * 2,
+            Interval::Minute5 => 60 * 5,
+            Interval::Minute15 => 60 * 15,
+            Interval::Minute30 => 60 * 30,
+            Interval::Minute60 | Interval::Hour1 => 60 * 60,
+            Interval::Minute90 => 60 * 90,
+            Interval::Day1 => DAY,
+            Interval::Day5 => DAY * 5,
+            Interval::Week1 => DAY * 7,
+            Interval::Month1 => DAY * 30,
+            Interval::Month3 => DAY * 90,
+        }
+    }
+
+    /// Whether dates need the time of day, for time frames of only a few days
+    fn shows_time(self) -> bool {
         match self {
-            TimeFrame::Day1 => 60,
-            TimeFrame::Week1 => 60 * 5,
-            TimeFrame::Month1 => 60 * 30,
-            TimeFrame::Month3 => 60 * 60,
-            TimeFrame::Month6 => 60 * 60,
-            _ => 60 * 60 * 24,
+            TimeFrame::Week1 => true,
+            TimeFrame::Custom(_) => self.round_by() < 60 * 30,
+            _ => false,
+        }
+    }
+
+    /// Width of the y label column when x labels are shown, so the first x
+    /// label fits under it
+    pub fn y_label_width(self) -> u16 {
+        match self {
+            TimeFrame::Day1 => 9,
+            _ if self.shows_time() => 12,
+            _ => 11,
         }
     }
 
@@ -192,7 +357,7 @@ impl TimeFrame {
 
         let fmt = match self {
             TimeFrame::Day1 => "%H:%M",
-            TimeFrame::Week1 => "%m-%d %H:%M",
+            _ if self.shows_time() => "%m-%d %H:%M",
             _ => "%F",
         };
 
@@ -200,6 +365,150 @@ impl TimeFrame {
     }
 }
 
+/// Time frame from `time_frames` in `config.yml` or entered with `r`, written
+/// as `<span> at <interval>`. The span is how far back to go, such as `3D`,
+/// `2W`, `6M` or `20Y`, or a date range such as `2020-01-01..2020-06-30`. A
+/// date range can leave out the interval to have one picked from its length
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct CustomTimeFrame {
+    pub name: String,
+    pub span: Span,
+    pub interval: Interval,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Span {
+    /// Up to now, going back this many days
+    Days(u32),
+    /// Up to now, going back this many months
+    Months(u32),
+    /// Between two unix timestamps
+    Dates(i64, i64),
+}
+
+impl CustomTimeFrame {
+    /// Start and end to fetch prices between, as unix timestamps
+    pub fn period(&self) -> (i64, i64) {
+        let now = Local::now();
+
+        let start = match self.span {
+            Span::Days(days) => now - chrono::Duration::days(days as i64),
+            Span::Months(months) => now
+                .checked_sub_months(Months::new(months))
+                .unwrap_or_default(),
+            Span::Dates(start, end) => return (start, end),
+        };
+
+        (start.timestamp(), now.timestamp())
+    }
+
+    pub fn has_ended(&self) -> bool {
+        matches!(self.span, Span::Dates(_, end) if end < Utc::now().timestamp())
+    }
+}
+
+impl FromStr for CustomTimeFrame {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, interval) = match s.split_once(" at ") {
+            Some((name, interval)) => (name.trim(), Some(interval.trim())),
+            None => (s.trim(), None),
+        };
+
+        let span = parse_span(name)?;
+
+        let interval = match (interval, span) {
+            (Some(interval), _) => interval.parse()?,
+            (None, Span::Dates(start, end)) => default_interval(start, end),
+            (None, _) => {
+                return Err(format!(
+                    "Missing interval for '{}', such as '{} at 1d'",
+                    name, name
+                ))
+            }
+        };
+
+        Ok(CustomTimeFrame {
+            name: name.to_ascii_uppercase(),
+            span,
+            interval,
+        })
+    }
+}
+
+impl TryFrom<String> for CustomTimeFrame {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+fn parse_span(span: &str) -> Result<Span, String> {
+    if let Some((start, end)) = span.split_once("..") {
+        let (start, end) = (parse_date(start)?, parse_date(end)? + 60 * 60 * 24);
+
+        if start >= end {
+            return Err(format!("'{}' ends before it starts", span));
+        }
+
+        return Ok(Span::Dates(start, end));
+    }
+
+    let invalid = || {
+        format!(
+            "'{}' isn't a span such as 3D, 2W, 6M, 20Y or 2020-01-01..2020-06-30",
+            span
+        )
+    };
+
+    let unit = span.chars().last().ok_or_else(invalid)?;
+    let count = span[..span.len() - unit.len_utf8()]
+        .parse::<u32>()
+        .ok()
+        .filter(|count| *count > 0)
+        .ok_or_else(invalid)?;
+
+    match unit.to_ascii_uppercase() {
+        'D' => Ok(Span::Days(count)),
+        'W' => count.checked_mul(7).map(Span::Days).ok_or_else(invalid),
+        'M' => Ok(Span::Months(count)),
+        'Y' => count.checked_mul(12).map(Span::Months).ok_or_else(invalid),
+        _ => Err(invalid()),
+    }
+}
+
+/// Start of `date` in local time, as a unix timestamp
+fn parse_date(date: &str) -> Result<i64, String> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|date| Local.from_local_datetime(&date).earliest())
+        .map(|date| date.timestamp())
+        .ok_or_else(|| format!("'{}' isn't a date such as 2020-01-31", date.trim()))
+}
+
+/// Finest interval that keeps a date range to a reasonable number of points.
+/// Intraday prices are only available for the last 60 days
+fn default_interval(start: i64, end: i64) -> Interval {
+    const DAY: i64 = 60 * 60 * 24;
+
+    let days = (end - start) / DAY;
+    let age = (Utc::now().timestamp() - start) / DAY;
+
+    if days <= 7 && age < 60 {
+        Interval::Minute5
+    } else if days <= 365 * 2 {
+        Interval::Day1
+    } else if days <= 365 * 10 {
+        Interval::Week1
+    } else {
+        Interval::Month1
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct MarketHours(pub i64, pub i64);
 
@@ -233,7 +542,7 @@ pub enum TradingPeriod {
     Post,
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct Price {
     pub close: f64,
     pub volume: u64,
@@ -309,6 +618,19 @@ pub fn zeros_as_pre(prices: &mut [f64]) {
     }
 }
 
+/// Abbreviates large volumes, i.e. 69864123 -> 69.86M
+pub fn format_volume(volume: u64) -> String {
+    const UNITS: [(f64, &str); 4] = [(1e12, "T"), (1e9, "B"), (1e6, "M"), (1e3, "k")];
+
+    let value = volume as f64;
+
+    UNITS
+        .iter()
+        .find(|(size, _)| value >= *size)
+        .map(|(size, unit)| format!("{:.2}{}", value / size, unit))
+        .unwrap_or_else(|| volume.to_string())
+}
+
 pub fn format_decimals(value: f64) -> String {
     let abs = value.abs();
 
@@ -323,3 +645,108 @@ pub fn format_decimals(value: f64) -> String {
         format!("{:.*}", n, value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Days;
+
+    use super::*;
+
+    const DAY: i64 = 60 * 60 * 24;
+
+    #[test]
+    fn test_time_frame_names() {
+        for time_frame in TimeFrame::ALL {
+            assert_eq!(time_frame.tab_name().parse(), Ok(time_frame));
+        }
+    }
+
+    #[test]
+    fn test_custom_time_frame() {
+        let custom = "3D at 2m".parse::<CustomTimeFrame>().unwrap();
+        assert_eq!(custom.name, "3D");
+        assert_eq!(custom.span, Span::Days(3));
+        assert_eq!(custom.interval, Interval::Minute2);
+
+        let custom = "20y at 1wk".parse::<CustomTimeFrame>().unwrap();
+        assert_eq!(custom.name, "20Y");
+        assert_eq!(custom.span, Span::Months(240));
+        assert_eq!(custom.interval, Interval::Week1);
+
+        let custom = "2W at 1h".parse::<CustomTimeFrame>().unwrap();
+        assert_eq!(custom.span, Span::Days(14));
+        assert_eq!(custom.interval, Interval::Hour1);
+    }
+
+    #[test]
+    fn test_custom_time_frame_invalid() {
+        for s in [
+            "3D",
+            "3D at",
+            "3D at 7m",
+            "0D at 1d",
+            "D at 1d",
+            "3X at 1d",
+            "-3D at 1d",
+            "",
+            "1000000000W at 1d",
+            "400000000Y at 1mo",
+        ] {
+            assert!(s.parse::<CustomTimeFrame>().is_err(), "{}", s);
+        }
+
+        assert_eq!(
+            "6M".parse::<CustomTimeFrame>().unwrap_err(),
+            "Missing interval for '6M', such as '6M at 1d'"
+        );
+    }
+
+    #[test]
+    fn test_date_range() {
+        let custom = "2020-01-01..2020-01-31 at 1d"
+            .parse::<CustomTimeFrame>()
+            .unwrap();
+        assert_eq!(custom.interval, Interval::Day1);
+
+        // Through the end of the last day
+        let Span::Dates(start, end) = custom.span else {
+            panic!("{:?} isn't a date range", custom.span);
+        };
+        assert_eq!(start, parse_date("2020-01-01").unwrap());
+        assert_eq!(end - start, 31 * DAY);
+        assert_eq!(custom.period(), (start, end));
+        assert!(custom.has_ended());
+
+        // A single day is a valid range
+        assert!("2020-01-06..2020-01-06".parse::<CustomTimeFrame>().is_ok());
+
+        for s in [
+            "2020-01-31..2020-01-01",
+            "2020-01-01..2020-13-01",
+            "2020-01-01..",
+            "..2020-01-01",
+            "2020-01-01..2020-01-31 at 7m",
+        ] {
+            assert!(s.parse::<CustomTimeFrame>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_default_interval() {
+        let interval = |s: &str| s.parse::<CustomTimeFrame>().unwrap().interval;
+
+        // Intraday prices are too old for a short range years ago
+        assert_eq!(interval("2020-01-06..2020-01-10"), Interval::Day1);
+        assert_eq!(interval("2019-01-01..2020-06-30"), Interval::Day1);
+        assert_eq!(interval("2010-01-01..2018-12-31"), Interval::Week1);
+        assert_eq!(interval("1990-01-01..2019-12-31"), Interval::Month1);
+
+        let today = Local::now().date_naive();
+        let recent = format!(
+            "{}..{}",
+            today.checked_sub_days(Days::new(3)).unwrap(),
+            today
+        );
+        assert_eq!(interval(&recent), Interval::Minute5);
+    }
+}
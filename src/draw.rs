@@ -1,5 +1,7 @@
+use chrono::Utc;
 use ratatui::backend::Backend;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Tabs, Wrap};
 use ratatui::{Frame, Terminal};
@@ -8,11 +10,13 @@ use crate::app::{App, Mode, ScrollDirection};
 use crate::common::TimeFrame;
 use crate::service::Service;
 use crate::theme::style;
+use crate::widget::options::GREEK_COLUMNS_WIDTH;
 use crate::widget::{
-    block, AddStockWidget, ChartConfigurationWidget, OptionsWidget, StockSummaryWidget,
-    StockWidget, HELP_HEIGHT, HELP_WIDTH,
+    block, AddStockWidget, AlertHistoryWidget, AlertsWidget, ChartConfigurationWidget,
+    OptionsChainWidget, OptionsInterestWidget, OptionsStrategyWidget, OptionsVolatilityWidget,
+    OptionsWidget, PromptWidget, StockSummaryWidget, StockWidget, HELP_HEIGHT, HELP_WIDTH,
 };
-use crate::THEME;
+use crate::{alert, SHOW_GREEKS, THEME};
 
 pub fn draw(terminal: &mut Terminal<impl Backend>, app: &mut App) {
     let current_size = terminal.size().unwrap_or_default();
@@ -30,14 +34,14 @@ pub fn draw(terminal: &mut Terminal<impl Backend>, app: &mut App) {
             // Set background color
             frame.render_widget(Block::default().style(style()), frame.size());
 
-            if app.debug.enabled && app.mode == Mode::AddStock {
+            if app.debug.enabled && is_prompt(app.mode) {
                 // layout[0] - Main window
-                // layout[1] - Add Stock window
+                // layout[1] - Add Stock / Date Range window
                 // layout[2] - Debug window
                 let layout = Layout::default()
                     .constraints([
                         Constraint::Min(0),
-                        Constraint::Length(3),
+                        Constraint::Length(prompt_height(app)),
                         Constraint::Length(5),
                     ])
                     .split(frame.size());
@@ -49,7 +53,7 @@ pub fn draw(terminal: &mut Terminal<impl Backend>, app: &mut App) {
                     }
                 }
 
-                draw_add_stock(frame, app, layout[1]);
+                draw_prompt(frame, app, layout[1]);
                 draw_debug(frame, app, layout[2]);
             } else if app.debug.enabled {
                 // layout[0] - Main window
@@ -61,15 +65,16 @@ pub fn draw(terminal: &mut Terminal<impl Backend>, app: &mut App) {
                 match app.mode {
                     Mode::DisplaySummary => draw_summary(frame, app, layout[0]),
                     Mode::Help => draw_help(frame, app, layout[0]),
+                    Mode::AlertHistory => draw_alert_history(frame, app, layout[0]),
                     _ => draw_main(frame, app, layout[0]),
                 }
 
                 draw_debug(frame, app, layout[1]);
-            } else if app.mode == Mode::AddStock {
+            } else if is_prompt(app.mode) {
                 // layout[0] - Main window
-                // layout[1] - Add Stock window
+                // layout[1] - Add Stock / Date Range window
                 let layout = Layout::default()
-                    .constraints([Constraint::Min(0), Constraint::Length(3)])
+                    .constraints([Constraint::Min(0), Constraint::Length(prompt_height(app))])
                     .split(frame.size());
 
                 if !app.stocks.is_empty() {
@@ -79,7 +84,7 @@ pub fn draw(terminal: &mut Terminal<impl Backend>, app: &mut App) {
                     }
                 }
 
-                draw_add_stock(frame, app, layout[1]);
+                draw_prompt(frame, app, layout[1]);
             } else {
                 // layout - Main window
                 let layout = frame.size();
@@ -87,6 +92,7 @@ pub fn draw(terminal: &mut Terminal<impl Backend>, app: &mut App) {
                 match app.mode {
                     Mode::DisplaySummary => draw_summary(frame, app, layout),
                     Mode::Help => draw_help(frame, app, layout),
+                    Mode::AlertHistory => draw_alert_history(frame, app, layout),
                     _ => draw_main(frame, app, layout),
                 }
             };
@@ -119,9 +125,21 @@ fn draw_main(frame: &mut Frame, app: &mut App, area: Rect) {
             split.to_vec()
         };
 
-        // Draw tabs
+        // Draw tabs, flashing those with alerts that haven't been seen
         {
-            let tabs: Vec<_> = app.stocks.iter().map(|w| Line::from(w.symbol())).collect();
+            let flash = Utc::now().timestamp_millis() / 500 % 2 == 0;
+
+            let tabs: Vec<_> = app
+                .stocks
+                .iter()
+                .map(|w| {
+                    if flash && alert::is_unacknowledged(w.symbol()) {
+                        Line::styled(w.symbol(), style().add_modifier(Modifier::REVERSED))
+                    } else {
+                        Line::from(w.symbol())
+                    }
+                })
+                .collect();
 
             frame.render_widget(
                 Tabs::new(tabs)
@@ -156,24 +174,47 @@ fn draw_main(frame: &mut Frame, app: &mut App, area: Rect) {
     if let Some(stock) = app.stocks.get_mut(app.current_tab) {
         // main_chunks[0] - Stock widget
         // main_chunks[1] - Options widget / Configuration widget (optional)
-        let mut main_chunks =
-            if app.mode == Mode::DisplayOptions || app.mode == Mode::ConfigureChart {
-                Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Min(0), Constraint::Length(44)])
-                    .split(layout[1])
-                    .to_vec()
-            } else {
-                vec![layout[1]]
-            };
+        let mut main_chunks = if has_pane(app.mode) {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(0), Constraint::Length(pane_width(app.mode))])
+                .split(layout[1])
+                .to_vec()
+        } else {
+            vec![layout[1]]
+        };
 
         match app.mode {
-            Mode::DisplayStock | Mode::AddStock => {
+            Mode::DisplayStock | Mode::AddStock | Mode::AddComparison | Mode::DateRange => {
                 frame.render_stateful_widget(StockWidget {}, main_chunks[0], stock);
             }
+            Mode::OptionsChain => {
+                if let Some(options) = stock.options.as_mut() {
+                    frame.render_stateful_widget(OptionsChainWidget {}, main_chunks[0], options);
+                }
+            }
+            Mode::OptionsInterest => {
+                if let Some(options) = stock.options.as_mut() {
+                    frame.render_stateful_widget(OptionsInterestWidget {}, main_chunks[0], options);
+                }
+            }
+            Mode::OptionsVolatility => {
+                if let Some(options) = stock.options.as_mut() {
+                    frame.render_stateful_widget(
+                        OptionsVolatilityWidget {},
+                        main_chunks[0],
+                        options,
+                    );
+                }
+            }
+            Mode::OptionsStrategy | Mode::SaveStrategy => {
+                if let Some(options) = stock.options.as_mut() {
+                    frame.render_stateful_widget(OptionsStrategyWidget {}, main_chunks[0], options);
+                }
+            }
             // If width is too small, don't render stock widget and use entire space
             // for options / configure widget
-            Mode::DisplayOptions | Mode::ConfigureChart => {
+            Mode::DisplayOptions | Mode::ConfigureChart | Mode::DisplayAlerts | Mode::AddAlert => {
                 if main_chunks[0].width >= 19 {
                     frame.render_stateful_widget(StockWidget {}, main_chunks[0], stock);
                 } else {
@@ -186,7 +227,7 @@ fn draw_main(frame: &mut Frame, app: &mut App, area: Rect) {
         match app.mode {
             Mode::DisplayOptions => {
                 if let Some(options) = stock.options.as_mut() {
-                    if main_chunks[1].width >= 44 && main_chunks[1].height >= 14 {
+                    if main_chunks[1].width >= 44 && main_chunks[1].height >= 18 {
                         frame.render_stateful_widget(OptionsWidget {}, main_chunks[1], options);
                     } else {
                         let mut padded = main_chunks[1];
@@ -204,6 +245,14 @@ fn draw_main(frame: &mut Frame, app: &mut App, area: Rect) {
                     }
                 }
             }
+            Mode::DisplayAlerts | Mode::AddAlert => {
+                let widget = AlertsWidget {
+                    symbol: stock.symbol(),
+                    alerts: &stock.alerts,
+                };
+
+                frame.render_stateful_widget(widget, main_chunks[1], &mut app.alerts);
+            }
             Mode::ConfigureChart => {
                 if main_chunks[1].width >= 44 && main_chunks[1].height >= 14 {
                     let state = &mut stock.chart_configuration;
@@ -236,8 +285,82 @@ fn draw_main(frame: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
-fn draw_add_stock(frame: &mut Frame, app: &mut App, area: Rect) {
-    frame.render_stateful_widget(AddStockWidget {}, area, &mut app.add_stock);
+/// Whether the mode shows a pane beside the stock
+fn has_pane(mode: Mode) -> bool {
+    matches!(
+        mode,
+        Mode::DisplayOptions | Mode::ConfigureChart | Mode::DisplayAlerts | Mode::AddAlert
+    )
+}
+
+fn pane_width(mode: Mode) -> u16 {
+    if mode == Mode::DisplayOptions && *SHOW_GREEKS.read() {
+        44 + GREEK_COLUMNS_WIDTH
+    } else {
+        44
+    }
+}
+
+/// Whether the mode takes input from a prompt along the bottom
+fn is_prompt(mode: Mode) -> bool {
+    matches!(
+        mode,
+        Mode::AddStock
+            | Mode::AddComparison
+            | Mode::DateRange
+            | Mode::AddAlert
+            | Mode::SaveStrategy
+    )
+}
+
+fn prompt_height(app: &App) -> u16 {
+    match app.mode {
+        Mode::AddStock | Mode::AddComparison => app.add_stock.height(),
+        _ => 3,
+    }
+}
+
+fn draw_prompt(frame: &mut Frame, app: &mut App, area: Rect) {
+    match app.mode {
+        Mode::AddStock => {
+            let widget = AddStockWidget {
+                title: " Add Ticker ",
+            };
+
+            frame.render_stateful_widget(widget, area, &mut app.add_stock);
+        }
+        Mode::AddComparison => {
+            let widget = AddStockWidget {
+                title: " Compare Ticker ",
+            };
+
+            frame.render_stateful_widget(widget, area, &mut app.add_stock);
+        }
+        Mode::AddAlert => {
+            let widget = PromptWidget {
+                title: " Add Alert ",
+                hint: "above 180 | below 150 | up 2% | down 2% | volume 2x",
+            };
+
+            frame.render_stateful_widget(widget, area, &mut app.prompt);
+        }
+        Mode::SaveStrategy => {
+            let widget = PromptWidget {
+                title: " Save Strategy ",
+                hint: "Name to save the strategy to config.yml as",
+            };
+
+            frame.render_stateful_widget(widget, area, &mut app.prompt);
+        }
+        _ => {
+            let widget = PromptWidget {
+                title: " Date Range ",
+                hint: "YYYY-MM-DD..YYYY-MM-DD [at <interval>]",
+            };
+
+            frame.render_stateful_widget(widget, area, &mut app.prompt);
+        }
+    }
 }
 
 fn draw_summary(frame: &mut Frame, app: &mut App, mut area: Rect) {
@@ -367,8 +490,8 @@ fn draw_summary(frame: &mut Frame, app: &mut App, mut area: Rect) {
         layout[2] = current;
 
         let time_frames = TimeFrame::tab_names()
-            .iter()
-            .map(|s| Line::from(*s))
+            .into_iter()
+            .map(Line::from)
             .collect::<Vec<_>>();
 
         // botton_layout[0] - time frame
@@ -431,6 +554,10 @@ fn draw_help(frame: &mut Frame, app: &App, area: Rect) {
     }
 }
 
+fn draw_alert_history(frame: &mut Frame, app: &mut App, area: Rect) {
+    frame.render_stateful_widget(AlertHistoryWidget {}, area, &mut app.alert_history);
+}
+
 fn draw_debug(frame: &mut Frame, app: &mut App, area: Rect) {
     app.debug.mode = app.mode;
 
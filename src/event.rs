@@ -1,24 +1,28 @@
 use app::ScrollDirection;
 use crossbeam_channel::Sender;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
 use crate::app::{self, Mode};
-use crate::common::ChartType;
+use crate::common::Oscillator;
+use crate::strategy::{Side, StrategyName};
 use crate::widget::options;
-use crate::{cleanup_terminal, ENABLE_PRE_POST, SHOW_VOLUMES, SHOW_X_LABELS};
+use crate::{
+    cleanup_terminal, set_mouse_capture, ALERT_LOG, ENABLE_PRE_POST, OSCILLATOR, SHOW_GREEKS,
+    SHOW_VOLUMES, SHOW_X_LABELS,
+};
 
 fn handle_keys_add_stock(keycode: KeyCode, app: &mut app::App) {
     match keycode {
         KeyCode::Enter => {
-            let mut stock = app.add_stock.enter(app.chart_type);
-
-            stock.set_time_frame(app.time_frame);
-
-            app.stocks.push(stock);
-            app.current_tab = app.stocks.len() - 1;
-
-            app.add_stock.reset();
-            app.mode = app.previous_mode;
+            if let Some(symbol) = app.add_stock.enter() {
+                app.submit_symbol(symbol);
+            }
+        }
+        KeyCode::Down => {
+            app.add_stock.select_next();
+        }
+        KeyCode::Up => {
+            app.add_stock.select_prev();
         }
         KeyCode::Char(c) => {
             app.add_stock.add_char(c);
@@ -28,7 +32,7 @@ fn handle_keys_add_stock(keycode: KeyCode, app: &mut app::App) {
         }
         KeyCode::Esc => {
             app.add_stock.reset();
-            if !app.stocks.is_empty() {
+            if !app.stocks.is_empty() || app.mode == Mode::AddComparison {
                 app.mode = app.previous_mode;
             }
         }
@@ -36,8 +40,80 @@ fn handle_keys_add_stock(keycode: KeyCode, app: &mut app::App) {
     }
 }
 
+fn handle_keys_prompt(keycode: KeyCode, app: &mut app::App) {
+    match keycode {
+        KeyCode::Enter => match app.mode {
+            Mode::AddAlert => {
+                if let Some(condition) = app.prompt.enter() {
+                    app.stocks[app.current_tab].add_alert(condition);
+                    app.mode = app.previous_mode;
+                }
+            }
+            Mode::SaveStrategy => {
+                if let Some(StrategyName(name)) = app.prompt.enter() {
+                    let options = app.stocks[app.current_tab].options.as_mut().unwrap();
+
+                    match options.save_strategy(name) {
+                        Ok(()) => app.mode = app.previous_mode,
+                        Err(e) => app.prompt.set_error(format!("Failed to save: {}", e)),
+                    }
+                }
+            }
+            _ => {
+                if let Some(date_range) = app.prompt.enter() {
+                    app.set_date_range(date_range);
+                }
+            }
+        },
+        KeyCode::Char(c) => {
+            app.prompt.add_char(c);
+        }
+        KeyCode::Backspace => {
+            app.prompt.del_char();
+        }
+        KeyCode::Esc => {
+            app.prompt.reset();
+            app.mode = app.previous_mode;
+        }
+        _ => {}
+    }
+}
+
 fn handle_keys_display_stock(keycode: KeyCode, modifiers: KeyModifiers, app: &mut app::App) {
+    let cursor_on = app.stocks[app.current_tab].cursor.is_some();
+
     match (keycode, modifiers) {
+        (KeyCode::Char('m'), KeyModifiers::NONE) => {
+            app.stocks[app.current_tab].toggle_cursor();
+            set_mouse_capture(app.stocks.iter().any(|stock| stock.cursor.is_some()));
+        }
+        (KeyCode::Esc, KeyModifiers::NONE) if cursor_on => {
+            app.stocks[app.current_tab].toggle_cursor();
+            set_mouse_capture(app.stocks.iter().any(|stock| stock.cursor.is_some()));
+        }
+        (KeyCode::Left | KeyCode::Char('h'), KeyModifiers::NONE) if cursor_on => {
+            app.stocks[app.current_tab].cursor_left();
+        }
+        (KeyCode::Right | KeyCode::Char('l'), KeyModifiers::NONE) if cursor_on => {
+            app.stocks[app.current_tab].cursor_right();
+        }
+        (KeyCode::Char('+') | KeyCode::Char('='), KeyModifiers::NONE | KeyModifiers::SHIFT)
+            if app.chart_type.is_time_based() =>
+        {
+            app.stocks[app.current_tab].zoom_in();
+        }
+        (KeyCode::Char('-'), KeyModifiers::NONE) if app.chart_type.is_time_based() => {
+            app.stocks[app.current_tab].zoom_out();
+        }
+        (KeyCode::Char('['), KeyModifiers::NONE) if app.chart_type.is_time_based() => {
+            app.stocks[app.current_tab].pan_left();
+        }
+        (KeyCode::Char(']'), KeyModifiers::NONE) if app.chart_type.is_time_based() => {
+            app.stocks[app.current_tab].pan_right();
+        }
+        (KeyCode::Char('0'), KeyModifiers::NONE) if app.chart_type.is_time_based() => {
+            app.stocks[app.current_tab].reset_zoom();
+        }
         (KeyCode::Left | KeyCode::Char('h'), KeyModifiers::CONTROL) => {
             let new_idx = if app.current_tab == 0 {
                 app.stocks.len() - 1
@@ -69,8 +145,25 @@ fn handle_keys_display_stock(keycode: KeyCode, modifiers: KeyModifiers, app: &mu
             app.previous_mode = app.mode;
             app.mode = app::Mode::AddStock;
         }
+        (KeyCode::Char('r'), KeyModifiers::NONE) => {
+            app.previous_mode = app.mode;
+            app.mode = app::Mode::DateRange;
+        }
+        (KeyCode::Char('a'), KeyModifiers::NONE) => {
+            app.previous_mode = app.mode;
+            app.mode = app::Mode::AddComparison;
+        }
+        (KeyCode::Char('d'), KeyModifiers::NONE) => {
+            app.stocks[app.current_tab].clear_comparisons();
+            app.sync_comparison_symbols();
+        }
+        (KeyCode::Char('n'), KeyModifiers::NONE) => {
+            app.mode = app::Mode::DisplayAlerts;
+        }
         (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
             app.stocks.remove(app.current_tab);
+            app.sync_quote_symbols();
+            app.sync_comparison_symbols();
 
             if app.current_tab != 0 {
                 app.current_tab -= 1;
@@ -84,15 +177,19 @@ fn handle_keys_display_stock(keycode: KeyCode, modifiers: KeyModifiers, app: &mu
         (KeyCode::Char('s'), KeyModifiers::NONE) => {
             app.mode = app::Mode::DisplaySummary;
         }
-        (KeyCode::Char('o'), KeyModifiers::NONE) => {
-            if app.stocks[app.current_tab].toggle_options() {
-                app.mode = app::Mode::DisplayOptions;
-            }
+        (KeyCode::Char('o'), KeyModifiers::NONE)
+            if app.stocks[app.current_tab].toggle_options() =>
+        {
+            app.mode = app::Mode::DisplayOptions;
         }
-        (KeyCode::Char('e'), KeyModifiers::NONE) => {
-            if app.stocks[app.current_tab].toggle_configure() {
-                app.mode = app::Mode::ConfigureChart;
-            }
+        (KeyCode::Char('e'), KeyModifiers::NONE)
+            if app
+                .stocks
+                .get(app.current_tab)
+                .is_some_and(|stock| stock.loaded()) =>
+        {
+            app.stocks[app.current_tab].toggle_configure();
+            app.mode = app::Mode::ConfigureChart;
         }
         (KeyCode::Tab, KeyModifiers::NONE) => {
             if app.current_tab == app.stocks.len() - 1 {
@@ -126,12 +223,119 @@ fn handle_keys_display_summary(keycode: KeyCode, app: &mut app::App) {
             app.previous_mode = app.mode;
             app.mode = app::Mode::AddStock;
         }
+        KeyCode::Char('r') => {
+            app.previous_mode = app.mode;
+            app.mode = app::Mode::DateRange;
+        }
+        _ => {}
+    }
+}
+
+fn handle_keys_display_alerts(keycode: KeyCode, app: &mut app::App) {
+    match keycode {
+        KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+            // Fired alerts have been seen by now
+            ALERT_LOG.write().acknowledge_all();
+
+            app.mode = app::Mode::DisplayStock;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.alerts.select_prev();
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.alerts
+                .select_next(app.stocks[app.current_tab].alerts.len());
+        }
+        KeyCode::Char('a') => {
+            app.previous_mode = app.mode;
+            app.mode = app::Mode::AddAlert;
+        }
+        KeyCode::Char('d') => {
+            app.stocks[app.current_tab].remove_alert(app.alerts.selected);
+        }
+        KeyCode::Char('h') => {
+            app.previous_mode = app.mode;
+            app.mode = app::Mode::AlertHistory;
+        }
+        _ => {}
+    }
+}
+
+fn handle_keys_alert_history(keycode: KeyCode, app: &mut app::App) {
+    let mut alert_log = ALERT_LOG.write();
+    let len = alert_log.fired.len();
+
+    match keycode {
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Char('q') => {
+            app.mode = app.previous_mode;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.alert_history.select_prev();
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.alert_history.select_next(len);
+        }
+        KeyCode::Enter => {
+            if let Some(idx) = app.alert_history.log_idx(len) {
+                alert_log.acknowledge(idx);
+            }
+        }
+        KeyCode::Char('a') => {
+            alert_log.acknowledge_all();
+        }
+        KeyCode::Char('d') => {
+            if let Some(idx) = app.alert_history.log_idx(len) {
+                alert_log.remove(idx);
+            }
+        }
+        KeyCode::Char('D') => {
+            alert_log.clear();
+        }
         _ => {}
     }
 }
 
 fn handle_keys_display_options(keycode: KeyCode, app: &mut app::App) {
     match keycode {
+        KeyCode::Char('g') => {
+            let mut show_greeks = SHOW_GREEKS.write();
+            *show_greeks = !*show_greeks;
+        }
+        KeyCode::Char('f')
+            if app.stocks[app.current_tab]
+                .options
+                .as_ref()
+                .unwrap()
+                .data()
+                .is_some() =>
+        {
+            app.mode = app::Mode::OptionsChain;
+        }
+        KeyCode::Char('s')
+            if app.stocks[app.current_tab]
+                .options
+                .as_ref()
+                .unwrap()
+                .data()
+                .is_some() =>
+        {
+            app.stocks[app.current_tab]
+                .options
+                .as_mut()
+                .unwrap()
+                .fetch_all_expiration_dates();
+            app.mode = app::Mode::OptionsVolatility;
+        }
+        KeyCode::Char('m')
+            if app.stocks[app.current_tab]
+                .options
+                .as_ref()
+                .unwrap()
+                .data()
+                .is_some() =>
+        {
+            app.mode = app::Mode::OptionsInterest;
+        }
         KeyCode::Esc | KeyCode::Char('o') | KeyCode::Char('q') => {
             app.stocks[app.current_tab].toggle_options();
             app.mode = app::Mode::DisplayStock;
@@ -196,20 +400,141 @@ fn handle_keys_display_options(keycode: KeyCode, app: &mut app::App) {
                 .unwrap()
                 .selection_mode_left();
         }
-        KeyCode::Right => {
+        KeyCode::Right
             if app.stocks[app.current_tab]
                 .options
                 .as_mut()
                 .unwrap()
                 .data()
-                .is_some()
-            {
-                app.stocks[app.current_tab]
-                    .options
-                    .as_mut()
-                    .unwrap()
-                    .selection_mode_right();
-            }
+                .is_some() =>
+        {
+            app.stocks[app.current_tab]
+                .options
+                .as_mut()
+                .unwrap()
+                .selection_mode_right();
+        }
+        _ => {}
+    }
+}
+
+fn handle_keys_options_chain(keycode: KeyCode, app: &mut app::App) {
+    let options = app.stocks[app.current_tab].options.as_mut().unwrap();
+
+    match keycode {
+        KeyCode::Esc | KeyCode::Char('f') | KeyCode::Char('q') => {
+            app.mode = app::Mode::DisplayOptions;
+        }
+        KeyCode::Char('o') => {
+            app.stocks[app.current_tab].toggle_options();
+            app.mode = app::Mode::DisplayStock;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            options.previous_strike();
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            options.next_strike();
+        }
+        KeyCode::Left | KeyCode::Char('h') => {
+            options.previous_date();
+        }
+        KeyCode::Right | KeyCode::Char('l') => {
+            options.next_date();
+        }
+        KeyCode::Tab => {
+            options.toggle_option_type();
+        }
+        KeyCode::Char('b') => {
+            options.add_leg(Side::Long);
+        }
+        KeyCode::Char('s') => {
+            options.add_leg(Side::Short);
+        }
+        KeyCode::Enter => {
+            app.mode = app::Mode::OptionsStrategy;
+        }
+        _ => {}
+    }
+}
+
+fn handle_keys_options_interest(keycode: KeyCode, app: &mut app::App) {
+    let options = app.stocks[app.current_tab].options.as_mut().unwrap();
+
+    match keycode {
+        KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('q') => {
+            app.mode = app::Mode::DisplayOptions;
+        }
+        KeyCode::Char('o') => {
+            app.stocks[app.current_tab].toggle_options();
+            app.mode = app::Mode::DisplayStock;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            options.previous_strike();
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            options.next_strike();
+        }
+        KeyCode::Left | KeyCode::Char('h') => {
+            options.previous_date();
+        }
+        KeyCode::Right | KeyCode::Char('l') => {
+            options.next_date();
+        }
+        KeyCode::Tab => {
+            options.toggle_interest_metric();
+        }
+        _ => {}
+    }
+}
+
+fn handle_keys_options_volatility(keycode: KeyCode, app: &mut app::App) {
+    let options = app.stocks[app.current_tab].options.as_mut().unwrap();
+
+    match keycode {
+        KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('q') => {
+            options.stop_fetching_all_expiration_dates();
+            app.mode = app::Mode::DisplayOptions;
+        }
+        KeyCode::Char('o') => {
+            app.stocks[app.current_tab].toggle_options();
+            app.mode = app::Mode::DisplayStock;
+        }
+        KeyCode::Left | KeyCode::Char('h') => {
+            options.previous_date();
+        }
+        KeyCode::Right | KeyCode::Char('l') => {
+            options.next_date();
+        }
+        _ => {}
+    }
+}
+
+fn handle_keys_options_strategy(keycode: KeyCode, app: &mut app::App) {
+    let options = app.stocks[app.current_tab].options.as_mut().unwrap();
+
+    match keycode {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+            app.mode = app::Mode::OptionsChain;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            options.previous_leg();
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            options.next_leg();
+        }
+        KeyCode::Char('d') => {
+            options.remove_leg();
+        }
+        KeyCode::Char('D') => {
+            options.clear_legs();
+        }
+        KeyCode::Tab => {
+            options.open_next_strategy();
+        }
+        KeyCode::Char('w') => {
+            app.prompt.reset();
+            app.previous_mode = app.mode;
+            app.mode = app::Mode::SaveStrategy;
         }
         _ => {}
     }
@@ -242,11 +567,9 @@ pub fn handle_keys_configure_chart(keycode: KeyCode, modifiers: KeyModifiers, ap
             let config = app.stocks[app.current_tab].chart_config_mut();
             config.enter(time_frame);
         }
-        (KeyCode::Char(c), KeyModifiers::NONE) => {
-            if c.is_numeric() || c == '.' {
-                let config = app.stocks[app.current_tab].chart_config_mut();
-                config.add_char(c);
-            }
+        (KeyCode::Char(c), KeyModifiers::NONE) if c.is_numeric() || c == '.' => {
+            let config = app.stocks[app.current_tab].chart_config_mut();
+            config.add_char(c);
         }
         (KeyCode::Backspace, KeyModifiers::NONE) => {
             let config = app.stocks[app.current_tab].chart_config_mut();
@@ -256,6 +579,53 @@ pub fn handle_keys_configure_chart(keycode: KeyCode, modifiers: KeyModifiers, ap
     }
 }
 
+pub fn handle_mouse_event(
+    mode: Mode,
+    mouse_event: MouseEvent,
+    app: &mut app::App,
+    request_redraw: &Sender<()>,
+) {
+    if mode != Mode::DisplayStock {
+        return;
+    }
+
+    let Some(stock) = app.stocks.get_mut(app.current_tab) else {
+        return;
+    };
+
+    let MouseEvent {
+        kind, column, row, ..
+    } = mouse_event;
+
+    let redraw = match kind {
+        MouseEventKind::Moved => stock.cursor_to(column, row),
+        MouseEventKind::Down(_) if stock.is_over_chart(column, row) => {
+            stock.drag_column = Some(column);
+            stock.cursor_to(column, row)
+        }
+        // Pans when zoomed in, otherwise moves the crosshair along
+        MouseEventKind::Drag(_) if stock.zoom.is_some() => stock.drag_to(column),
+        MouseEventKind::Drag(_) => stock.cursor_to(column, row),
+        MouseEventKind::Up(_) => {
+            stock.drag_column.take();
+            false
+        }
+        MouseEventKind::ScrollUp if stock.is_over_chart(column, row) => {
+            stock.zoom_in();
+            true
+        }
+        MouseEventKind::ScrollDown if stock.is_over_chart(column, row) => {
+            stock.zoom_out();
+            true
+        }
+        _ => false,
+    };
+
+    if redraw {
+        let _ = request_redraw.try_send(());
+    }
+}
+
 pub fn handle_key_bindings(
     mode: Mode,
     key_event: KeyEvent,
@@ -267,11 +637,16 @@ pub fn handle_key_bindings(
             cleanup_terminal();
             std::process::exit(0);
         }
-        (Mode::AddStock, modifiers, keycode) => {
+        (Mode::AddStock | Mode::AddComparison, modifiers, keycode) => {
             if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT {
                 handle_keys_add_stock(keycode, app)
             }
         }
+        (Mode::DateRange | Mode::AddAlert | Mode::SaveStrategy, modifiers, keycode) => {
+            if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT {
+                handle_keys_prompt(keycode, app)
+            }
+        }
         (Mode::Help, modifiers, keycode) => {
             if modifiers.is_empty()
                 && (matches!(
@@ -283,7 +658,17 @@ pub fn handle_key_bindings(
             }
         }
         (mode, KeyModifiers::NONE, KeyCode::Char('q'))
-            if !matches!(mode, Mode::DisplayOptions | Mode::ConfigureChart) =>
+            if !matches!(
+                mode,
+                Mode::DisplayOptions
+                    | Mode::OptionsChain
+                    | Mode::OptionsInterest
+                    | Mode::OptionsStrategy
+                    | Mode::OptionsVolatility
+                    | Mode::ConfigureChart
+                    | Mode::DisplayAlerts
+                    | Mode::AlertHistory
+            ) =>
         {
             cleanup_terminal();
             std::process::exit(0);
@@ -300,7 +685,7 @@ pub fn handle_key_bindings(
             }
         }
         (_, KeyModifiers::NONE, KeyCode::Char('v')) => {
-            if app.chart_type != ChartType::Kagi {
+            if app.chart_type.is_time_based() {
                 let mut show_volumes = SHOW_VOLUMES.write();
                 *show_volumes = !*show_volumes;
             }
@@ -318,6 +703,12 @@ pub fn handle_key_bindings(
             let mut show_x_labels = SHOW_X_LABELS.write();
             *show_x_labels = !*show_x_labels;
         }
+        (_, KeyModifiers::NONE, KeyCode::Char('i')) => {
+            if app.chart_type.is_time_based() {
+                let mut oscillator = OSCILLATOR.write();
+                *oscillator = Oscillator::cycle(*oscillator);
+            }
+        }
         (_, KeyModifiers::SHIFT, KeyCode::Left) | (_, KeyModifiers::NONE, KeyCode::Char('<')) => {
             if let Some(stock) = app.stocks.get_mut(app.current_tab) {
                 if let Some(chart_state) = stock.chart_state_mut() {
@@ -337,6 +728,36 @@ pub fn handle_key_bindings(
                 handle_keys_display_options(keycode, app)
             }
         }
+        (Mode::OptionsChain, modifiers, keycode) => {
+            if modifiers.is_empty() {
+                handle_keys_options_chain(keycode, app)
+            }
+        }
+        (Mode::OptionsInterest, modifiers, keycode) => {
+            if modifiers.is_empty() {
+                handle_keys_options_interest(keycode, app)
+            }
+        }
+        (Mode::OptionsVolatility, modifiers, keycode) => {
+            if modifiers.is_empty() {
+                handle_keys_options_volatility(keycode, app)
+            }
+        }
+        (Mode::OptionsStrategy, modifiers, keycode) => {
+            if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT {
+                handle_keys_options_strategy(keycode, app)
+            }
+        }
+        (Mode::DisplayAlerts, modifiers, keycode) => {
+            if modifiers.is_empty() {
+                handle_keys_display_alerts(keycode, app)
+            }
+        }
+        (Mode::AlertHistory, modifiers, keycode) => {
+            if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT {
+                handle_keys_alert_history(keycode, app)
+            }
+        }
         (Mode::ConfigureChart, modifiers, keycode) => {
             handle_keys_configure_chart(keycode, modifiers, app)
         }
@@ -0,0 +1,181 @@
+use std::f64::consts::PI;
+
+/// Black-Scholes sensitivities of an option's price. Theta is per calendar
+/// day, and vega and rho are per 1% change in volatility and rate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub rho: f64,
+}
+
+/// European option priced with Black-Scholes, adjusted for a continuous
+/// dividend yield
+pub struct Contract {
+    pub call: bool,
+    /// Price of the underlying
+    pub spot: f64,
+    pub strike: f64,
+    /// Time left until expiration
+    pub years: f64,
+    /// Annualized implied volatility, where 0.25 is 25%
+    pub volatility: f64,
+    /// Annualized risk-free rate, where 0.04 is 4%
+    pub rate: f64,
+    /// Annualized dividend yield of the underlying, where 0.015 is 1.5%
+    pub dividend_yield: f64,
+}
+
+impl Contract {
+    /// `None` once expired, or if any input is unusable
+    pub fn greeks(&self) -> Option<Greeks> {
+        let Contract {
+            call,
+            spot: s,
+            strike: k,
+            years: t,
+            volatility: v,
+            rate: r,
+            dividend_yield: q,
+        } = *self;
+
+        let valid = s > 0.0 && k > 0.0 && t > 0.0 && v > 0.0 && r.is_finite() && q.is_finite();
+
+        if !valid {
+            return None;
+        }
+
+        let sqrt_t = t.sqrt();
+        let d1 = ((s / k).ln() + (r - q + v * v / 2.0) * t) / (v * sqrt_t);
+        let d2 = d1 - v * sqrt_t;
+
+        let dividend_discount = (-q * t).exp();
+        let discount = (-r * t).exp();
+
+        let gamma = dividend_discount * norm_pdf(d1) / (s * v * sqrt_t);
+        let vega = s * dividend_discount * norm_pdf(d1) * sqrt_t;
+        let decay = -s * dividend_discount * norm_pdf(d1) * v / (2.0 * sqrt_t);
+
+        let (delta, theta, rho) = if call {
+            (
+                dividend_discount * norm_cdf(d1),
+                decay - r * k * discount * norm_cdf(d2) + q * s * dividend_discount * norm_cdf(d1),
+                k * t * discount * norm_cdf(d2),
+            )
+        } else {
+            (
+                dividend_discount * (norm_cdf(d1) - 1.0),
+                decay + r * k * discount * norm_cdf(-d2)
+                    - q * s * dividend_discount * norm_cdf(-d1),
+                -k * t * discount * norm_cdf(-d2),
+            )
+        };
+
+        Some(Greeks {
+            delta,
+            gamma,
+            theta: theta / 365.0,
+            vega: vega / 100.0,
+            rho: rho / 100.0,
+        })
+    }
+}
+
+fn norm_pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * PI).sqrt()
+}
+
+fn norm_cdf(x: f64) -> f64 {
+    (1.0 + erf(x / 2f64.sqrt())) / 2.0
+}
+
+/// Abramowitz and Stegun 7.1.26, accurate to 1.5e-7
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+
+    (1.0 - poly * (-x * x).exp()).copysign(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    fn contract(call: bool) -> Contract {
+        Contract {
+            call,
+            spot: 100.0,
+            strike: 100.0,
+            years: 1.0,
+            volatility: 0.2,
+            rate: 0.05,
+            dividend_yield: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_call_greeks() {
+        let greeks = contract(true).greeks().unwrap();
+
+        assert_approx(greeks.delta, 0.6368, 1e-4);
+        assert_approx(greeks.gamma, 0.01876, 1e-5);
+        assert_approx(greeks.vega, 0.3752, 1e-4);
+        assert_approx(greeks.rho, 0.5323, 1e-4);
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let call = contract(true).greeks().unwrap();
+        let put = contract(false).greeks().unwrap();
+
+        // Without dividends a call's delta is a put's plus one
+        assert_approx(call.delta - put.delta, 1.0, 1e-9);
+        assert_approx(call.gamma, put.gamma, 1e-12);
+        assert_approx(call.vega, put.vega, 1e-12);
+    }
+
+    #[test]
+    fn test_theta_per_day() {
+        let call = contract(true).greeks().unwrap();
+        let put = contract(false).greeks().unwrap();
+
+        // Annual theta of -6.414 for the call and -1.658 for the put
+        assert_approx(call.theta, -6.414 / 365.0, 1e-5);
+        assert_approx(put.theta, -1.658 / 365.0, 1e-5);
+
+        // Parity gives the difference as the daily decay of the discounted
+        // strike
+        let carry = -0.05 * 100.0 * (-0.05f64).exp() / 365.0;
+        assert_approx(call.theta - put.theta, carry, 1e-9);
+    }
+
+    #[test]
+    fn test_unusable_inputs() {
+        let expired = Contract {
+            years: 0.0,
+            ..contract(true)
+        };
+        assert_eq!(expired.greeks(), None);
+
+        let no_volatility = Contract {
+            volatility: 0.0,
+            ..contract(false)
+        };
+        assert_eq!(no_volatility.greeks(), None);
+    }
+}
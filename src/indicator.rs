@@ -0,0 +1,438 @@
+use crate::common::Price;
+
+/// Simple moving average over `period` values, `None` until there's enough data
+pub fn sma(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut sum = 0.0;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            sum += value;
+
+            if idx >= period {
+                sum -= values[idx - period];
+            }
+
+            (period > 0 && idx + 1 >= period).then(|| sum / period as f64)
+        })
+        .collect()
+}
+
+/// Exponential moving average over `period` values, seeded with the SMA of the
+/// first `period` values
+pub fn ema(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let k = 2.0 / (period as f64 + 1.0);
+
+    let mut prev: Option<f64> = None;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            if period == 0 || idx + 1 < period {
+                return None;
+            }
+
+            let next = match prev {
+                Some(prev) => value * k + prev * (1.0 - k),
+                None => values[..period].iter().sum::<f64>() / period as f64,
+            };
+
+            prev = Some(next);
+            prev
+        })
+        .collect()
+}
+
+/// Bollinger Bands as (lower, middle, upper), `std_devs` standard deviations
+/// either side of the SMA over `period` values
+pub fn bollinger(values: &[f64], period: usize, std_devs: f64) -> Vec<Option<(f64, f64, f64)>> {
+    sma(values, period)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, mean)| {
+            let mean = mean?;
+
+            let variance = values[idx + 1 - period..=idx]
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / period as f64;
+
+            let offset = variance.sqrt() * std_devs;
+
+            Some((mean - offset, mean, mean + offset))
+        })
+        .collect()
+}
+
+/// Volume weighted average price from the first of `prices`, using the typical
+/// price of each bar
+pub fn vwap(prices: &[Price]) -> Vec<Option<f64>> {
+    let mut total_volume = 0.0;
+    let mut total_value = 0.0;
+
+    prices
+        .iter()
+        .map(|price| {
+            let typical = (price.high + price.low + price.close) / 3.0;
+
+            total_volume += price.volume as f64;
+            total_value += typical * price.volume as f64;
+
+            (total_volume > 0.0 && price.close > 0.0).then(|| total_value / total_volume)
+        })
+        .collect()
+}
+
+/// Relative strength index over `period` values, using Wilder's smoothing
+pub fn rsi(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut avg_gain = 0.0;
+    let mut avg_loss = 0.0;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            if period == 0 || idx == 0 {
+                return None;
+            }
+
+            let change = value - values[idx - 1];
+            let (gain, loss) = (change.max(0.0), (-change).max(0.0));
+
+            if idx <= period {
+                avg_gain += gain / period as f64;
+                avg_loss += loss / period as f64;
+
+                if idx < period {
+                    return None;
+                }
+            } else {
+                avg_gain = (avg_gain * (period - 1) as f64 + gain) / period as f64;
+                avg_loss = (avg_loss * (period - 1) as f64 + loss) / period as f64;
+            }
+
+            if avg_loss == 0.0 {
+                Some(100.0)
+            } else {
+                Some(100.0 - 100.0 / (1.0 + avg_gain / avg_loss))
+            }
+        })
+        .collect()
+}
+
+/// MACD as (macd, signal, histogram), where the MACD line is the `fast` EMA
+/// less the `slow` EMA and the signal line is its `signal` EMA
+pub fn macd(
+    values: &[f64],
+    fast: usize,
+    slow: usize,
+    signal: usize,
+) -> Vec<Option<(f64, f64, f64)>> {
+    let lines = ema(values, fast)
+        .into_iter()
+        .zip(ema(values, slow))
+        .map(|(fast, slow)| Some(fast? - slow?))
+        .collect::<Vec<_>>();
+
+    let start = lines.iter().take_while(|line| line.is_none()).count();
+    let defined = lines.iter().flatten().copied().collect::<Vec<_>>();
+
+    let mut signals = ema(&defined, signal).into_iter();
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            if idx < start {
+                return None;
+            }
+
+            let line = (*line)?;
+            let signal = signals.next().flatten()?;
+
+            Some((line, signal, line - signal))
+        })
+        .collect()
+}
+
+/// Stochastic oscillator as (%K, %D), where %K is where the close sits within
+/// the high / low range of the last `period` bars and %D is its `smoothing`
+/// SMA
+pub fn stochastic(prices: &[Price], period: usize, smoothing: usize) -> Vec<Option<(f64, f64)>> {
+    let ks = prices
+        .iter()
+        .enumerate()
+        .map(|(idx, price)| {
+            if period == 0 || idx + 1 < period {
+                return None;
+            }
+
+            let window = &prices[idx + 1 - period..=idx];
+
+            let low = window.iter().map(|p| p.low).fold(f64::INFINITY, f64::min);
+            let high = window
+                .iter()
+                .map(|p| p.high)
+                .fold(f64::NEG_INFINITY, f64::max);
+
+            if high > low {
+                Some((price.close - low) / (high - low) * 100.0)
+            } else {
+                Some(50.0)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let start = ks.iter().take_while(|k| k.is_none()).count();
+    let defined = ks.iter().flatten().copied().collect::<Vec<_>>();
+
+    let mut ds = sma(&defined, smoothing).into_iter();
+
+    ks.iter()
+        .enumerate()
+        .map(|(idx, k)| {
+            if idx < start {
+                return None;
+            }
+
+            Some(((*k)?, ds.next().flatten()?))
+        })
+        .collect()
+}
+
+/// Average true range over `period` bars, using Wilder's smoothing
+pub fn atr(prices: &[Price], period: usize) -> Vec<Option<f64>> {
+    let mut avg = 0.0;
+
+    prices
+        .iter()
+        .enumerate()
+        .map(|(idx, price)| {
+            if period == 0 || idx == 0 {
+                return None;
+            }
+
+            let prev_close = prices[idx - 1].close;
+            let true_range = (price.high - price.low)
+                .max((price.high - prev_close).abs())
+                .max((price.low - prev_close).abs());
+
+            if idx <= period {
+                avg += true_range / period as f64;
+
+                (idx == period).then_some(avg)
+            } else {
+                avg = (avg * (period - 1) as f64 + true_range) / period as f64;
+
+                Some(avg)
+            }
+        })
+        .collect()
+}
+
+/// Applies `indicator` to the closes of `prices` that have data, lining the
+/// results back up with `prices` so any gaps are `None`
+pub fn on_closes<T>(
+    prices: &[Price],
+    indicator: impl FnOnce(&[f64]) -> Vec<Option<T>>,
+) -> Vec<Option<T>> {
+    on_prices(prices, |prices| {
+        let closes = prices.iter().map(|price| price.close).collect::<Vec<_>>();
+
+        indicator(&closes)
+    })
+}
+
+/// Applies `indicator` to the `prices` that have data, lining the results back
+/// up with `prices` so any gaps are `None`
+pub fn on_prices<T>(
+    prices: &[Price],
+    indicator: impl FnOnce(&[Price]) -> Vec<Option<T>>,
+) -> Vec<Option<T>> {
+    let with_data = prices
+        .iter()
+        .filter(|price| price.close > 0.0)
+        .copied()
+        .collect::<Vec<_>>();
+
+    let mut results = indicator(&with_data).into_iter();
+
+    prices
+        .iter()
+        .map(|price| {
+            if price.close > 0.0 {
+                results.next().flatten()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(actual: Option<f64>, expected: Option<f64>) {
+        match (actual, expected) {
+            (Some(actual), Some(expected)) => assert!(
+                (actual - expected).abs() < 1e-9,
+                "{} != {}",
+                actual,
+                expected
+            ),
+            _ => assert_eq!(actual, expected),
+        }
+    }
+
+    fn assert_all_approx(actual: &[Option<f64>], expected: &[Option<f64>]) {
+        assert_eq!(actual.len(), expected.len());
+
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert_approx(*actual, *expected);
+        }
+    }
+
+    #[test]
+    fn test_sma() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        assert_all_approx(
+            &sma(&values, 3),
+            &[None, None, Some(2.0), Some(3.0), Some(4.0)],
+        );
+        assert_all_approx(&sma(&values[..2], 3), &[None, None]);
+        assert!(sma(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_ema() {
+        let values = [2.0, 4.0, 6.0, 8.0, 12.0];
+
+        // Seeded with the SMA of 4, then k = 0.5
+        assert_all_approx(
+            &ema(&values, 3),
+            &[None, None, Some(4.0), Some(6.0), Some(9.0)],
+        );
+        assert_all_approx(&ema(&values[..2], 3), &[None, None]);
+    }
+
+    #[test]
+    fn test_bollinger() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let offset = 2.0 * (2.0f64 / 3.0).sqrt();
+
+        let bands = bollinger(&values, 3, 2.0);
+
+        assert_eq!(bands.len(), 5);
+        assert!(bands[..2].iter().all(Option::is_none));
+
+        for (band, mean) in bands[2..].iter().zip([2.0, 3.0, 4.0]) {
+            let (lower, middle, upper) = band.unwrap();
+
+            assert_approx(Some(lower), Some(mean - offset));
+            assert_approx(Some(middle), Some(mean));
+            assert_approx(Some(upper), Some(mean + offset));
+        }
+
+        assert!(bollinger(&values[..2], 3, 2.0).iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn test_rsi() {
+        let values = [1.0, 2.0, 3.0, 2.0, 4.0];
+
+        // Average gain / loss of 1 / 0, 0.5 / 0.5, then 1.25 / 0.25
+        assert_all_approx(
+            &rsi(&values, 2),
+            &[
+                None,
+                None,
+                Some(100.0),
+                Some(50.0),
+                Some(100.0 - 100.0 / 6.0),
+            ],
+        );
+        assert_all_approx(&rsi(&values[..2], 2), &[None, None]);
+    }
+
+    #[test]
+    fn test_macd() {
+        let values = [1.0, 2.0, 4.0, 8.0];
+
+        let results = macd(&values, 1, 2, 2);
+
+        assert_eq!(results.len(), 4);
+        assert!(results[..2].iter().all(Option::is_none));
+
+        let expected = [
+            (5.0 / 6.0, 2.0 / 3.0, 1.0 / 6.0),
+            (29.0 / 18.0, 35.0 / 27.0, 17.0 / 54.0),
+        ];
+
+        for (actual, expected) in results[2..].iter().zip(expected) {
+            let (line, signal, histogram) = actual.unwrap();
+
+            assert_approx(Some(line), Some(expected.0));
+            assert_approx(Some(signal), Some(expected.1));
+            assert_approx(Some(histogram), Some(expected.2));
+        }
+    }
+
+    #[test]
+    fn test_macd_warm_up() {
+        let values = (1..=40).map(f64::from).collect::<Vec<_>>();
+
+        // Slow EMA needs 26 values, then the signal EMA 9 MACD values
+        let first = macd(&values, 12, 26, 9).iter().position(Option::is_some);
+        assert_eq!(first, Some(33));
+
+        assert!(macd(&values[..20], 12, 26, 9).iter().all(Option::is_none));
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Price {
+        Price {
+            close,
+            high,
+            low,
+            open: close,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stochastic() {
+        let prices = [
+            bar(10.0, 8.0, 9.0),
+            bar(12.0, 9.0, 11.0),
+            bar(13.0, 10.0, 12.0),
+            bar(14.0, 11.0, 11.0),
+            bar(12.0, 10.0, 10.0),
+        ];
+
+        // %K of 80, 40 and 0 once there are 3 bars, %D waits for 2 of those
+        let results = stochastic(&prices, 3, 2);
+
+        assert_eq!(results.len(), 5);
+        assert!(results[..3].iter().all(Option::is_none));
+
+        for (actual, expected) in results[3..].iter().zip([(40.0, 60.0), (0.0, 20.0)]) {
+            let (k, d) = actual.unwrap();
+
+            assert_approx(Some(k), Some(expected.0));
+            assert_approx(Some(d), Some(expected.1));
+        }
+    }
+
+    #[test]
+    fn test_stochastic_flat_range() {
+        let prices = [bar(10.0, 10.0, 10.0); 3];
+
+        let results = stochastic(&prices, 2, 1);
+
+        assert_eq!(results, [None, Some((50.0, 50.0)), Some((50.0, 50.0))]);
+    }
+}
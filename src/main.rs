@@ -2,7 +2,6 @@ use std::collections::HashMap;
 use std::time::Duration;
 use std::{io, panic, thread};
 
-use api::model::CrumbData;
 use crossbeam_channel::{bounded, select, unbounded, Receiver, Sender};
 use crossterm::event::{Event, MouseEvent, MouseEventKind};
 use crossterm::{cursor, execute, terminal};
@@ -11,29 +10,43 @@ use parking_lot::{Mutex, RwLock};
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use rclite::Arc;
+use service::comparison::ComparisonService;
 use service::default_timestamps::DefaultTimestampService;
+use service::quote::QuoteService;
+use service::stream::StreamService;
 use tickrs_api as api;
+use tickrs_api::stream::StreamFormat;
 
+use crate::alert::AlertLog;
 use crate::app::DebugInfo;
-use crate::common::{ChartType, TimeFrame};
+use crate::common::{ChartType, CustomTimeFrame, Oscillator, TimeFrame};
+use crate::strategy::StrategyBook;
 
+mod alert;
 mod app;
+mod cache;
 mod common;
 mod draw;
 mod event;
+mod greeks;
+mod indicator;
 mod opts;
 mod portfolio;
+mod provider;
 mod service;
+mod strategy;
 mod task;
 mod theme;
 mod widget;
 
 lazy_static! {
-    static ref CLIENT: api::Client = api::Client::new();
     static ref DEBUG_LEVEL: app::EnvConfig = app::EnvConfig::load();
     pub static ref OPTS: opts::Opts = opts::resolve_opts();
+    static ref CLIENT: Box<dyn api::DataProvider> = provider::new(&OPTS);
     pub static ref UPDATE_INTERVAL: u64 = OPTS.update_interval.unwrap_or(1);
     pub static ref TIME_FRAME: TimeFrame = OPTS.time_frame.unwrap_or(TimeFrame::Day1);
+    pub static ref CUSTOM_TIME_FRAMES: RwLock<Vec<CustomTimeFrame>> =
+        RwLock::new(OPTS.time_frames.clone());
     pub static ref HIDE_TOGGLE: bool = OPTS.hide_toggle;
     pub static ref HIDE_PREV_CLOSE: bool = OPTS.hide_prev_close;
     pub static ref REDRAW_REQUEST: (Sender<()>, Receiver<()>) = bounded(1);
@@ -42,9 +55,14 @@ lazy_static! {
     pub static ref ENABLE_PRE_POST: RwLock<bool> = RwLock::new(OPTS.enable_pre_post);
     pub static ref TRUNC_PRE: bool = OPTS.trunc_pre;
     pub static ref SHOW_VOLUMES: RwLock<bool> = RwLock::new(OPTS.show_volumes);
+    pub static ref SHOW_GREEKS: RwLock<bool> = RwLock::new(OPTS.show_greeks);
+    pub static ref RISK_FREE_RATE: f64 = OPTS.risk_free_rate.unwrap_or(0.04);
+    pub static ref OSCILLATOR: RwLock<Option<Oscillator>> = RwLock::new(OPTS.oscillator);
     pub static ref DEFAULT_TIMESTAMPS: RwLock<HashMap<TimeFrame, Vec<i64>>> = Default::default();
+    pub static ref ALERT_LOG: RwLock<AlertLog> = RwLock::new(AlertLog::load());
+    pub static ref STRATEGIES: RwLock<StrategyBook> =
+        RwLock::new(StrategyBook::new(OPTS.strategies.clone()));
     pub static ref THEME: theme::Theme = OPTS.theme.unwrap_or_default();
-    pub static ref YAHOO_CRUMB: async_std::sync::RwLock<Option<CrumbData>> = Default::default();
 }
 
 fn main() {
@@ -55,9 +73,11 @@ fn main() {
     let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend).unwrap();
 
+    // Surface provider configuration errors before we take over the terminal
+    lazy_static::initialize(&CLIENT);
+
     setup_panic_hook();
     setup_terminal();
-    set_crumb();
 
     let request_redraw = REDRAW_REQUEST.0.clone();
     let data_received = DATA_RECEIVED.1.clone();
@@ -82,10 +102,38 @@ fn main() {
 
     let default_timestamp_service = DefaultTimestampService::new();
 
+    let starting_symbols: Vec<_> = starting_stocks
+        .iter()
+        .map(|stock| stock.symbol().to_string())
+        .collect();
+
+    let quote_service = QuoteService::new(starting_symbols.clone());
+
+    let mut comparison_service = ComparisonService::new(opts.time_frame.unwrap_or(TimeFrame::Day1));
+    comparison_service.set_symbols(
+        starting_stocks
+            .iter()
+            .flat_map(|stock| stock.comparisons.iter().map(|c| c.symbol.clone()))
+            .collect(),
+    );
+
+    let stream_service = opts.stream.then(|| {
+        StreamService::new(
+            opts.stream_url
+                .clone()
+                .unwrap_or_else(|| api::stream::YAHOO_STREAM_URL.to_string()),
+            opts.stream_format.unwrap_or(StreamFormat::Yahoo),
+            starting_symbols,
+        )
+    });
+
     let app = Arc::new(Mutex::new(app::App {
         mode: starting_mode,
         stocks: starting_stocks,
         add_stock: widget::AddStockState::new(),
+        prompt: Default::default(),
+        alerts: Default::default(),
+        alert_history: Default::default(),
         help: widget::HelpWidget {},
         current_tab: 0,
         hide_help: opts.hide_help,
@@ -103,6 +151,11 @@ fn main() {
         },
         time_frame: opts.time_frame.unwrap_or(TimeFrame::Day1),
         default_timestamp_service,
+        quote_service,
+        comparison_service,
+        stream_service,
+        streaming: false,
+        quotes_paused: false,
         summary_scroll_state: Default::default(),
         chart_type: starting_chart_type,
     }));
@@ -167,7 +220,9 @@ fn main() {
                     Ok(Event::Key(key_event)) => {
                         event::handle_key_bindings(app.mode, key_event, &mut app, &request_redraw);
                     }
-                    Ok(Event::Mouse(MouseEvent { kind, row, column,.. })) => {
+                    Ok(Event::Mouse(mouse_event)) => {
+                        let MouseEvent { kind, row, column, .. } = mouse_event;
+
                         if app.debug.enabled {
                             match kind {
                                 MouseEventKind::Down(_) => app.debug.cursor_location = Some((row, column)),
@@ -176,6 +231,8 @@ fn main() {
                                 _ => {}
                             }
                         }
+
+                        event::handle_mouse_event(app.mode, mouse_event, &mut app, &request_redraw);
                     }
                     Ok(Event::Resize(..)) => {
                         let _ = request_redraw.try_send(());
@@ -205,9 +262,8 @@ fn setup_terminal() {
 fn cleanup_terminal() {
     let mut stdout = io::stdout();
 
-    if DEBUG_LEVEL.debug_mouse {
-        execute!(stdout, crossterm::event::DisableMouseCapture).unwrap();
-    }
+    // Also captured while a crosshair cursor is on
+    execute!(stdout, crossterm::event::DisableMouseCapture).unwrap();
 
     execute!(stdout, cursor::MoveTo(0, 0)).unwrap();
     execute!(stdout, terminal::Clear(terminal::ClearType::All)).unwrap();
@@ -218,6 +274,18 @@ fn cleanup_terminal() {
     terminal::disable_raw_mode().unwrap();
 }
 
+/// Captures the mouse while a crosshair cursor is on so it can follow the
+/// pointer, otherwise leaving it to the terminal for selecting text
+fn set_mouse_capture(enabled: bool) {
+    let mut stdout = io::stdout();
+
+    if enabled {
+        execute!(stdout, crossterm::event::EnableMouseCapture).unwrap();
+    } else if !DEBUG_LEVEL.debug_mouse {
+        execute!(stdout, crossterm::event::DisableMouseCapture).unwrap();
+    }
+}
+
 fn setup_ui_events() -> Receiver<Event> {
     let (sender, receiver) = unbounded();
     std::thread::spawn(move || loop {
@@ -233,13 +301,3 @@ fn setup_panic_hook() {
         better_panic::Settings::auto().create_panic_handler()(panic_info);
     }));
 }
-
-fn set_crumb() {
-    async_std::task::spawn(async move {
-        let mut _guard = YAHOO_CRUMB.write().await;
-
-        if let Ok(crumb) = CLIENT.get_crumb().await {
-            *_guard = Some(crumb);
-        }
-    });
-}
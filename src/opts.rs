@@ -1,14 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 use std::{fs, process};
 
 use anyhow::{bail, format_err, Error};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
 use structopt::StructOpt;
 
-use crate::common::{ChartType, TimeFrame};
+use crate::alert::Alert;
+use crate::api::stream::StreamFormat;
+use crate::api::ClientConfig;
+use crate::common::{ChartType, CustomTimeFrame, Oscillator, TimeFrame};
 use crate::portfolio::Portfolio;
+use crate::provider::ProviderKind;
+use crate::strategy::{self, Strategy};
 use crate::theme::Theme;
-use crate::widget::KagiOptions;
+use crate::widget::{KagiOptions, OverlayOptions, PointFigureOptions, RenkoOptions};
 
 pub fn resolve_opts() -> Opts {
     let mut opts = get_cli_opts();
@@ -16,8 +23,18 @@ pub fn resolve_opts() -> Opts {
     if let Ok(config_opts) = get_config_opts() {
         // Options
         opts.chart_type = opts.chart_type.or(config_opts.chart_type);
+        opts.compare = opts.compare.or(config_opts.compare);
+        opts.oscillator = opts.oscillator.or(config_opts.oscillator);
+        opts.provider = opts.provider.or(config_opts.provider);
+        opts.record = opts.record.or(config_opts.record);
+        opts.replay_dir = opts.replay_dir.or(config_opts.replay_dir);
+        opts.replay_speed = opts.replay_speed.or(config_opts.replay_speed);
+        opts.risk_free_rate = opts.risk_free_rate.or(config_opts.risk_free_rate);
+        opts.stream_format = opts.stream_format.or(config_opts.stream_format);
+        opts.stream_url = opts.stream_url.or(config_opts.stream_url);
         opts.symbols = opts.symbols.or(config_opts.symbols);
         opts.time_frame = opts.time_frame.or(config_opts.time_frame);
+        opts.time_frames = config_opts.time_frames;
         opts.update_interval = opts.update_interval.or(config_opts.update_interval);
 
         // Flags
@@ -25,8 +42,10 @@ pub fn resolve_opts() -> Opts {
         opts.hide_help = opts.hide_help || config_opts.hide_help;
         opts.hide_prev_close = opts.hide_prev_close || config_opts.hide_prev_close;
         opts.hide_toggle = opts.hide_toggle || config_opts.hide_toggle;
+        opts.show_greeks = opts.show_greeks || config_opts.show_greeks;
         opts.show_volumes = opts.show_volumes || config_opts.show_volumes;
         opts.show_x_labels = opts.show_x_labels || config_opts.show_x_labels;
+        opts.stream = opts.stream || config_opts.stream;
         opts.summary = opts.summary || config_opts.summary;
         opts.trunc_pre = opts.trunc_pre || config_opts.trunc_pre;
 
@@ -36,8 +55,28 @@ pub fn resolve_opts() -> Opts {
         // Kagi Options
         opts.kagi_options = config_opts.kagi_options;
 
+        // Overlay Options
+        opts.overlay_options = config_opts.overlay_options;
+
+        // Renko / Point & Figure Options
+        opts.renko_options = config_opts.renko_options;
+        opts.pnf_options = config_opts.pnf_options;
+
         // Portfolio
         opts.portfolio = config_opts.portfolio;
+
+        // Options Greeks
+        opts.dividend_yields = config_opts.dividend_yields;
+
+        // Options Strategies
+        opts.strategies = config_opts.strategies;
+
+        // Alerts
+        opts.alerts = config_opts.alerts;
+        opts.alert_command = config_opts.alert_command;
+
+        // HTTP
+        opts.http = config_opts.http;
     }
 
     opts
@@ -47,7 +86,7 @@ fn get_cli_opts() -> Opts {
     Opts::from_args()
 }
 
-fn get_config_opts() -> Result<Opts, Error> {
+fn config_path() -> Result<PathBuf, Error> {
     let config_dir = dirs_next::config_dir()
         .ok_or_else(|| format_err!("Could not get config directory"))?
         .join("tickrs");
@@ -56,7 +95,11 @@ fn get_config_opts() -> Result<Opts, Error> {
         let _ = fs::create_dir_all(&config_dir);
     }
 
-    let config_path = config_dir.join("config.yml");
+    Ok(config_dir.join("config.yml"))
+}
+
+fn get_config_opts() -> Result<Opts, Error> {
+    let config_path = config_path()?;
 
     if !config_path.exists() {
         let _ = fs::write(&config_path, DEFAULT_CONFIG);
@@ -89,13 +132,57 @@ fn get_config_opts() -> Result<Opts, Error> {
 pub struct Opts {
     // Options
     //
-    #[structopt(short, long, possible_values(&["line", "candle", "kagi"]))]
+    #[structopt(
+        short,
+        long,
+        possible_values(&[
+            "line",
+            "area",
+            "candle",
+            "ohlc",
+            "heikin_ashi",
+            "kagi",
+            "renko",
+            "pnf"
+        ])
+    )]
     /// Chart type to start app with [default: line]
     pub chart_type: Option<ChartType>,
+    #[structopt(long, use_delimiter = true)]
+    /// Comma separated list of ticker symbols to compare each stock against
+    pub compare: Option<Vec<String>>,
+    #[structopt(long, possible_values(&["rsi", "macd", "stochastic"]))]
+    /// Oscillator to show in a panel below the chart
+    pub oscillator: Option<Oscillator>,
+    #[structopt(long, possible_values(&["yahoo", "replay"]))]
+    /// Market data provider to source prices, quotes and options from [default: yahoo]
+    pub provider: Option<ProviderKind>,
+    #[structopt(long, parse(from_os_str))]
+    /// Save every API response to this directory, so the session can be replayed later
+    pub record: Option<PathBuf>,
+    #[structopt(long, parse(from_os_str))]
+    /// Directory of recorded data to serve when using the replay provider
+    pub replay_dir: Option<PathBuf>,
+    #[structopt(long)]
+    /// Play back the recorded 1D session at this many seconds per second
+    pub replay_speed: Option<f64>,
+    #[structopt(long)]
+    /// Annualized risk-free rate used to calculate options greeks, where 0.04 is 4% [default: 0.04]
+    pub risk_free_rate: Option<f64>,
+    #[structopt(long, possible_values(&["yahoo", "json"]))]
+    /// Message format of the quote stream [default: yahoo]
+    pub stream_format: Option<StreamFormat>,
+    #[structopt(long)]
+    /// Websocket URL to stream quotes from [default: Yahoo! Finance's streamer]
+    pub stream_url: Option<String>,
     #[structopt(short, long, use_delimiter = true)]
     /// Comma separated list of ticker symbols to start app with
     pub symbols: Option<Vec<String>>,
-    #[structopt(short = "t", long, possible_values(&["1D", "1W", "1M", "3M", "6M", "1Y", "5Y"]))]
+    #[structopt(
+        short = "t",
+        long,
+        possible_values(&["1D", "1W", "1M", "3M", "6M", "YTD", "1Y", "2Y", "5Y", "10Y", "MAX"])
+    )]
     /// Use specified time frame when starting program and when new stocks are added [default: 1D]
     pub time_frame: Option<TimeFrame>,
     #[structopt(short = "i", long)]
@@ -117,24 +204,125 @@ pub struct Opts {
     /// Hide toggle block
     pub hide_toggle: bool,
     #[structopt(long)]
+    /// Show greek columns in the options chain
+    pub show_greeks: bool,
+    #[structopt(long)]
     /// Show volumes graph
     pub show_volumes: bool,
     #[structopt(short = "x", long)]
     /// Show x-axis labels
     pub show_x_labels: bool,
     #[structopt(long)]
+    /// Stream real-time quotes over a websocket, polling only while the stream is down
+    pub stream: bool,
+    #[structopt(long)]
     /// Start in summary mode
     pub summary: bool,
     #[structopt(long)]
     /// Truncate pre market graphing to only 30 minutes prior to markets opening
     pub trunc_pre: bool,
 
+    #[structopt(skip)]
+    pub time_frames: Vec<CustomTimeFrame>,
     #[structopt(skip)]
     pub theme: Option<Theme>,
     #[structopt(skip)]
     pub kagi_options: HashMap<String, KagiOptions>,
     #[structopt(skip)]
+    pub overlay_options: HashMap<String, OverlayOptions>,
+    #[structopt(skip)]
+    pub renko_options: HashMap<String, RenkoOptions>,
+    #[structopt(skip)]
+    pub pnf_options: HashMap<String, PointFigureOptions>,
+    #[structopt(skip)]
     pub portfolio: Option<Portfolio>,
+    #[structopt(skip)]
+    pub dividend_yields: HashMap<String, f64>,
+    #[structopt(skip)]
+    pub strategies: BTreeMap<String, Vec<Strategy>>,
+    #[structopt(skip)]
+    pub alerts: Vec<Alert>,
+    #[structopt(skip)]
+    pub alert_command: Option<String>,
+    #[structopt(skip)]
+    pub http: Option<ClientConfig>,
+}
+
+/// Adds the strategy to the `strategies` section of `config.yml`, replacing any
+/// for `symbol` with the same name. Only that section is rewritten, and the
+/// file is left alone if anything else would change
+pub fn save_strategy(symbol: &str, strategy: &Strategy) -> Result<(), Error> {
+    let config_path = config_path()?;
+    let config = fs::read_to_string(&config_path).unwrap_or_else(|_| DEFAULT_CONFIG.to_string());
+
+    fs::write(config_path, with_strategy(&config, symbol, strategy)?)?;
+
+    Ok(())
+}
+
+fn with_strategy(config: &str, symbol: &str, strategy: &Strategy) -> Result<String, Error> {
+    #[derive(Serialize)]
+    struct Section<'a> {
+        strategies: &'a BTreeMap<String, Vec<Strategy>>,
+    }
+
+    let mut expected = match serde_yaml::from_str::<Value>(config)? {
+        Value::Mapping(mapping) => mapping,
+        Value::Null => Mapping::new(),
+        _ => bail!("config.yml isn't a mapping"),
+    };
+
+    let mut strategies: BTreeMap<String, Vec<Strategy>> = match expected.get("strategies") {
+        Some(Value::Null) | None => BTreeMap::new(),
+        Some(section) => serde_yaml::from_value(section.clone())?,
+    };
+    strategy::insert(
+        strategies.entry(symbol.to_string()).or_default(),
+        strategy.clone(),
+    );
+
+    let section = serde_yaml::to_string(&Section {
+        strategies: &strategies,
+    })?;
+
+    let mut lines = config.lines().collect::<Vec<_>>();
+
+    match lines
+        .iter()
+        .position(|line| line.starts_with("strategies:"))
+    {
+        Some(start) => {
+            // Indented or list lines belong to the section, up to the next
+            // top level key or comment
+            let mut end = (start + 1..lines.len())
+                .find(|&idx| !lines[idx].is_empty() && !lines[idx].starts_with([' ', '\t', '-']))
+                .unwrap_or(lines.len());
+
+            while end > start + 1 && lines[end - 1].trim().is_empty() {
+                end -= 1;
+            }
+
+            lines.splice(start..end, section.lines());
+        }
+        None => {
+            while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                lines.pop();
+            }
+
+            lines.push("");
+            lines.extend(section.lines());
+        }
+    }
+
+    let updated = lines.join("\n") + "\n";
+
+    expected.insert("strategies".into(), serde_yaml::to_value(&strategies)?);
+
+    if serde_yaml::from_str::<Value>(&updated)? != Value::Mapping(expected) {
+        bail!("Couldn't find where the `strategies` section of config.yml ends");
+    }
+
+    Ok(updated)
 }
 
 const DEFAULT_CONFIG: &str = "---
@@ -145,14 +333,77 @@ const DEFAULT_CONFIG: &str = "---
 
 # Chart type to start app with
 # Default is line
-# Possible values: line, candle, kagi
+# Possible values: line, area, candle, ohlc, heikin_ashi, kagi, renko, pnf
 #chart_type: candle
 
+# List of ticker symbols to compare each stock against, drawn over time based
+# charts by their percent change over the time frame
+#compare:
+#  - SPY
+#  - QQQ
+
+# Oscillator to show in a panel below the chart, cycled in the GUI by
+# pressing 'i'
+# Possible values: rsi, macd, stochastic
+#oscillator: rsi
+
+# Market data provider to source prices, quotes and options from
+# Default is yahoo
+# Possible values: yahoo, replay
+#provider: yahoo
+
+# Save every API response to this directory, so the session can be replayed
+# later by pointing replay_dir at it
+#record: /home/user/tickrs-session
+
+# Directory of recorded data to serve when using the replay provider
+#
+# Recordings are stored per symbol as chart_<range>_<interval>.json,
+# company.json and options_<expiration date>.json, or can be a directory
+# captured with record
+#replay_dir: /home/user/tickrs-demo
+
+# Play back the recorded 1D session at this many seconds per second, instead
+# of showing the whole session at once
+#replay_speed: 60
+
+# Annualized risk-free rate used to calculate options greeks, where 0.04 is 4%
+# Default is 0.04
+#risk_free_rate: 0.045
+
+# Message format of the quote stream, if enabled
+# Default is yahoo
+# Possible values: yahoo, json
+#
+# json expects one object per message, with optional volume (traded so far
+# today) and session (pre, regular, post) fields:
+# {\"symbol\": \"SPY\", \"price\": 512.3, \"time\": 1710979200, \"volume\": 1000}
+#stream_format: yahoo
+
+# Websocket URL to stream quotes from, if enabled
+# Default is Yahoo! Finance's streamer
+#stream_url: wss://streamer.finance.yahoo.com/?version=2
+
 # Use specified time frame when starting program and when new stocks are added
 # Default is 1D
-# Possible values: 1D, 1W, 1M, 3M, 6M, 1Y, 5Y
+# Possible values: 1D, 1W, 1M, 3M, 6M, YTD, 1Y, 2Y, 5Y, 10Y, MAX
 #time_frame: 1D
 
+# Extra time frames, shown after the built in ones
+#
+# Each is written as '<span> at <interval>'. The span is how far back to go in
+# days, weeks, months or years (D, W, M, Y), or a date range written as
+# '<start>..<end>'. A date range can leave out the interval to have one picked
+# from its length
+#
+# Possible intervals: 1m, 2m, 5m, 15m, 30m, 60m, 90m, 1h, 1d, 5d, 1wk, 1mo, 3mo
+#
+# Intraday intervals are only available for the last 60 days
+#time_frames:
+#  - 3D at 2m
+#  - 20Y at 1wk
+#  - 2020-02-01..2020-06-30 at 1d
+
 # Interval to update data from API (seconds)
 # Default is 1
 #update_interval: 1
@@ -169,12 +420,19 @@ const DEFAULT_CONFIG: &str = "---
 # Hide toggle block
 #hide_toggle: true
 
+# Show delta, gamma, theta, vega and rho columns in the options chain, toggled
+# in the GUI by pressing 'g' from the options pane
+#show_greeks: true
+
 # Show volumes graph
 #show_volumes: true
 
 # Show x-axis labels
 #show_x_labels: true
 
+# Stream real-time quotes over a websocket, polling only while the stream is down
+#stream: true
+
 # Start in summary mode
 #summary: true
 
@@ -215,6 +473,87 @@ const DEFAULT_CONFIG: &str = "---
 #        type: pct
 #        value: 0.10
 
+# Ticker options for Renko charts
+#
+# A map of each ticker with a box_size field. If no entry is defined for a
+# symbol, the 14 bar average true range is used. This can be updated in the GUI
+# by pressing 'e'
+#
+# box_size can be supplied as a single value, or a map on time frame like the
+# Kagi reversal
+#
+# box_size.type can be 'pct', 'amount' or 'atr', where the value of atr is the
+# number of bars to average over
+#
+#renko_options:
+#  SPY:
+#    box_size:
+#      type: amount
+#      value: 2.50
+#  AMD:
+#    box_size:
+#      type: atr
+#      value: 20
+
+# Ticker options for Point & Figure charts
+#
+# A map of each ticker with box_size, reversal and/or price fields (all
+# optional). box_size is set the same way as for Renko and defaults to the 14
+# bar average true range, reversal is the number of boxes needed to start a new
+# column and defaults to 3, and price can be 'close' (default) or 'high_low'.
+# This can be updated in the GUI by pressing 'e'
+#
+#pnf_options:
+#  SPY:
+#    box_size:
+#      type: pct
+#      value: 0.01
+#    reversal: 3
+#    price: high_low
+
+# Indicator overlays for line, area, candle, OHLC and Heikin-Ashi charts
+#
+# A map of each ticker to the overlays drawn over its chart, all fields optional.
+# Periods are in data points of the time frame being viewed, and VWAP is only
+# drawn on 1D. This can be updated in the GUI by pressing 'e', though changes
+# made there aren't saved
+#
+#overlay_options:
+#  SPY:
+#    sma: true
+#    sma_period: 20
+#    ema: true
+#    ema_period: 50
+#    bollinger: true
+#    bollinger_period: 20
+#    bollinger_std_dev: 2.0
+#    vwap: true
+
+# Annualized dividend yield of each ticker used to calculate options greeks,
+# where 0.015 is 1.5%. Tickers without an entry are treated as paying none
+#
+#dividend_yields:
+#  SPY: 0.013
+#  AAPL: 0.005
+
+# Options strategies for each ticker, built and saved in the GUI by pressing
+# 'f' from the options pane, adding legs to the strategy with 'b' / 's' and
+# pressing 'w' from the strategy screen
+#
+# Legs are written as '<long / short> <quantity> <call / put> <strike>
+# <expiration> at <premium>'. Saving a strategy from the GUI rewrites only this
+# section, adding the strategy or replacing the one with the same name. Comments
+# inside the section aren't kept, so keep them outside of it
+#
+#strategies:
+#  SPY:
+#    - name: Iron condor
+#      legs:
+#        - long 1 put 505 2024-03-21 at 0.72
+#        - short 1 put 510 2024-03-21 at 1.09
+#        - short 1 call 525 2024-03-21 at 1.16
+#        - long 1 call 530 2024-03-21 at 0.76
+
 # Apply a custom theme
 #
 # All colors are optional. If commented out / omitted, the color will get sourced
@@ -232,6 +571,16 @@ const DEFAULT_CONFIG: &str = "---
 #  border_axis: '#FC9766'
 #  highlight_focused: '#FC9766'
 #  highlight_unfocused: '#727072'
+#  overlay_sma: '#FFDA65'
+#  overlay_ema: '#79DBEA'
+#  overlay_bollinger: '#AB9DF2'
+#  overlay_vwap: '#FC9766'
+#  oscillator_line: '#79DBEA'
+#  oscillator_signal: '#FFDA65'
+#  comparison_1: '#FF6188'
+#  comparison_2: '#78DCE8'
+#  comparison_3: '#FFD866'
+#  comparison_4: '#FCFCFA'
 
 # Portfolio tracking
 #
@@ -246,4 +595,124 @@ const DEFAULT_CONFIG: &str = "---
 #  SPY:
 #    quantity: 100
 #    average_price: 450.25
+
+# Alerts
+#
+# Alerts ring the terminal bell, flash the stock's tab and are listed in the
+# alerts pane, opened by pressing 'n', once their condition is met. They fire
+# again only after the condition stops being met. Alerts can also be added from
+# the alerts pane, and are only checked for stocks that are open
+#
+# Fired alerts are logged in the tickrs data directory, and can be looked back
+# on by pressing 'h' from the alerts pane
+#
+# Conditions are 'above <price>', 'below <price>', 'up <pct>%' / 'down <pct>%'
+# on the day, or 'volume <multiple>x' the average daily volume
+#
+#alerts:
+#  - AMD above 180
+#  - SPY down 2%
+#  - AMD volume 2x
+
+# Shell command run whenever an alert fires, with the alert in the
+# TICKRS_ALERT, TICKRS_SYMBOL, TICKRS_CONDITION and TICKRS_PRICE environment
+# variables
+#alert_command: notify-send tickrs \"$TICKRS_ALERT at $TICKRS_PRICE\"
+
+# HTTP settings for the yahoo provider
+#
+# All fields are optional. base_url and cookie_url can point to a mock server,
+# proxy accepts http, https and socks URLs and timeout is in seconds. headers are
+# sent with every request and replace the default User-Agent if supplied
+#
+# retries is the number of times a failed request is retried, waiting longer
+# between each attempt and honoring Retry-After when rate limited. Default is 3
+#
+#http:
+#  base_url: https://query1.finance.yahoo.com
+#  cookie_url: https://fc.yahoo.com
+#  proxy: http://proxy.example.com:8080
+#  timeout: 10
+#  retries: 3
+#  headers:
+#    User-Agent: tickrs
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strategy(name: &str, leg: &str) -> Strategy {
+        Strategy {
+            name: name.to_string(),
+            legs: vec![leg.parse().unwrap()],
+        }
+    }
+
+    const CALL: &str = "long 1 call 520 2024-03-21 at 1.75";
+    const PUT: &str = "long 1 put 505 2024-03-21 at 0.72";
+
+    #[test]
+    fn test_with_strategy_keeps_rest_of_config() {
+        let config = "---
+# Symbols to start with
+symbols:
+  - SPY
+
+# Saved strategies
+strategies:
+  SPY:
+    - name: Call
+      legs:
+        - long 1 call 520 2024-03-21 at 1.75
+
+# Seconds between updates
+update_interval: 2
 ";
+
+        let updated = with_strategy(config, "SPY", &strategy("Put", PUT)).unwrap();
+
+        assert!(updated.starts_with("---\n# Symbols to start with\nsymbols:\n  - SPY\n"));
+        assert!(updated.contains("\n# Saved strategies\nstrategies:\n"));
+        assert!(updated.ends_with("\n\n# Seconds between updates\nupdate_interval: 2\n"));
+
+        let opts = serde_yaml::from_str::<Opts>(&updated).unwrap();
+        assert_eq!(opts.symbols, Some(vec!["SPY".to_string()]));
+        assert_eq!(opts.update_interval, Some(2));
+        assert_eq!(
+            opts.strategies["SPY"],
+            vec![strategy("Call", CALL), strategy("Put", PUT)]
+        );
+    }
+
+    #[test]
+    fn test_with_strategy_replaces_same_name() {
+        let config = "strategies:\n  SPY:\n    - name: Spread\n      legs:\n        - long 1 call 520 2024-03-21 at 1.75\n";
+
+        let updated = with_strategy(config, "SPY", &strategy("Spread", PUT)).unwrap();
+
+        let opts = serde_yaml::from_str::<Opts>(&updated).unwrap();
+        assert_eq!(opts.strategies["SPY"], vec![strategy("Spread", PUT)]);
+    }
+
+    #[test]
+    fn test_with_strategy_appends_section() {
+        let updated = with_strategy(DEFAULT_CONFIG, "QQQ", &strategy("Call", CALL)).unwrap();
+
+        // Every comment of the default config is kept
+        assert!(updated.starts_with(DEFAULT_CONFIG.trim_end()));
+
+        let opts = serde_yaml::from_str::<Opts>(&updated).unwrap();
+        assert_eq!(opts.strategies["QQQ"], vec![strategy("Call", CALL)]);
+        assert_eq!(opts.strategies.len(), 1);
+    }
+
+    #[test]
+    fn test_with_strategy_refuses_unclear_section() {
+        // The top level comment splits the section, so rewriting up to it
+        // would leave the rest of the section behind
+        let config = "strategies:\n  SPY:\n    - name: Call\n      legs:\n# Comment\n        - long 1 call 520 2024-03-21 at 1.75\n";
+
+        assert!(with_strategy(config, "SPY", &strategy("Put", PUT)).is_err());
+    }
+}
@@ -1,6 +1,7 @@
-use serde::Deserialize;
 use std::collections::HashMap;
 
+use serde::Deserialize;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct PortfolioItem {
     pub quantity: f64,
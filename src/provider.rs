@@ -0,0 +1,64 @@
+use std::process;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::api::{self, DataProvider};
+use crate::opts::Opts;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Deserialize)]
+pub enum ProviderKind {
+    #[serde(rename = "yahoo")]
+    Yahoo,
+    #[serde(rename = "replay")]
+    Replay,
+}
+
+impl FromStr for ProviderKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ProviderKind::*;
+
+        match s {
+            "yahoo" => Ok(Yahoo),
+            "replay" => Ok(Replay),
+            _ => Err("Valid providers are: 'yahoo', 'replay'"),
+        }
+    }
+}
+
+/// Builds the data provider selected by the user, defaulting to Yahoo! Finance
+pub fn new(opts: &Opts) -> Box<dyn DataProvider> {
+    match opts.provider.unwrap_or(ProviderKind::Yahoo) {
+        ProviderKind::Yahoo => {
+            let config = opts.http.clone().unwrap_or_default();
+
+            let client = match api::Client::with_config(config) {
+                Ok(client) => client,
+                Err(e) => {
+                    println!("Error configuring HTTP client: {:#}", e);
+                    process::exit(1);
+                }
+            };
+
+            match opts.record.as_ref() {
+                Some(dir) => Box::new(client.record_to(dir)),
+                None => Box::new(client),
+            }
+        }
+        ProviderKind::Replay => {
+            let Some(dir) = opts.replay_dir.as_ref() else {
+                println!("The replay provider requires a directory supplied with --replay-dir");
+                process::exit(1);
+            };
+
+            let replay = api::Replay::new(dir);
+
+            match opts.replay_speed {
+                Some(speed) => Box::new(replay.with_playback(speed)),
+                None => Box::new(replay),
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
+pub mod comparison;
 pub mod default_timestamps;
 pub mod options;
+pub mod quote;
 pub mod stock;
+pub mod stream;
+pub mod symbol_search;
 
 /// Container of one or more tasks, that manages capturing all queued task responses
 /// into one update response
@@ -0,0 +1,84 @@
+use super::*;
+use crate::api::model::ChartMeta;
+use crate::common::{Price, TimeFrame};
+use crate::task::*;
+
+type PricesHandle = AsyncTaskHandle<(TimeFrame, ChartMeta, Vec<Price>)>;
+
+/// Fetches the prices of each symbol compared against once, however many
+/// stocks are comparing against it
+pub struct ComparisonService {
+    time_frame: TimeFrame,
+    handles: Vec<(String, PricesHandle)>,
+}
+
+impl ComparisonService {
+    pub fn new(time_frame: TimeFrame) -> ComparisonService {
+        ComparisonService {
+            time_frame,
+            handles: vec![],
+        }
+    }
+
+    /// Replace the symbols fetched, keeping the tasks of those already fetched
+    /// running
+    pub fn set_symbols(&mut self, mut symbols: Vec<String>) {
+        symbols.sort();
+        symbols.dedup();
+
+        self.handles.retain(|(symbol, _)| symbols.contains(symbol));
+
+        for symbol in symbols {
+            if !self.handles.iter().any(|(s, _)| *s == symbol) {
+                let handle = Prices::new(symbol.clone(), self.time_frame).connect();
+
+                self.handles.push((symbol, handle));
+            }
+        }
+    }
+
+    pub fn set_time_frame(&mut self, time_frame: TimeFrame) {
+        self.time_frame = time_frame;
+
+        for (symbol, handle) in self.handles.iter_mut() {
+            *handle = Prices::new(symbol.clone(), time_frame).connect();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Update {
+    Prices(String, (TimeFrame, ChartMeta, Vec<Price>)),
+    Error(String, anyhow::Error),
+}
+
+impl Service for ComparisonService {
+    type Update = Update;
+
+    fn updates(&self) -> Vec<Self::Update> {
+        self.handles
+            .iter()
+            .flat_map(|(symbol, handle)| {
+                handle
+                    .response()
+                    .try_iter()
+                    .map(move |response| match response {
+                        Ok(prices) => Update::Prices(symbol.clone(), prices),
+                        Err(e) => Update::Error(symbol.clone(), e),
+                    })
+            })
+            .collect()
+    }
+
+    fn pause(&self) {
+        for (_, handle) in self.handles.iter() {
+            handle.pause();
+        }
+    }
+
+    fn resume(&self) {
+        for (_, handle) in self.handles.iter() {
+            handle.resume();
+        }
+    }
+}
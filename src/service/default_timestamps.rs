@@ -17,11 +17,24 @@ impl DefaultTimestampService {
     }
 }
 
+#[derive(Debug)]
+pub enum Update {
+    Timestamps(HashMap<TimeFrame, Vec<i64>>),
+    Error(anyhow::Error),
+}
+
 impl Service for DefaultTimestampService {
-    type Update = HashMap<TimeFrame, Vec<i64>>;
+    type Update = Update;
 
     fn updates(&self) -> Vec<Self::Update> {
-        self.handle.response().try_iter().collect()
+        self.handle
+            .response()
+            .try_iter()
+            .map(|response| match response {
+                Ok(timestamps) => Update::Timestamps(timestamps),
+                Err(e) => Update::Error(e),
+            })
+            .collect()
     }
 
     fn pause(&self) {
@@ -6,6 +6,7 @@ pub struct OptionsService {
     symbol: String,
     expiration_dates_handle: AsyncTaskHandle<Vec<i64>>,
     options_data_handle: Option<AsyncTaskHandle<model::OptionsHeader>>,
+    expirations_data_handle: Option<AsyncTaskHandle<Vec<model::OptionsHeader>>>,
 }
 
 impl OptionsService {
@@ -17,6 +18,7 @@ impl OptionsService {
             symbol,
             expiration_dates_handle,
             options_data_handle: None,
+            expirations_data_handle: None,
         }
     }
 
@@ -26,12 +28,54 @@ impl OptionsService {
 
         self.options_data_handle = Some(options_data_handle);
     }
+
+    /// Starts fetching the options data of every expiration date, which is only
+    /// needed to compare across them
+    pub fn fetch_all_expiration_dates(&mut self, expiration_dates: Vec<i64>) {
+        let task = OptionsExpirations::new(self.symbol.clone(), expiration_dates);
+        let expirations_data_handle = task.connect();
+
+        self.expirations_data_handle = Some(expirations_data_handle);
+    }
+
+    /// Cancels fetching every expiration date, once they're no longer compared
+    pub fn stop_fetching_all_expiration_dates(&mut self) {
+        self.expirations_data_handle = None;
+    }
+
+    pub fn is_fetching_all_expiration_dates(&self) -> bool {
+        self.expirations_data_handle.is_some()
+    }
 }
 
 #[derive(Debug)]
 pub enum Update {
     ExpirationDates(Vec<i64>),
     OptionsData(model::OptionsHeader),
+    ExpirationsData(Vec<model::OptionsHeader>),
+    Error(Fetch, anyhow::Error),
+}
+
+/// Data fetched for the options of a stock, used to attribute errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Fetch {
+    ExpirationDates,
+    OptionsData,
+    ExpirationsData,
+}
+
+fn responses<'a, R>(
+    handle: &'a AsyncTaskHandle<R>,
+    fetch: Fetch,
+    update: impl Fn(R) -> Update + 'a,
+) -> impl Iterator<Item = Update> + 'a {
+    handle
+        .response()
+        .try_iter()
+        .map(move |response| match response {
+            Ok(response) => update(response),
+            Err(e) => Update::Error(fetch, e),
+        })
 }
 
 impl Service for OptionsService {
@@ -40,19 +84,26 @@ impl Service for OptionsService {
     fn updates(&self) -> Vec<Self::Update> {
         let mut updates = vec![];
 
-        let expiration_dates_updates = self
-            .expiration_dates_handle
-            .response()
-            .try_iter()
-            .map(Update::ExpirationDates);
-        updates.extend(expiration_dates_updates);
+        updates.extend(responses(
+            &self.expiration_dates_handle,
+            Fetch::ExpirationDates,
+            Update::ExpirationDates,
+        ));
 
         if let Some(ref options_data_handle) = self.options_data_handle {
-            let options_data_updates = options_data_handle
-                .response()
-                .try_iter()
-                .map(Update::OptionsData);
-            updates.extend(options_data_updates);
+            updates.extend(responses(
+                options_data_handle,
+                Fetch::OptionsData,
+                Update::OptionsData,
+            ));
+        }
+
+        if let Some(ref expirations_data_handle) = self.expirations_data_handle {
+            updates.extend(responses(
+                expirations_data_handle,
+                Fetch::ExpirationsData,
+                Update::ExpirationsData,
+            ));
         }
 
         updates
@@ -63,6 +114,9 @@ impl Service for OptionsService {
         if let Some(handle) = self.options_data_handle.as_ref() {
             handle.pause();
         }
+        if let Some(handle) = self.expirations_data_handle.as_ref() {
+            handle.pause();
+        }
     }
 
     fn resume(&self) {
@@ -70,5 +124,8 @@ impl Service for OptionsService {
         if let Some(handle) = self.options_data_handle.as_ref() {
             handle.resume();
         }
+        if let Some(handle) = self.expirations_data_handle.as_ref() {
+            handle.resume();
+        }
     }
 }
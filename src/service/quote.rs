@@ -0,0 +1,58 @@
+use async_std::sync::Arc;
+use parking_lot::RwLock;
+
+use super::*;
+use crate::api::model::QuoteData;
+use crate::task::*;
+
+/// Fetches quotes for all tracked symbols together, so the number of requests
+/// doesn't grow with the number of stocks
+pub struct QuoteService {
+    symbols: Arc<RwLock<Vec<String>>>,
+    handle: AsyncTaskHandle<(Vec<String>, Vec<QuoteData>)>,
+}
+
+impl QuoteService {
+    pub fn new(symbols: Vec<String>) -> QuoteService {
+        let symbols = Arc::new(RwLock::new(symbols));
+
+        let task = Quotes::new(symbols.clone());
+        let handle = task.connect();
+
+        QuoteService { symbols, handle }
+    }
+
+    /// Replace the symbols included in the next batch
+    pub fn set_symbols(&self, symbols: Vec<String>) {
+        *self.symbols.write() = symbols;
+    }
+}
+
+#[derive(Debug)]
+pub enum Update {
+    Quotes((Vec<String>, Vec<QuoteData>)),
+    Error(anyhow::Error),
+}
+
+impl Service for QuoteService {
+    type Update = Update;
+
+    fn updates(&self) -> Vec<Self::Update> {
+        self.handle
+            .response()
+            .try_iter()
+            .map(|response| match response {
+                Ok(quotes) => Update::Quotes(quotes),
+                Err(e) => Update::Error(e),
+            })
+            .collect()
+    }
+
+    fn pause(&self) {
+        self.handle.pause();
+    }
+
+    fn resume(&self) {
+        self.handle.resume();
+    }
+}
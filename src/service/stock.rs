@@ -5,16 +5,12 @@ use crate::task::*;
 
 pub struct StockService {
     symbol: String,
-    current_price_handle: AsyncTaskHandle<(f64, Option<f64>, String)>,
     prices_handle: AsyncTaskHandle<(TimeFrame, ChartMeta, Vec<Price>)>,
     company_handle: AsyncTaskHandle<CompanyData>,
 }
 
 impl StockService {
     pub fn new(symbol: String, time_frame: TimeFrame) -> StockService {
-        let task = CurrentPrice::new(symbol.clone());
-        let current_price_handle = task.connect();
-
         let task = Prices::new(symbol.clone(), time_frame);
         let prices_handle = task.connect();
 
@@ -23,7 +19,6 @@ impl StockService {
 
         StockService {
             symbol,
-            current_price_handle,
             prices_handle,
             company_handle,
         }
@@ -39,9 +34,32 @@ impl StockService {
 
 #[derive(Debug)]
 pub enum Update {
-    NewPrice((f64, Option<f64>, String)),
     Prices((TimeFrame, ChartMeta, Vec<Price>)),
     CompanyData(Box<CompanyData>),
+    Error(Fetch, anyhow::Error),
+}
+
+/// Data fetched for a stock, used to attribute errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Fetch {
+    Quote,
+    Prices,
+    Company,
+    Timestamps,
+}
+
+fn responses<'a, R>(
+    handle: &'a AsyncTaskHandle<R>,
+    fetch: Fetch,
+    update: impl Fn(R) -> Update + 'a,
+) -> impl Iterator<Item = Update> + 'a {
+    handle
+        .response()
+        .try_iter()
+        .map(move |response| match response {
+            Ok(response) => update(response),
+            Err(e) => Update::Error(fetch, e),
+        })
 }
 
 impl Service for StockService {
@@ -50,35 +68,23 @@ impl Service for StockService {
     fn updates(&self) -> Vec<Self::Update> {
         let mut updates = vec![];
 
-        let current_price_updates = self
-            .current_price_handle
-            .response()
-            .try_iter()
-            .map(Update::NewPrice);
-        updates.extend(current_price_updates);
-
-        let prices_updates = self.prices_handle.response().try_iter().map(Update::Prices);
+        let prices_updates = responses(&self.prices_handle, Fetch::Prices, Update::Prices);
         updates.extend(prices_updates);
 
-        let company_updates = self
-            .company_handle
-            .response()
-            .try_iter()
-            .map(Box::new)
-            .map(Update::CompanyData);
+        let company_updates = responses(&self.company_handle, Fetch::Company, |data| {
+            Update::CompanyData(Box::new(data))
+        });
         updates.extend(company_updates);
 
         updates
     }
 
     fn pause(&self) {
-        self.current_price_handle.pause();
         self.prices_handle.pause();
         self.company_handle.pause();
     }
 
     fn resume(&self) {
-        self.current_price_handle.resume();
         self.prices_handle.resume();
         self.company_handle.resume();
     }
@@ -0,0 +1,141 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use async_std::future;
+use async_std::sync::Arc;
+use async_std::task::{self, JoinHandle};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use parking_lot::RwLock;
+
+use super::*;
+use crate::api::stream::{QuoteStream, StreamFormat, Tick};
+use crate::DATA_RECEIVED;
+
+/// How often subscriptions are brought in line with the tracked symbols
+const SUBSCRIPTION_CHECK: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(60);
+
+/// Keeps a websocket open that pushes real-time ticks for all tracked
+/// symbols, reconnecting with backoff whenever it drops
+pub struct StreamService {
+    symbols: Arc<RwLock<Vec<String>>>,
+    response: Receiver<Update>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StreamService {
+    pub fn new(url: String, format: StreamFormat, symbols: Vec<String>) -> StreamService {
+        let symbols = Arc::new(RwLock::new(symbols));
+        let (sender, response) = unbounded();
+
+        let handle = task::spawn({
+            let symbols = symbols.clone();
+
+            async move {
+                let mut delay = RECONNECT_DELAY;
+
+                loop {
+                    let started = Instant::now();
+
+                    // Whatever the reason the stream ended, polling covers for
+                    // it until we're reconnected
+                    let _ = stream(&url, format, &symbols, &sender).await;
+
+                    let _ = sender.send(Update::Disconnected);
+                    let _ = DATA_RECEIVED.0.try_send(());
+
+                    if started.elapsed() > RECONNECT_DELAY_MAX {
+                        delay = RECONNECT_DELAY;
+                    }
+
+                    task::sleep(delay).await;
+
+                    delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+                }
+            }
+        });
+
+        StreamService {
+            symbols,
+            response,
+            handle: Some(handle),
+        }
+    }
+
+    /// Replace the symbols subscribed to
+    pub fn set_symbols(&self, symbols: Vec<String>) {
+        *self.symbols.write() = symbols;
+    }
+}
+
+async fn stream(
+    url: &str,
+    format: StreamFormat,
+    symbols: &RwLock<Vec<String>>,
+    sender: &Sender<Update>,
+) -> Result<()> {
+    let mut stream = QuoteStream::connect(url, format).await?;
+
+    let _ = sender.send(Update::Connected);
+    let _ = DATA_RECEIVED.0.try_send(());
+
+    let mut subscribed: Vec<String> = vec![];
+
+    loop {
+        let wanted = symbols.read().clone();
+
+        if wanted != subscribed {
+            let removed = subscribed
+                .iter()
+                .filter(|symbol| !wanted.contains(symbol))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            stream.unsubscribe(&removed).await?;
+            stream.subscribe(&wanted).await?;
+
+            subscribed = wanted;
+        }
+
+        // Time out periodically so subscription changes are picked up even
+        // when no ticks are coming in
+        match future::timeout(SUBSCRIPTION_CHECK, stream.next_tick()).await {
+            Ok(Ok(Some(tick))) => {
+                let _ = sender.send(Update::Tick(tick));
+                let _ = DATA_RECEIVED.0.try_send(());
+            }
+            Ok(Ok(None)) => bail!("Stream closed by server"),
+            Ok(Err(e)) => return Err(e),
+            Err(_) => {}
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Update {
+    Connected,
+    Disconnected,
+    Tick(Tick),
+}
+
+impl Service for StreamService {
+    type Update = Update;
+
+    fn updates(&self) -> Vec<Self::Update> {
+        self.response.try_iter().collect()
+    }
+
+    // Ticks for every stock share the one connection, which stays open for as
+    // long as streaming is enabled
+    fn pause(&self) {}
+
+    fn resume(&self) {}
+}
+
+impl Drop for StreamService {
+    fn drop(&mut self) {
+        let handle = self.handle.take().unwrap();
+        task::spawn(async { handle.cancel().await });
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+use crate::api::model::SearchResult;
+use crate::task::*;
+
+/// Searches for symbols as the user types, only ever tracking the latest query,
+/// and looks up the quote of a submitted symbol to check it exists
+pub struct SymbolSearchService {
+    query: Option<String>,
+    handle: Option<AsyncTaskHandle<Vec<SearchResult>>>,
+    lookup_symbol: Option<String>,
+    lookup_handle: Option<AsyncTaskHandle<bool>>,
+}
+
+impl SymbolSearchService {
+    pub fn new() -> SymbolSearchService {
+        SymbolSearchService {
+            query: None,
+            handle: None,
+            lookup_symbol: None,
+            lookup_handle: None,
+        }
+    }
+
+    pub fn search(&mut self, query: String) {
+        let task = SymbolSearch::new(query.clone());
+
+        // Dropping the previous handle cancels a search still in flight
+        self.handle = Some(task.connect());
+        self.query = Some(query);
+    }
+
+    pub fn lookup(&mut self, symbol: String) {
+        let task = SymbolLookup::new(symbol.clone());
+
+        self.lookup_handle = Some(task.connect());
+        self.lookup_symbol = Some(symbol);
+    }
+
+    pub fn clear(&mut self) {
+        self.handle = None;
+        self.query = None;
+        self.clear_lookup();
+    }
+
+    pub fn clear_lookup(&mut self) {
+        self.lookup_handle = None;
+        self.lookup_symbol = None;
+    }
+}
+
+#[derive(Debug)]
+pub enum Update {
+    Results(String, Vec<SearchResult>),
+    Failed(String),
+    Found(String),
+    NotFound(String),
+    LookupFailed(String),
+}
+
+impl Service for SymbolSearchService {
+    type Update = Update;
+
+    fn updates(&self) -> Vec<Self::Update> {
+        let mut updates = vec![];
+
+        if let (Some(handle), Some(query)) = (self.handle.as_ref(), self.query.as_ref()) {
+            let search_updates = handle.response().try_iter().map(|response| match response {
+                Ok(results) => Update::Results(query.clone(), results),
+                Err(_) => Update::Failed(query.clone()),
+            });
+            updates.extend(search_updates);
+        }
+
+        if let (Some(handle), Some(symbol)) =
+            (self.lookup_handle.as_ref(), self.lookup_symbol.as_ref())
+        {
+            let lookup_updates = handle.response().try_iter().map(|response| match response {
+                Ok(true) => Update::Found(symbol.clone()),
+                Ok(false) => Update::NotFound(symbol.clone()),
+                Err(_) => Update::LookupFailed(symbol.clone()),
+            });
+            updates.extend(lookup_updates);
+        }
+
+        updates
+    }
+
+    fn pause(&self) {
+        if let Some(handle) = self.handle.as_ref() {
+            handle.pause();
+        }
+        if let Some(handle) = self.lookup_handle.as_ref() {
+            handle.pause();
+        }
+    }
+
+    fn resume(&self) {
+        if let Some(handle) = self.handle.as_ref() {
+            handle.resume();
+        }
+        if let Some(handle) = self.lookup_handle.as_ref() {
+            handle.resume();
+        }
+    }
+}
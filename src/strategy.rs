@@ -0,0 +1,460 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::opts;
+
+/// Shares each contract is for
+const CONTRACT_SIZE: f64 = 100.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Long,
+    Short,
+}
+
+impl Side {
+    fn sign(self) -> f64 {
+        match self {
+            Side::Long => 1.0,
+            Side::Short => -1.0,
+        }
+    }
+}
+
+/// Contracts of one option bought or sold, written as
+/// `long 1 call 520 2024-03-21 at 1.75`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Leg {
+    pub side: Side,
+    pub quantity: u32,
+    pub option_type: OptionType,
+    pub strike: f64,
+    pub expiration: NaiveDate,
+    /// Premium per share when opened
+    pub price: f64,
+}
+
+impl Leg {
+    /// Profit or loss of the leg if the underlying is at `price` when it
+    /// expires
+    fn payoff(&self, price: f64) -> f64 {
+        let intrinsic = match self.option_type {
+            OptionType::Call => (price - self.strike).max(0.0),
+            OptionType::Put => (self.strike - price).max(0.0),
+        };
+
+        self.side.sign() * (intrinsic - self.price) * self.quantity as f64 * CONTRACT_SIZE
+    }
+
+    /// Whether `other` is the same option, regardless of side
+    fn same_option(&self, other: &Leg) -> bool {
+        self.option_type == other.option_type
+            && self.strike == other.strike
+            && self.expiration == other.expiration
+    }
+}
+
+impl fmt::Display for Leg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} at {}",
+            match self.side {
+                Side::Long => "long",
+                Side::Short => "short",
+            },
+            self.quantity,
+            match self.option_type {
+                OptionType::Call => "call",
+                OptionType::Put => "put",
+            },
+            self.strike,
+            self.expiration.format("%Y-%m-%d"),
+            self.price
+        )
+    }
+}
+
+impl FromStr for Leg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid leg '{}', expected 'long 1 call 520 2024-03-21 at 1.75'",
+                s.trim()
+            )
+        };
+
+        let positive = |s: &str| {
+            s.parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite() && *value > 0.0)
+                .ok_or_else(invalid)
+        };
+
+        let parts = s.split_whitespace().collect::<Vec<_>>();
+
+        let [side, quantity, option_type, strike, expiration, "at", price] = parts[..] else {
+            return Err(invalid());
+        };
+
+        Ok(Leg {
+            side: match side.to_ascii_lowercase().as_str() {
+                "long" => Side::Long,
+                "short" => Side::Short,
+                _ => return Err(invalid()),
+            },
+            quantity: quantity
+                .parse()
+                .ok()
+                .filter(|quantity| *quantity > 0)
+                .ok_or_else(invalid)?,
+            option_type: match option_type.to_ascii_lowercase().as_str() {
+                "call" => OptionType::Call,
+                "put" => OptionType::Put,
+                _ => return Err(invalid()),
+            },
+            strike: positive(strike)?,
+            expiration: NaiveDate::parse_from_str(expiration, "%Y-%m-%d").map_err(|_| invalid())?,
+            price: positive(price)?,
+        })
+    }
+}
+
+impl TryFrom<String> for Leg {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Leg> for String {
+    fn from(leg: Leg) -> Self {
+        leg.to_string()
+    }
+}
+
+/// Legs traded together, such as a vertical spread, straddle or iron condor.
+/// Payoffs are at expiration, as if every leg expires together
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Strategy {
+    pub name: String,
+    pub legs: Vec<Leg>,
+}
+
+impl Strategy {
+    /// Adds the leg, netting it against one already held on the same option
+    pub fn add(&mut self, leg: Leg) {
+        let Some(idx) = self.legs.iter().position(|held| held.same_option(&leg)) else {
+            self.legs.push(leg);
+            return;
+        };
+
+        let held = &mut self.legs[idx];
+
+        if held.side == leg.side {
+            let quantity = held.quantity + leg.quantity;
+
+            held.price = (held.price * held.quantity as f64 + leg.price * leg.quantity as f64)
+                / quantity as f64;
+            held.quantity = quantity;
+        } else if held.quantity > leg.quantity {
+            held.quantity -= leg.quantity;
+        } else if held.quantity < leg.quantity {
+            *held = Leg {
+                quantity: leg.quantity - held.quantity,
+                ..leg
+            };
+        } else {
+            self.legs.remove(idx);
+        }
+    }
+
+    pub fn payoff(&self, price: f64) -> f64 {
+        self.legs.iter().map(|leg| leg.payoff(price)).sum()
+    }
+
+    /// Premium paid to open, negative when it's a credit
+    pub fn net_debit(&self) -> f64 {
+        self.legs
+            .iter()
+            .map(|leg| leg.side.sign() * leg.price * leg.quantity as f64 * CONTRACT_SIZE)
+            .sum()
+    }
+
+    /// Prices where the payoff changes slope, lowest first
+    pub fn strikes(&self) -> Vec<f64> {
+        let mut strikes = self.legs.iter().map(|leg| leg.strike).collect::<Vec<_>>();
+
+        strikes.sort_by(f64::total_cmp);
+        strikes.dedup();
+
+        strikes
+    }
+
+    /// Change in payoff per dollar the underlying rises past the highest
+    /// strike
+    fn upside_slope(&self) -> f64 {
+        self.legs
+            .iter()
+            .filter(|leg| leg.option_type == OptionType::Call)
+            .map(|leg| leg.side.sign() * leg.quantity as f64 * CONTRACT_SIZE)
+            .sum()
+    }
+
+    /// Payoff at zero and at each strike, between which it's linear
+    fn kinks(&self) -> Vec<(f64, f64)> {
+        [0.0]
+            .into_iter()
+            .chain(self.strikes())
+            .map(|price| (price, self.payoff(price)))
+            .collect()
+    }
+
+    /// Prices the underlying has to expire at for the strategy to break even
+    pub fn break_evens(&self) -> Vec<f64> {
+        let kinks = self.kinks();
+
+        let mut break_evens = kinks
+            .windows(2)
+            .filter_map(|pair| {
+                let [(x0, y0), (x1, y1)] = [pair[0], pair[1]];
+
+                ((y0 < 0.0 && y1 >= 0.0) || (y0 > 0.0 && y1 <= 0.0))
+                    .then(|| x0 + (x1 - x0) * y0 / (y0 - y1))
+            })
+            .collect::<Vec<_>>();
+
+        let slope = self.upside_slope();
+
+        if let Some((x, y)) = kinks.last().copied() {
+            if y * slope < 0.0 {
+                break_evens.push(x - y / slope);
+            }
+        }
+
+        break_evens
+    }
+
+    /// `None` when there's no limit to the profit
+    pub fn max_profit(&self) -> Option<f64> {
+        (self.upside_slope() <= 0.0).then(|| {
+            self.kinks()
+                .into_iter()
+                .map(|(_, y)| y)
+                .fold(f64::MIN, f64::max)
+        })
+    }
+
+    /// `None` when there's no limit to the loss
+    pub fn max_loss(&self) -> Option<f64> {
+        (self.upside_slope() >= 0.0).then(|| {
+            self.kinks()
+                .into_iter()
+                .map(|(_, y)| y)
+                .fold(f64::MAX, f64::min)
+        })
+    }
+}
+
+/// Strategies of each symbol, those set in `config.yml` when it was loaded
+/// along with those saved from the GUI since
+#[derive(Debug, Default)]
+pub struct StrategyBook {
+    config: BTreeMap<String, Vec<Strategy>>,
+    saved: BTreeMap<String, Vec<Strategy>>,
+}
+
+impl StrategyBook {
+    pub fn new(config: BTreeMap<String, Vec<Strategy>>) -> StrategyBook {
+        StrategyBook {
+            config,
+            saved: BTreeMap::new(),
+        }
+    }
+
+    /// Strategies of `symbol`, with those saved replacing any from `config.yml`
+    /// with the same name
+    pub fn strategies(&self, symbol: &str) -> Vec<Strategy> {
+        let mut strategies = self.config.get(symbol).cloned().unwrap_or_default();
+
+        for strategy in self.saved.get(symbol).into_iter().flatten() {
+            insert(&mut strategies, strategy.clone());
+        }
+
+        strategies
+    }
+
+    /// Saves the strategy to `config.yml`, replacing any for `symbol` with the
+    /// same name. Only this strategy is written, so the rest of the section is
+    /// kept as it is on disk
+    pub fn save(&mut self, symbol: &str, strategy: Strategy) -> Result<()> {
+        opts::save_strategy(symbol, &strategy)?;
+
+        insert(self.saved.entry(symbol.to_string()).or_default(), strategy);
+
+        Ok(())
+    }
+}
+
+/// Adds the strategy, replacing any with the same name
+pub fn insert(strategies: &mut Vec<Strategy>, strategy: Strategy) {
+    match strategies.iter().position(|s| s.name == strategy.name) {
+        Some(idx) => strategies[idx] = strategy,
+        None => strategies.push(strategy),
+    }
+}
+
+/// Name a strategy is saved under, which can't be empty
+pub struct StrategyName(pub String);
+
+impl FromStr for StrategyName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            Err("Enter a name to save the strategy as".to_string())
+        } else {
+            Ok(StrategyName(s.trim().to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strategy(name: &str, legs: &[&str]) -> Strategy {
+        Strategy {
+            name: name.to_string(),
+            legs: legs.iter().map(|leg| leg.parse().unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_leg() {
+        let leg: Leg = "Short 2 PUT 505.5 2024-03-21 at 0.72".parse().unwrap();
+
+        assert_eq!(
+            leg,
+            Leg {
+                side: Side::Short,
+                quantity: 2,
+                option_type: OptionType::Put,
+                strike: 505.5,
+                expiration: NaiveDate::from_ymd_opt(2024, 3, 21).unwrap(),
+                price: 0.72,
+            }
+        );
+        assert_eq!(leg.to_string().parse::<Leg>(), Ok(leg));
+    }
+
+    #[test]
+    fn test_parse_invalid_leg() {
+        for leg in [
+            "long 1 call 0 2024-03-21 at 1.75",
+            "long 1 call -520 2024-03-21 at 1.75",
+            "long 1 call 520 2024-03-21 at 0",
+            "long 1 call 520 2024-03-21 at -1.75",
+            "long 1 call NaN 2024-03-21 at 1.75",
+            "long 0 call 520 2024-03-21 at 1.75",
+            "long 1 call 520 2024-03-21 1.75",
+            "hold 1 call 520 2024-03-21 at 1.75",
+        ] {
+            assert!(leg.parse::<Leg>().is_err(), "{}", leg);
+        }
+    }
+
+    #[test]
+    fn test_long_call() {
+        let long_call = strategy("Call", &["long 1 call 520 2024-03-21 at 1.75"]);
+
+        assert_eq!(long_call.break_evens(), vec![521.75]);
+        assert_eq!(long_call.max_profit(), None);
+        assert_eq!(long_call.max_loss(), Some(-175.0));
+        assert_eq!(long_call.net_debit(), 175.0);
+    }
+
+    #[test]
+    fn test_short_straddle() {
+        let short_straddle = strategy(
+            "Straddle",
+            &[
+                "short 1 call 520 2024-03-21 at 4.00",
+                "short 1 put 520 2024-03-21 at 3.50",
+            ],
+        );
+
+        assert_eq!(short_straddle.break_evens(), vec![512.5, 527.5]);
+        assert_eq!(short_straddle.max_profit(), Some(750.0));
+        assert_eq!(short_straddle.max_loss(), None);
+        assert_eq!(short_straddle.net_debit(), -750.0);
+    }
+
+    #[test]
+    fn test_add_nets_legs() {
+        let mut spread = strategy("Spread", &["long 2 call 520 2024-03-21 at 1.75"]);
+
+        spread.add("short 1 call 520 2024-03-21 at 2.00".parse().unwrap());
+        assert_eq!(
+            spread.legs,
+            strategy("", &["long 1 call 520 2024-03-21 at 1.75"]).legs
+        );
+
+        spread.add("long 1 call 520 2024-03-21 at 2.25".parse().unwrap());
+        assert_eq!(
+            spread.legs,
+            strategy("", &["long 2 call 520 2024-03-21 at 2"]).legs
+        );
+
+        spread.add("short 2 call 520 2024-03-21 at 2.00".parse().unwrap());
+        assert!(spread.legs.is_empty());
+    }
+
+    #[test]
+    fn test_book_strategies() {
+        let call = "long 1 call 520 2024-03-21 at 1.75";
+        let put = "long 1 put 520 2024-03-21 at 2.10";
+
+        let mut book = StrategyBook::new(BTreeMap::from([(
+            "SPY".to_string(),
+            vec![strategy("Straddle", &[call]), strategy("Call", &[call])],
+        )]));
+
+        insert(
+            book.saved.entry("SPY".to_string()).or_default(),
+            strategy("Straddle", &[call, put]),
+        );
+        insert(
+            book.saved.entry("QQQ".to_string()).or_default(),
+            strategy("Put", &[put]),
+        );
+
+        // Saved strategies replace those from config.yml with the same name in
+        // place, without being copied over them
+        assert_eq!(
+            book.strategies("SPY"),
+            vec![
+                strategy("Straddle", &[call, put]),
+                strategy("Call", &[call]),
+            ]
+        );
+        assert_eq!(book.strategies("QQQ"), vec![strategy("Put", &[put])]);
+        assert_eq!(book.config["SPY"][0], strategy("Straddle", &[call]));
+        assert!(book.strategies("AMD").is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use std::time::{Duration, Instant};
 
+use anyhow::Result;
 use async_std::sync::Arc;
 use async_std::task;
 use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
@@ -7,19 +8,28 @@ use futures::future::BoxFuture;
 use task::JoinHandle;
 
 pub use self::company::Company;
-pub use self::current_price::CurrentPrice;
 pub use self::default_timestamps::DefaultTimestamps;
 pub use self::options_data::OptionsData;
 pub use self::options_dates::OptionsDates;
+pub use self::options_expirations::OptionsExpirations;
 pub use self::prices::Prices;
+pub use self::quotes::Quotes;
+pub use self::symbol_lookup::SymbolLookup;
+pub use self::symbol_search::SymbolSearch;
 use crate::{DATA_RECEIVED, UPDATE_INTERVAL};
 
 mod company;
-mod current_price;
 mod default_timestamps;
 mod options_data;
 mod options_dates;
+mod options_expirations;
 mod prices;
+mod quotes;
+mod symbol_lookup;
+mod symbol_search;
+
+/// Longest a task that only needs to succeed once waits between retries
+const RETRY_INTERVAL_MAX: Duration = Duration::from_secs(5 * 60);
 
 /// Trait to define a type that spawns an Async Task to complete background
 /// work.
@@ -29,19 +39,22 @@ pub trait AsyncTask: 'static {
 
     /// Interval that `task` should be executed at
     ///
-    /// If `None` is returned, the task will only get executed once then exit
+    /// If `None` is returned, the task will exit once it has succeeded, being
+    /// retried starting at the default update interval, doubling after each
+    /// failure up to 5 minutes
     fn update_interval(&self) -> Option<Duration>;
 
     /// Input data needed for the `task`
     fn input(&self) -> Self::Input;
 
-    /// Defines the async task that will get executed and return` Response`
-    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Option<Self::Response>>;
+    /// Defines the async task that will get executed and return `Response`, or
+    /// the error that prevented it
+    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>>;
 
     /// Runs the task on the async runtime and returns a handle to query updates from
     fn connect(&self) -> AsyncTaskHandle<Self::Response> {
         let (command_sender, command_receiver) = bounded(1);
-        let (response_sender, response_receiver) = unbounded::<Self::Response>();
+        let (response_sender, response_receiver) = unbounded::<Result<Self::Response>>();
         let data_received = DATA_RECEIVED.0.clone();
 
         let update_interval = self.update_interval();
@@ -53,16 +66,16 @@ pub trait AsyncTask: 'static {
             let mut paused = false;
 
             // Execute the task initially and request a redraw to display this data
-            if let Some(response) = <Self as AsyncTask>::task(input.clone()).await {
-                let _ = response_sender.send(response);
-                let _ = data_received.try_send(());
-            }
-
-            // If no update interval is defined, exit task
-            let update_interval = if let Some(interval) = update_interval {
-                interval.max(Duration::from_secs(*UPDATE_INTERVAL))
-            } else {
-                return;
+            let response = <Self as AsyncTask>::task(input.clone()).await;
+            let succeeded = response.is_ok();
+            let _ = response_sender.send(response);
+            let _ = data_received.try_send(());
+
+            // If no update interval is defined, exit task once it has succeeded
+            let (mut update_interval, once) = match update_interval {
+                Some(interval) => (interval.max(Duration::from_secs(*UPDATE_INTERVAL)), false),
+                None if succeeded => return,
+                None => (Duration::from_secs(*UPDATE_INTERVAL), true),
             };
 
             // Execute task every update interval
@@ -75,9 +88,15 @@ pub trait AsyncTask: 'static {
                 }
 
                 if last_updated.elapsed() >= update_interval && !paused {
-                    if let Some(response) = <Self as AsyncTask>::task(input.clone()).await {
-                        let _ = response_sender.send(response);
-                        let _ = data_received.try_send(());
+                    let response = <Self as AsyncTask>::task(input.clone()).await;
+                    let succeeded = response.is_ok();
+                    let _ = response_sender.send(response);
+                    let _ = data_received.try_send(());
+
+                    if once && succeeded {
+                        return;
+                    } else if once {
+                        update_interval = (update_interval * 2).min(RETRY_INTERVAL_MAX);
                     }
 
                     last_updated = Instant::now();
@@ -102,13 +121,13 @@ enum AsyncTaskCommand {
 }
 
 pub struct AsyncTaskHandle<R> {
-    response: Receiver<R>,
+    response: Receiver<Result<R>>,
     handle: Option<JoinHandle<()>>,
     command_sender: Sender<AsyncTaskCommand>,
 }
 
 impl<R> AsyncTaskHandle<R> {
-    pub fn response(&self) -> &Receiver<R> {
+    pub fn response(&self) -> &Receiver<Result<R>> {
         &self.response
     }
 
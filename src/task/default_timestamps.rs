@@ -26,25 +26,37 @@ impl AsyncTask for DefaultTimestamps {
 
     fn input(&self) -> Self::Input {}
 
-    fn task<'a>(_input: Arc<Self::Input>) -> BoxFuture<'a, Option<Self::Response>> {
+    fn task<'a>(_input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>> {
         Box::pin(async move {
             let symbol = "SPY";
 
-            let tasks = TimeFrame::ALL[1..].iter().map(|timeframe| async move {
+            // Max goes back as far as each stock does, so there's nothing to line
+            // them up to
+            let time_frames = TimeFrame::ALL
+                .into_iter()
+                .filter(|timeframe| !matches!(timeframe, TimeFrame::Day1 | TimeFrame::Max))
+                .filter_map(|timeframe| Some((timeframe, timeframe.as_range()?)));
+
+            let tasks = time_frames.map(|(timeframe, range)| async move {
                 let interval = timeframe.api_interval();
-                let range = timeframe.as_range();
 
-                if let Ok(chart) = crate::CLIENT
+                crate::CLIENT
                     .get_chart_data(symbol, interval, range, false)
                     .await
-                {
-                    Some((*timeframe, chart.timestamp))
-                } else {
-                    None
-                }
+                    .map(|chart| (timeframe, chart.timestamp))
             });
 
-            Some(join_all(tasks).await.into_iter().flatten().collect())
+            let (timestamps, errors): (Vec<_>, Vec<_>) =
+                join_all(tasks).await.into_iter().partition(Result::is_ok);
+
+            // Partial results are still useful, only fail if nothing came back
+            if timestamps.is_empty() {
+                if let Some(Err(e)) = errors.into_iter().next() {
+                    return Err(e);
+                }
+            }
+
+            Ok(timestamps.into_iter().flatten().collect())
         })
     }
 }
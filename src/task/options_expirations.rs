@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use async_std::sync::Arc;
+use futures::future::BoxFuture;
+
+use super::*;
+use crate::api::model;
+
+/// Returns options data for every expiration date of a company, skipping any
+/// that fail as long as one succeeds. Only fetched once, since it's a lot of
+/// requests, and retried while every one fails
+pub struct OptionsExpirations {
+    symbol: String,
+    dates: Vec<i64>,
+}
+
+impl OptionsExpirations {
+    pub fn new(symbol: String, dates: Vec<i64>) -> OptionsExpirations {
+        OptionsExpirations { symbol, dates }
+    }
+}
+
+impl AsyncTask for OptionsExpirations {
+    type Input = (String, Vec<i64>);
+    type Response = Vec<model::OptionsHeader>;
+
+    fn update_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    fn input(&self) -> Self::Input {
+        (self.symbol.clone(), self.dates.clone())
+    }
+
+    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>> {
+        Box::pin(async move {
+            let symbol = &input.0;
+
+            let mut headers = vec![];
+            let mut error = None;
+
+            // One at a time, to go easy on rate limits
+            for date in input.1.iter().copied() {
+                match crate::CLIENT
+                    .get_options_for_expiration_date(symbol, date)
+                    .await
+                {
+                    Ok(header) => headers.push(header),
+                    Err(e) => error = Some(e),
+                }
+            }
+
+            match error {
+                Some(e) if headers.is_empty() => Err(e),
+                _ => Ok(headers),
+            }
+        })
+    }
+}
@@ -1,8 +1,10 @@
+use anyhow::bail;
 use async_std::sync::Arc;
 use futures::future::BoxFuture;
 
 use super::*;
 use crate::api::model::ChartMeta;
+use crate::cache::PriceCache;
 use crate::common::{chart_data_to_prices, Price, TimeFrame};
 
 /// Returns an array of prices, depending on the TimeFrame chosen
@@ -22,14 +24,14 @@ impl AsyncTask for Prices {
     type Response = (TimeFrame, ChartMeta, Vec<Price>);
 
     fn update_interval(&self) -> Option<Duration> {
-        Some(self.time_frame.update_interval())
+        self.time_frame.update_interval()
     }
 
     fn input(&self) -> Self::Input {
         (self.symbol.clone(), self.time_frame)
     }
 
-    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Option<Self::Response>> {
+    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>> {
         Box::pin(async move {
             let symbol = &input.0;
             let time_frame = input.1;
@@ -38,18 +40,55 @@ impl AsyncTask for Prices {
 
             let include_pre_post = time_frame == TimeFrame::Day1;
 
-            if let Ok(response) = crate::CLIENT
-                .get_chart_data(symbol, interval, time_frame.as_range(), include_pre_post)
-                .await
-            {
-                Some((
-                    time_frame,
-                    response.meta.clone(),
-                    chart_data_to_prices(response),
-                ))
-            } else {
-                None
+            let cache = PriceCache::enabled(time_frame)
+                .then(|| PriceCache::load(symbol, time_frame))
+                .flatten();
+
+            // Only fetch what's newer than the cache, falling back to the full
+            // range if that fails
+            if let Some(mut cache) = cache {
+                let tail = crate::CLIENT
+                    .get_chart_data_since(symbol, interval, cache.since(time_frame), false)
+                    .await;
+
+                if let Ok(response) = tail {
+                    let mut meta = response.meta.clone();
+
+                    cache.extend(chart_data_to_prices(response));
+                    let _ = cache.save(symbol, time_frame);
+
+                    // The tail's previous close is from partway through the range
+                    meta.chart_previous_close = cache.prev_close.unwrap_or_default();
+
+                    return Ok((time_frame, meta, cache.prices));
+                }
             }
+
+            let response = match (time_frame.as_range(), time_frame.custom()) {
+                (Some(range), _) => {
+                    crate::CLIENT
+                        .get_chart_data(symbol, interval, range, include_pre_post)
+                        .await?
+                }
+                (None, Some(custom)) => {
+                    let (start, end) = custom.period();
+
+                    crate::CLIENT
+                        .get_chart_data_between(symbol, interval, start, end, false)
+                        .await?
+                }
+                (None, None) => bail!("Unknown time frame"),
+            };
+
+            let meta = response.meta.clone();
+            let prices = chart_data_to_prices(response);
+
+            if PriceCache::enabled(time_frame) && !prices.is_empty() {
+                let _ = PriceCache::new(prices.clone(), meta.chart_previous_close)
+                    .save(symbol, time_frame);
+            }
+
+            Ok((time_frame, meta, prices))
         })
     }
 }
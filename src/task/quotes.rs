@@ -0,0 +1,46 @@
+use async_std::sync::Arc;
+use futures::future::BoxFuture;
+use parking_lot::RwLock;
+
+use super::*;
+use crate::api::model::QuoteData;
+
+/// Returns the latest quote of every tracked symbol from a single request,
+/// along with the symbols that were requested
+pub struct Quotes {
+    symbols: Arc<RwLock<Vec<String>>>,
+}
+
+impl Quotes {
+    pub fn new(symbols: Arc<RwLock<Vec<String>>>) -> Quotes {
+        Quotes { symbols }
+    }
+}
+
+impl AsyncTask for Quotes {
+    type Input = Arc<RwLock<Vec<String>>>;
+    type Response = (Vec<String>, Vec<QuoteData>);
+
+    fn update_interval(&self) -> Option<Duration> {
+        Some(Duration::from_secs(1))
+    }
+
+    fn input(&self) -> Self::Input {
+        self.symbols.clone()
+    }
+
+    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>> {
+        Box::pin(async move {
+            // Symbols can be added or removed between runs
+            let symbols = input.read().clone();
+
+            if symbols.is_empty() {
+                return Ok((symbols, vec![]));
+            }
+
+            let quotes = crate::CLIENT.get_quotes(&symbols).await?;
+
+            Ok((symbols, quotes))
+        })
+    }
+}
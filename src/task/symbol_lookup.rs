@@ -0,0 +1,42 @@
+use async_std::sync::Arc;
+use futures::future::BoxFuture;
+
+use super::*;
+
+/// Returns whether a quote exists for a symbol, to check it before it's added
+pub struct SymbolLookup {
+    symbol: String,
+}
+
+impl SymbolLookup {
+    pub fn new(symbol: String) -> SymbolLookup {
+        SymbolLookup { symbol }
+    }
+}
+
+impl AsyncTask for SymbolLookup {
+    type Input = String;
+    type Response = bool;
+
+    fn update_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    fn input(&self) -> Self::Input {
+        self.symbol.clone()
+    }
+
+    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>> {
+        Box::pin(async move {
+            let symbol = input.as_ref();
+
+            let quotes = crate::CLIENT
+                .get_quotes(std::slice::from_ref(symbol))
+                .await?;
+
+            Ok(quotes
+                .iter()
+                .any(|quote| quote.symbol.eq_ignore_ascii_case(symbol)))
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use async_std::sync::Arc;
+use futures::future::BoxFuture;
+
+use super::*;
+use crate::api::model::SearchResult;
+
+/// Wait after the last keystroke before searching, so typing a symbol doesn't
+/// send a request for every character
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Returns symbols matching what has been typed so far
+pub struct SymbolSearch {
+    query: String,
+}
+
+impl SymbolSearch {
+    pub fn new(query: String) -> SymbolSearch {
+        SymbolSearch { query }
+    }
+}
+
+impl AsyncTask for SymbolSearch {
+    type Input = String;
+    type Response = Vec<SearchResult>;
+
+    fn update_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    fn input(&self) -> Self::Input {
+        self.query.clone()
+    }
+
+    fn task<'a>(input: Arc<Self::Input>) -> BoxFuture<'a, Result<Self::Response>> {
+        Box::pin(async move {
+            let query = input.as_ref();
+
+            // Searching for the next keystroke drops this task before it wakes
+            task::sleep(DEBOUNCE).await;
+
+            crate::CLIENT.search_symbols(query).await
+        })
+    }
+}
@@ -49,9 +49,32 @@ def_theme_struct_with_defaults!(
     border_secondary => Color::Reset,
     border_axis => Color::Blue,
     highlight_focused => Color::LightBlue,
-    highlight_unfocused => Color::DarkGray
+    highlight_unfocused => Color::DarkGray,
+    overlay_sma => Color::Yellow,
+    overlay_ema => Color::LightBlue,
+    overlay_bollinger => Color::Magenta,
+    overlay_vwap => Color::Cyan,
+    oscillator_line => Color::LightBlue,
+    oscillator_signal => Color::Yellow,
+    comparison_1 => Color::LightMagenta,
+    comparison_2 => Color::LightCyan,
+    comparison_3 => Color::LightYellow,
+    comparison_4 => Color::White
 );
 
+impl Theme {
+    /// Color of the `idx`th symbol compared against, cycling through the four
+    /// comparison colors
+    pub fn comparison(self, idx: usize) -> Color {
+        [
+            self.comparison_1(),
+            self.comparison_2(),
+            self.comparison_3(),
+            self.comparison_4(),
+        ][idx % 4]
+    }
+}
+
 fn hex_to_color(hex: &str) -> Option<Color> {
     if hex.len() == 7 {
         let hash = &hex[0..1];
@@ -6,18 +6,29 @@ use ratatui::layout::Rect;
 use ratatui::widgets::StatefulWidget;
 
 pub use self::add_stock::{AddStockState, AddStockWidget};
-pub use self::chart_configuration::{ChartConfigurationWidget, KagiOptions};
+pub use self::alert_history::{AlertHistoryState, AlertHistoryWidget};
+pub use self::alerts::{AlertsState, AlertsWidget};
+pub use self::chart_configuration::{
+    ChartConfigurationWidget, KagiOptions, OverlayOptions, PointFigureOptions, RenkoOptions,
+};
 pub use self::help::{HelpWidget, HELP_HEIGHT, HELP_WIDTH};
-pub use self::options::{OptionsState, OptionsWidget};
-pub use self::stock::{StockState, StockWidget};
+pub use self::options::{
+    OptionsChainWidget, OptionsInterestWidget, OptionsState, OptionsStrategyWidget,
+    OptionsVolatilityWidget, OptionsWidget,
+};
+pub use self::prompt::{PromptState, PromptWidget};
+pub use self::stock::{Comparison, StockState, StockWidget};
 pub use self::stock_summary::StockSummaryWidget;
 
 mod add_stock;
+mod alert_history;
+mod alerts;
 pub mod block;
 mod chart;
 pub mod chart_configuration;
 mod help;
 pub mod options;
+mod prompt;
 mod stock;
 mod stock_summary;
 
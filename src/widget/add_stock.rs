@@ -1,18 +1,36 @@
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Alignment, Rect};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Paragraph, StatefulWidget, Widget, Wrap};
+use ratatui::widgets::{Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget};
 
 use super::block;
-use crate::common::ChartType;
+use crate::api::model::SearchResult;
+use crate::service::symbol_search::{self, SymbolSearchService};
+use crate::service::Service;
 use crate::theme::style;
 use crate::THEME;
 
+/// Max number of search results shown in the dropdown
+const MAX_RESULTS: usize = 6;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum SearchStatus {
+    Idle,
+    Searching,
+    Done,
+    Failed,
+}
+
 pub struct AddStockState {
     search_string: String,
     has_user_input: bool,
     error_msg: Option<String>,
+    search_service: SymbolSearchService,
+    search_status: SearchStatus,
+    results: Vec<SearchResult>,
+    selected: Option<usize>,
+    validating: bool,
 }
 
 impl AddStockState {
@@ -21,40 +39,160 @@ impl AddStockState {
             search_string: String::new(),
             has_user_input: false,
             error_msg: Some(String::new()),
+            search_service: SymbolSearchService::new(),
+            search_status: SearchStatus::Idle,
+            results: vec![],
+            selected: None,
+            validating: false,
         }
     }
 
     pub fn add_char(&mut self, c: char) {
         self.search_string.push(c);
         self.has_user_input = true;
+
+        self.search();
     }
 
     pub fn del_char(&mut self) {
         self.search_string.pop();
+
+        self.search();
     }
 
     pub fn reset(&mut self) {
         self.search_string.drain(..);
         self.has_user_input = false;
         self.error_msg = None;
+
+        self.clear_search();
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.results.len().min(MAX_RESULTS);
+
+        if len > 0 {
+            self.selected = Some(self.selected.map_or(0, |idx| (idx + 1) % len));
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        let len = self.results.len().min(MAX_RESULTS);
+
+        if len > 0 {
+            self.selected = Some(self.selected.map_or(len - 1, |idx| (idx + len - 1) % len));
+        }
+    }
+
+    /// Returns the symbol to add if a search result is selected. Otherwise the
+    /// typed symbol is looked up, and returned from `update` if it exists.
+    pub fn enter(&mut self) -> Option<String> {
+        if let Some(result) = self.selected.and_then(|idx| self.results.get(idx)) {
+            return Some(result.symbol.clone());
+        }
+
+        self.submit();
+
+        None
+    }
+
+    pub fn update(&mut self) -> Option<String> {
+        let mut submitted = None;
+
+        for update in self.search_service.updates() {
+            match update {
+                symbol_search::Update::Results(query, results) if query == self.query() => {
+                    self.results = results;
+                    self.search_status = SearchStatus::Done;
+                }
+                symbol_search::Update::Failed(query) if query == self.query() => {
+                    self.search_status = SearchStatus::Failed;
+                }
+                symbol_search::Update::Found(symbol) => submitted = Some(symbol),
+                symbol_search::Update::NotFound(symbol) => {
+                    self.reset();
+                    self.error_msg = Some(format!("Unknown symbol: {}", symbol));
+                }
+                // Symbol can't be validated if quotes are unavailable, so let the
+                // stock widget report any error fetching it instead
+                symbol_search::Update::LookupFailed(symbol) => submitted = Some(symbol),
+                _ => {}
+            }
+        }
+
+        if submitted.is_some() {
+            self.search_service.clear_lookup();
+            self.validating = false;
+        }
+
+        submitted
+    }
+
+    /// Height needed to display the prompt and any search results
+    pub fn height(&self) -> u16 {
+        3 + self.results.len().min(MAX_RESULTS) as u16
     }
 
-    pub fn enter(&mut self, chart_type: ChartType) -> super::StockState {
-        super::StockState::new(self.search_string.clone().to_ascii_uppercase(), chart_type)
+    fn query(&self) -> &str {
+        self.search_string.trim()
+    }
+
+    fn search(&mut self) {
+        self.selected = None;
+
+        // Editing the symbol cancels submitting it
+        self.search_service.clear_lookup();
+        self.validating = false;
+
+        if self.query().is_empty() {
+            self.clear_search();
+        } else {
+            self.search_service.search(self.query().to_string());
+            self.search_status = SearchStatus::Searching;
+        }
+    }
+
+    fn clear_search(&mut self) {
+        self.search_service.clear();
+        self.search_status = SearchStatus::Idle;
+        self.results.clear();
+        self.selected = None;
+        self.validating = false;
+    }
+
+    fn submit(&mut self) {
+        let symbol = self.query().to_ascii_uppercase();
+
+        if !symbol.is_empty() && !self.validating {
+            self.search_service.lookup(symbol);
+            self.validating = true;
+        }
     }
 }
 
-pub struct AddStockWidget {}
+pub struct AddStockWidget {
+    pub title: &'static str,
+}
 
 impl StatefulWidget for AddStockWidget {
     type State = AddStockState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let spans = if !state.has_user_input && state.error_msg.is_some() {
+        let block = block::new(self.title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        // layout[0] - Prompt
+        // layout[1] - Search results
+        let layout = Layout::default()
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(inner);
+
+        let spans = if let (false, Some(error_msg)) = (state.has_user_input, &state.error_msg) {
             Line::from(vec![
                 Span::styled("> ", style().fg(THEME.text_normal())),
                 Span::styled(
-                    state.error_msg.as_ref().unwrap(),
+                    error_msg,
                     style().add_modifier(Modifier::BOLD).fg(THEME.loss()),
                 ),
             ])
@@ -71,10 +209,66 @@ impl StatefulWidget for AddStockWidget {
         };
 
         Paragraph::new(spans)
-            .block(block::new(" Add Ticker "))
             .style(style())
             .alignment(Alignment::Left)
-            .wrap(Wrap { trim: true })
-            .render(area, buf);
+            .render(layout[0], buf);
+
+        let status = if state.validating {
+            Some("Checking...")
+        } else if state.search_status == SearchStatus::Searching {
+            Some("Searching...")
+        } else {
+            None
+        };
+
+        if let Some(status) = status {
+            Paragraph::new(Span::styled(status, style().fg(THEME.gray())))
+                .alignment(Alignment::Right)
+                .render(layout[0], buf);
+        }
+
+        if state.results.is_empty() {
+            return;
+        }
+
+        let rows = state.results.iter().take(MAX_RESULTS).map(|result| {
+            let name = result
+                .long_name
+                .as_deref()
+                .or(result.short_name.as_deref())
+                .unwrap_or_default();
+
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    result.symbol.as_str(),
+                    style().add_modifier(Modifier::BOLD),
+                )),
+                Cell::from(name),
+                Cell::from(result.exchange.as_deref().unwrap_or_default()),
+                Cell::from(result.quote_type.as_deref().unwrap_or_default()),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Percentage(100),
+                Constraint::Length(12),
+                Constraint::Length(14),
+            ],
+        )
+        .style(style().fg(THEME.text_normal()))
+        .highlight_style(
+            style()
+                .bg(THEME.highlight_focused())
+                .fg(THEME.text_normal()),
+        )
+        .column_spacing(2);
+
+        let mut table_state = TableState::default();
+        table_state.select(state.selected);
+
+        <Table as StatefulWidget>::render(table, layout[1], buf, &mut table_state);
     }
 }
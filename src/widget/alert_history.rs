@@ -0,0 +1,142 @@
+use chrono::{Local, TimeZone};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Modifier;
+use ratatui::text::Span;
+use ratatui::widgets::block::{Position, Title};
+use ratatui::widgets::{Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget};
+
+use super::block;
+use crate::common::format_decimals;
+use crate::draw::{add_padding, PaddingDirection};
+use crate::theme::style;
+use crate::{ALERT_LOG, THEME};
+
+/// Selected entry of the alert history, counting from the newest
+#[derive(Default)]
+pub struct AlertHistoryState {
+    table_state: TableState,
+}
+
+impl AlertHistoryState {
+    pub fn selected(&self) -> usize {
+        self.table_state.selected().unwrap_or_default()
+    }
+
+    pub fn select_next(&mut self, len: usize) {
+        if len > 0 {
+            self.table_state
+                .select(Some((self.selected() + 1).min(len - 1)));
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.table_state
+            .select(Some(self.selected().saturating_sub(1)));
+    }
+
+    /// Index into the log of the selected entry
+    pub fn log_idx(&self, len: usize) -> Option<usize> {
+        len.checked_sub(self.selected() + 1)
+    }
+}
+
+/// Every logged alert, newest first, with those not yet acknowledged marked
+/// as new. Failures to read or save the log are shown on the bottom border
+pub struct AlertHistoryWidget {}
+
+impl StatefulWidget for AlertHistoryWidget {
+    type State = AlertHistoryState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let alert_log = ALERT_LOG.read();
+
+        let mut block = block::new(" Alert History - <ESC> to go back ");
+
+        if let Some(error) = alert_log.error.as_ref() {
+            block = block.title(
+                Title::from(Span::styled(
+                    format!(" {} ", error),
+                    style().fg(THEME.loss()),
+                ))
+                .position(Position::Bottom),
+            );
+        }
+
+        block.render(area, buf);
+        area = add_padding(area, 1, PaddingDirection::All);
+        area = add_padding(area, 1, PaddingDirection::Left);
+        area = add_padding(area, 1, PaddingDirection::Right);
+
+        // layout[0] - Alerts
+        // layout[1] - Key hints
+        let layout = Layout::default()
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+
+        Paragraph::new(Span::styled(
+            "<Up / Down>: scroll  <Enter>: acknowledge  a: acknowledge all  d: delete  D: clear",
+            style().fg(THEME.gray()),
+        ))
+        .render(layout[1], buf);
+
+        if alert_log.fired.is_empty() {
+            Paragraph::new(Span::styled(
+                "No alerts have fired",
+                style().fg(THEME.text_normal()),
+            ))
+            .render(layout[0], buf);
+
+            return;
+        }
+
+        let selected = state.selected().min(alert_log.fired.len() - 1);
+        state.table_state.select(Some(selected));
+
+        let header = Row::new(vec!["Time", "Alert", "Price", ""])
+            .style(style().fg(THEME.text_secondary()))
+            .bottom_margin(1);
+
+        let rows = alert_log.fired.iter().rev().map(|fired| {
+            let time = Local
+                .timestamp_opt(fired.time, 0)
+                .single()
+                .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_default();
+
+            let status = if fired.acknowledged {
+                Span::styled("", style())
+            } else {
+                Span::styled(
+                    "New",
+                    style()
+                        .fg(THEME.text_primary())
+                        .add_modifier(Modifier::BOLD),
+                )
+            };
+
+            Row::new(vec![
+                Cell::from(Span::styled(time, style().fg(THEME.gray()))),
+                Cell::from(fired.alert.to_string()),
+                Cell::from(format_decimals(fired.price)),
+                Cell::from(status),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(19),
+                Constraint::Length(layout[0].width.saturating_sub(19 + 12 + 3 + 6)),
+                Constraint::Length(12),
+                Constraint::Length(3),
+            ],
+        )
+        .header(header)
+        .style(style().fg(THEME.text_normal()))
+        .highlight_style(style().bg(THEME.highlight_focused()))
+        .column_spacing(2);
+
+        <Table as StatefulWidget>::render(table, layout[0], buf, &mut state.table_state);
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{Local, TimeZone};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Modifier;
+use ratatui::text::Span;
+use ratatui::widgets::{
+    Block, Borders, Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget,
+};
+
+use super::block;
+use crate::alert::AlertState;
+use crate::common::format_decimals;
+use crate::draw::{add_padding, PaddingDirection};
+use crate::theme::style;
+use crate::{ALERT_LOG, THEME};
+
+/// Selected alert of the current stock
+#[derive(Default)]
+pub struct AlertsState {
+    pub selected: usize,
+}
+
+impl AlertsState {
+    pub fn select_next(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = (self.selected + 1).min(len - 1);
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+/// Alerts watched on the current stock, followed by the alerts fired, newest
+/// first. Those fired since the pane was last closed are bold
+pub struct AlertsWidget<'a> {
+    pub symbol: &'a str,
+    pub alerts: &'a [AlertState],
+}
+
+impl StatefulWidget for AlertsWidget<'_> {
+    type State = AlertsState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        block::new(" Alerts ").render(area, buf);
+        area = add_padding(area, 1, PaddingDirection::All);
+        area = add_padding(area, 1, PaddingDirection::Left);
+        area = add_padding(area, 1, PaddingDirection::Right);
+
+        state.selected = state.selected.min(self.alerts.len().saturating_sub(1));
+
+        // Draw the stock's alerts
+        {
+            Paragraph::new(Span::styled(
+                self.symbol,
+                style().fg(THEME.text_secondary()),
+            ))
+            .render(area, buf);
+            area = add_padding(area, 2, PaddingDirection::Top);
+
+            let height = (self.alerts.len().max(1) as u16).min(area.height);
+            let mut alerts_area = area;
+            alerts_area.height = height;
+
+            if self.alerts.is_empty() {
+                Paragraph::new(Span::styled(
+                    "Press 'a' to add an alert",
+                    style().fg(THEME.gray()),
+                ))
+                .render(alerts_area, buf);
+            } else {
+                let rows = self.alerts.iter().map(|alert| {
+                    let (status, color) = if alert.triggered {
+                        ("Triggered", THEME.text_primary())
+                    } else {
+                        ("Watching", THEME.gray())
+                    };
+
+                    Row::new(vec![
+                        Cell::from(alert.condition.to_string()),
+                        Cell::from(Span::styled(status, style().fg(color))),
+                    ])
+                });
+
+                let table = Table::new(
+                    rows,
+                    [
+                        Constraint::Length(area.width.saturating_sub(10)),
+                        Constraint::Length(9),
+                    ],
+                )
+                .style(style().fg(THEME.text_normal()))
+                .highlight_style(style().bg(THEME.highlight_focused()));
+
+                let mut table_state = TableState::default();
+                table_state.select(Some(state.selected));
+
+                <Table as StatefulWidget>::render(table, alerts_area, buf, &mut table_state);
+            }
+
+            area = add_padding(area, height + 1, PaddingDirection::Top);
+        }
+
+        if area.height < 3 {
+            return;
+        }
+
+        // Draw fired alerts
+        {
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(style().fg(THEME.border_secondary()))
+                .title(Span::styled(" Fired ", style().fg(THEME.text_normal())))
+                .render(area, buf);
+            area = add_padding(area, 1, PaddingDirection::Top);
+
+            let alert_log = ALERT_LOG.read();
+
+            let rows = alert_log.fired.iter().rev().map(|fired| {
+                let time = Local
+                    .timestamp_opt(fired.time, 0)
+                    .single()
+                    .map(|time| time.format("%m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+
+                let modifier = if fired.acknowledged {
+                    Modifier::empty()
+                } else {
+                    Modifier::BOLD
+                };
+
+                Row::new(vec![
+                    Cell::from(Span::styled(time, style().fg(THEME.gray()))),
+                    Cell::from(fired.alert.to_string()),
+                    Cell::from(format_decimals(fired.price)),
+                ])
+                .style(style().add_modifier(modifier))
+            });
+
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(11),
+                    Constraint::Length(area.width.saturating_sub(22)),
+                    Constraint::Length(9),
+                ],
+            )
+            .style(style().fg(THEME.text_normal()));
+
+            Widget::render(table, area, buf);
+        }
+    }
+}
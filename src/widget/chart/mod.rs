@@ -1,15 +1,133 @@
+use std::ops::Range;
+
+use ratatui::style::Color;
+use ratatui::text::Span;
+
+pub use self::oscillator::OscillatorChart;
 pub use self::prices_candlestick::PricesCandlestickChart;
 pub use self::prices_kagi::PricesKagiChart;
 pub use self::prices_line::PricesLineChart;
+pub use self::prices_pnf::PricesPointFigureChart;
+pub use self::prices_renko::PricesRenkoChart;
 pub use self::volume_bar::VolumeBarChart;
+use crate::common::TimeFrame;
+use crate::theme::style;
+use crate::THEME;
 
+mod oscillator;
 mod prices_candlestick;
 pub mod prices_kagi;
 mod prices_line;
+pub mod prices_pnf;
+pub mod prices_renko;
 mod volume_bar;
 
 const SCROLL_STEP: usize = 2;
 
+/// Indicator line drawn over the price chart, with a value per data point
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    pub color: Color,
+    pub values: Vec<Option<f64>>,
+}
+
+/// Widens the chart's `min` / `max` so overlays in the visible `range` aren't
+/// cut off
+fn overlay_min_max(
+    overlays: &[Overlay],
+    range: Range<usize>,
+    (min, max): (f64, f64),
+) -> (f64, f64) {
+    overlays
+        .iter()
+        .flat_map(|overlay| {
+            overlay
+                .values
+                .iter()
+                .skip(range.start)
+                .take(range.len())
+                .flatten()
+        })
+        .fold((min, max), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        })
+}
+
+/// X labels for charts that aren't plotted against time, evenly picked from
+/// the `dates` of each column drawn
+fn date_labels(width: u16, dates: &[i64], time_frame: TimeFrame) -> Vec<Span<'static>> {
+    let mut labels = vec![];
+
+    if dates.is_empty() {
+        return labels;
+    }
+
+    let label_len = dates
+        .first()
+        .map_or(0, |d| time_frame.format_time(*d).len())
+        + 5;
+
+    let num_labels = width as usize / label_len;
+
+    if num_labels == 0 {
+        return labels;
+    }
+
+    for i in 0..num_labels {
+        let idx = i * (dates.len() - 1) / (num_labels.max(2) - 1);
+
+        let timestamp = dates.get(idx).unwrap();
+
+        let label = Span::styled(
+            time_frame.format_time(*timestamp),
+            style().fg(THEME.text_normal()),
+        );
+
+        labels.push(label);
+    }
+
+    labels
+}
+
+/// Where prices are placed along the x-axis of a time based chart, so panels
+/// below it line up with the chart type being drawn
+#[derive(Debug, Clone, Copy)]
+pub struct XAxis {
+    pub bounds: [f64; 2],
+    /// Added to the index of a price to get its x value
+    pub offset: f64,
+}
+
+impl XAxis {
+    pub fn x(self, idx: usize) -> f64 {
+        idx as f64 + self.offset
+    }
+
+    /// Index of the price closest to `pct` of the way across the axis
+    pub fn idx(self, pct: f64) -> usize {
+        let x = self.bounds[0] + pct * (self.bounds[1] - self.bounds[0]);
+
+        (x - self.offset).round().max(0.0) as usize
+    }
+}
+
+/// Window of a time based chart that's zoomed into, in data points
+#[derive(Debug, Clone, Copy, Hash)]
+pub struct Zoom {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Zoom {
+    /// Range of the window, kept within `num_points`
+    pub fn range(self, num_points: usize) -> Range<usize> {
+        let len = self.len.min(num_points);
+        let start = self.start.min(num_points - len);
+
+        start..start + len
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, Hash)]
 pub struct ChartState {
     pub max_offset: Option<usize>,
@@ -0,0 +1,280 @@
+use std::ops::Range;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Color;
+use ratatui::symbols::Marker;
+use ratatui::text::Span;
+use ratatui::widgets::canvas::{Canvas, Line};
+use ratatui::widgets::{Block, Borders, StatefulWidget, Widget};
+
+use crate::common::{format_decimals, Oscillator, Price};
+use crate::draw::{add_padding, PaddingDirection};
+use crate::theme::style;
+use crate::widget::StockState;
+use crate::{indicator, THEME};
+
+const RSI_PERIOD: usize = 14;
+const MACD_FAST: usize = 12;
+const MACD_SLOW: usize = 26;
+const MACD_SIGNAL: usize = 9;
+const STOCHASTIC_PERIOD: usize = 14;
+const STOCHASTIC_SMOOTHING: usize = 3;
+
+/// Oscillator drawn in a panel below the price chart, sharing its x-axis
+pub struct OscillatorChart<'a> {
+    pub data: &'a [Price],
+    pub loaded: bool,
+    pub show_x_labels: bool,
+    pub oscillator: Oscillator,
+}
+
+/// Values of an oscillator ready to be drawn
+struct Series {
+    lines: Vec<(Color, Vec<Option<f64>>)>,
+    histogram: Vec<Option<f64>>,
+    /// Reference levels drawn across the panel, such as overbought / oversold
+    levels: Vec<f64>,
+    bounds: Option<[f64; 2]>,
+}
+
+impl Series {
+    fn new(oscillator: Oscillator, data: &[Price]) -> Series {
+        match oscillator {
+            Oscillator::Rsi => Series {
+                lines: vec![(
+                    THEME.oscillator_line(),
+                    indicator::on_closes(data, |closes| indicator::rsi(closes, RSI_PERIOD)),
+                )],
+                histogram: vec![],
+                levels: vec![30.0, 70.0],
+                bounds: Some([0.0, 100.0]),
+            },
+            Oscillator::Macd => {
+                let macd = indicator::on_closes(data, |closes| {
+                    indicator::macd(closes, MACD_FAST, MACD_SLOW, MACD_SIGNAL)
+                });
+
+                Series {
+                    lines: vec![
+                        (
+                            THEME.oscillator_line(),
+                            macd.iter().map(|m| m.map(|(line, _, _)| line)).collect(),
+                        ),
+                        (
+                            THEME.oscillator_signal(),
+                            macd.iter()
+                                .map(|m| m.map(|(_, signal, _)| signal))
+                                .collect(),
+                        ),
+                    ],
+                    histogram: macd.iter().map(|m| m.map(|(_, _, hist)| hist)).collect(),
+                    levels: vec![0.0],
+                    bounds: None,
+                }
+            }
+            Oscillator::Stochastic => {
+                let stochastic = indicator::on_prices(data, |prices| {
+                    indicator::stochastic(prices, STOCHASTIC_PERIOD, STOCHASTIC_SMOOTHING)
+                });
+
+                Series {
+                    lines: vec![
+                        (
+                            THEME.oscillator_line(),
+                            stochastic.iter().map(|s| s.map(|(k, _)| k)).collect(),
+                        ),
+                        (
+                            THEME.oscillator_signal(),
+                            stochastic.iter().map(|s| s.map(|(_, d)| d)).collect(),
+                        ),
+                    ],
+                    histogram: vec![],
+                    levels: vec![20.0, 80.0],
+                    bounds: Some([0.0, 100.0]),
+                }
+            }
+        }
+    }
+
+    /// Fixed bounds for oscillators with a fixed range, otherwise the range of
+    /// the values drawn within `range`
+    fn y_bounds(&self, range: Range<usize>) -> [f64; 2] {
+        if let Some(bounds) = self.bounds {
+            return bounds;
+        }
+
+        let (min, max) = self
+            .lines
+            .iter()
+            .map(|(_, values)| values)
+            .chain([&self.histogram])
+            .flat_map(|values| values.iter().skip(range.start).take(range.len()).flatten())
+            .fold((0.0_f64, 0.0_f64), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+
+        if min == max {
+            [min - 1.0, max + 1.0]
+        } else {
+            [min, max]
+        }
+    }
+}
+
+fn title(oscillator: Oscillator) -> String {
+    match oscillator {
+        Oscillator::Rsi => format!(" RSI({}) ", RSI_PERIOD),
+        Oscillator::Macd => format!(" MACD({},{},{}) ", MACD_FAST, MACD_SLOW, MACD_SIGNAL),
+        Oscillator::Stochastic => format!(
+            " Stochastic({},{}) ",
+            STOCHASTIC_PERIOD, STOCHASTIC_SMOOTHING
+        ),
+    }
+}
+
+impl StatefulWidget for OscillatorChart<'_> {
+    type State = StockState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if area.width <= 9 || area.height <= 3 {
+            return;
+        }
+
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(style().fg(THEME.border_secondary()))
+            .title(Span::styled(
+                title(self.oscillator),
+                style().fg(THEME.text_normal()),
+            ))
+            .render(area, buf);
+        area = add_padding(area, 1, PaddingDirection::Top);
+
+        if !self.loaded {
+            return;
+        }
+
+        let series = Series::new(self.oscillator, self.data);
+
+        let x_axis = state.x_axis(self.data);
+        let x_bounds = x_axis.bounds;
+        let range = state.visible_range(self.data);
+        let y_bounds = series.y_bounds(range.clone());
+
+        // layout[0] - Y lables
+        // layout[1] - chart
+        let mut layout: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(if self.show_x_labels {
+                    state.time_frame.y_label_width()
+                } else {
+                    9
+                }),
+                Constraint::Min(0),
+            ])
+            .split(area)
+            .to_vec();
+
+        // Fix for border render
+        layout[1].x = layout[1].x.saturating_sub(1);
+        layout[1].width += 1;
+
+        // Draw y labels at the reference levels, or the range of an unbounded
+        // oscillator
+        {
+            let y_area = layout[0];
+
+            let labels = if series.bounds.is_some() {
+                series
+                    .levels
+                    .iter()
+                    .map(|level| (*level, format!("{:>8}", level)))
+                    .collect::<Vec<_>>()
+            } else {
+                y_bounds
+                    .iter()
+                    .map(|value| (*value, format!("{:>8}", format_decimals(*value))))
+                    .collect::<Vec<_>>()
+            };
+
+            for (value, label) in labels {
+                let pct = (value - y_bounds[0]) / (y_bounds[1] - y_bounds[0]);
+                let dy = (pct * (y_area.height - 1) as f64).round() as u16;
+
+                if dy < y_area.height {
+                    buf.set_span(
+                        y_area.left(),
+                        y_area.bottom() - 1 - dy,
+                        &Span::styled(label, style().fg(THEME.text_normal())),
+                        y_area.width,
+                    );
+                }
+            }
+        }
+
+        Canvas::default()
+            .background_color(THEME.background())
+            .block(
+                Block::default()
+                    .style(style())
+                    .borders(Borders::LEFT)
+                    .border_style(style().fg(THEME.border_axis())),
+            )
+            .marker(Marker::Braille)
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
+            .paint(move |ctx| {
+                for level in series.levels.iter() {
+                    ctx.draw(&Line {
+                        x1: x_bounds[0],
+                        y1: *level,
+                        x2: x_bounds[1],
+                        y2: *level,
+                        color: THEME.gray(),
+                    });
+                }
+
+                ctx.layer();
+
+                for (idx, value) in series.histogram.iter().enumerate() {
+                    if let (true, Some(value)) = (range.contains(&idx), value) {
+                        ctx.draw(&Line {
+                            x1: x_axis.x(idx),
+                            y1: 0.0,
+                            x2: x_axis.x(idx),
+                            y2: *value,
+                            color: if *value >= 0.0 {
+                                THEME.profit()
+                            } else {
+                                THEME.loss()
+                            },
+                        });
+                    }
+                }
+
+                ctx.layer();
+
+                for (color, values) in series.lines.iter() {
+                    let points = values
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| range.contains(idx))
+                        .filter_map(|(idx, value)| value.map(|value| (x_axis.x(idx), value)))
+                        .collect::<Vec<_>>();
+
+                    for window in points.windows(2) {
+                        ctx.draw(&Line {
+                            x1: window[0].0,
+                            y1: window[0].1,
+                            x2: window[1].0,
+                            y2: window[1].1,
+                            color: *color,
+                        });
+                    }
+                }
+            })
+            .render(layout[1], buf);
+    }
+}
@@ -5,13 +5,14 @@ use ratatui::text::Span;
 use ratatui::widgets::canvas::{Canvas, Line, Rectangle};
 use ratatui::widgets::{Block, Borders, StatefulWidget, Widget};
 
+use super::{overlay_min_max, Overlay};
 use crate::common::{Price, TimeFrame};
 use crate::draw::{add_padding, PaddingDirection};
 use crate::theme::style;
 use crate::widget::StockState;
 use crate::{HIDE_PREV_CLOSE, THEME};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct Candle {
     open: f64,
     close: f64,
@@ -24,6 +25,13 @@ pub struct PricesCandlestickChart<'a> {
     pub data: &'a [Price],
     pub is_summary: bool,
     pub show_x_labels: bool,
+    /// Draw Heikin-Ashi candles, which average each candle with the last to
+    /// smooth out the trend
+    pub heikin_ashi: bool,
+    /// Draw OHLC bars, with the open ticked to the left and close to the right,
+    /// instead of candle bodies
+    pub ohlc: bool,
+    pub overlays: &'a [Overlay],
 }
 
 impl StatefulWidget for PricesCandlestickChart<'_> {
@@ -42,18 +50,35 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
             area = add_padding(area, 1, PaddingDirection::Top);
         }
 
+        let end_price = state.end_price(self.data);
+
         let mut data = self.data.to_vec();
         data.push(Price {
-            close: state.current_price(),
-            open: state.current_price(),
-            high: state.current_price(),
-            low: state.current_price(),
+            close: end_price,
+            open: end_price,
+            high: end_price,
+            low: end_price,
             ..Default::default()
         });
 
-        let (min, max) = state.min_max(&data);
+        let (min, max) = overlay_min_max(
+            self.overlays,
+            state.visible_range(self.data),
+            state.visible_min_max(self.data),
+        );
         let (start, end) = state.start_end();
-        let x_bounds = state.x_bounds(start, end, &data);
+        let x_axis = state.x_axis(self.data);
+        // Points spanned by the chart
+        let num_points = x_axis.bounds[1] - x_axis.bounds[0];
+
+        // Prices in view, along with the current price when zoomed in on the
+        // latest ones
+        let range = state.visible_range(self.data);
+        let visible = if range.end >= self.data.len() {
+            &data[range.start.min(self.data.len())..]
+        } else {
+            &data[range.clone()]
+        };
 
         // x_layout[0] - chart + y labels
         // x_layout[1] - (x labels)
@@ -74,11 +99,7 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
                 Constraint::Length(if !self.loaded {
                     8
                 } else if self.show_x_labels {
-                    match state.time_frame {
-                        TimeFrame::Day1 => 9,
-                        TimeFrame::Week1 => 12,
-                        _ => 11,
-                    }
+                    state.time_frame.y_label_width()
                 } else {
                     9
                 }),
@@ -91,6 +112,15 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
         layout[1].x = layout[1].x.saturating_sub(1);
         layout[1].width += 1;
 
+        if !self.is_summary {
+            state.chart_area = Some(Rect {
+                x: layout[1].x + 1,
+                width: layout[1].width - 1,
+                height: layout[1].height - u16::from(self.show_x_labels),
+                ..layout[1]
+            });
+        }
+
         // Draw x labels
         if self.show_x_labels && self.loaded {
             // Fix for y label render
@@ -138,10 +168,10 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
         let width = layout[1].width - 1;
         let num_candles = width / 2;
 
-        let candles = data
+        let candles = visible
             .iter()
             .flat_map(|p| vec![*p; num_candles as usize])
-            .chunks(x_bounds[1] as usize)
+            .chunks(num_points as usize)
             .into_iter()
             .map(|c| {
                 let prices = c.filter(|p| p.close.gt(&0.0)).collect::<Vec<_>>();
@@ -172,6 +202,16 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
             })
             .collect::<Vec<_>>();
 
+        let candles = if self.heikin_ashi {
+            heikin_ashi(candles)
+        } else {
+            candles
+        };
+
+        let cursor = state
+            .cursor_idx(&data)
+            .filter(|idx| !self.is_summary && range.contains(idx));
+
         if self.loaded {
             Canvas::default()
                 .background_color(THEME.background())
@@ -188,20 +228,38 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
                 .x_bounds([0.0, num_candles as f64 * 4.0])
                 .y_bounds(state.y_bounds(min, max))
                 .paint(move |ctx| {
-                    if state.time_frame == TimeFrame::Day1
-                        && self.loaded
-                        && !*HIDE_PREV_CLOSE
-                        && state.prev_close_price.is_some()
-                    {
+                    if let (TimeFrame::Day1, true, false, Some(prev_close)) = (
+                        state.time_frame,
+                        self.loaded,
+                        *HIDE_PREV_CLOSE,
+                        state.prev_close_price,
+                    ) {
                         ctx.draw(&Line {
                             x1: 0.0,
                             x2: num_candles as f64 * 4.0,
-                            y1: state.prev_close_price.unwrap(),
-                            y2: state.prev_close_price.unwrap(),
+                            y1: prev_close,
+                            y2: prev_close,
                             color: THEME.gray(),
                         })
                     }
 
+                    // Each price in view is spread over `num_candles` slots,
+                    // which are then chunked into candles `num_points` slots
+                    // wide
+                    let x = |idx: usize| {
+                        (x_axis.x(idx) - x_axis.bounds[0]) * num_candles as f64 / num_points * 4.0
+                    };
+
+                    if let Some(idx) = cursor {
+                        ctx.draw(&Line {
+                            x1: x(idx),
+                            x2: x(idx),
+                            y1: min,
+                            y2: max,
+                            color: THEME.text_normal(),
+                        });
+                    }
+
                     ctx.layer();
 
                     for (idx, candle) in candles.iter().enumerate() {
@@ -212,6 +270,34 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
                                 THEME.loss()
                             };
 
+                            if self.ohlc {
+                                ctx.draw(&Line {
+                                    x1: idx as f64 * 4.0 + 2.0,
+                                    x2: idx as f64 * 4.0 + 2.0,
+                                    y1: candle.low,
+                                    y2: candle.high,
+                                    color,
+                                });
+
+                                ctx.draw(&Line {
+                                    x1: idx as f64 * 4.0 + 1.0,
+                                    x2: idx as f64 * 4.0 + 2.0,
+                                    y1: candle.open,
+                                    y2: candle.open,
+                                    color,
+                                });
+
+                                ctx.draw(&Line {
+                                    x1: idx as f64 * 4.0 + 2.0,
+                                    x2: idx as f64 * 4.0 + 3.0,
+                                    y1: candle.close,
+                                    y2: candle.close,
+                                    color,
+                                });
+
+                                continue;
+                            }
+
                             ctx.draw(&Rectangle {
                                 x: idx as f64 * 4.0 + 1.0,
                                 y: candle.open.min(candle.close),
@@ -238,6 +324,29 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
                             });
                         }
                     }
+
+                    ctx.layer();
+
+                    for overlay in self.overlays {
+                        for ((idx, a), b) in overlay
+                            .values
+                            .iter()
+                            .enumerate()
+                            .zip(overlay.values.iter().skip(1))
+                            .skip(range.start)
+                            .take(range.len().saturating_sub(1))
+                        {
+                            if let (Some(a), Some(b)) = (a, b) {
+                                ctx.draw(&Line {
+                                    x1: x(idx),
+                                    x2: x(idx + 1),
+                                    y1: *a,
+                                    y2: *b,
+                                    color: overlay.color,
+                                });
+                            }
+                        }
+                    }
                 })
                 .render(layout[1], buf);
         } else {
@@ -252,3 +361,80 @@ impl StatefulWidget for PricesCandlestickChart<'_> {
         }
     }
 }
+
+fn heikin_ashi(candles: Vec<Option<Candle>>) -> Vec<Option<Candle>> {
+    let mut prev: Option<Candle> = None;
+
+    candles
+        .into_iter()
+        .map(|candle| {
+            let candle = candle?;
+
+            let close = (candle.open + candle.high + candle.low + candle.close) / 4.0;
+            let open = match prev {
+                Some(prev) => (prev.open + prev.close) / 2.0,
+                None => (candle.open + candle.close) / 2.0,
+            };
+
+            let ha_candle = Candle {
+                open,
+                close,
+                high: candle.high.max(open).max(close),
+                low: candle.low.min(open).min(close),
+            };
+
+            prev = Some(ha_candle);
+
+            Some(ha_candle)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(open: f64, high: f64, low: f64, close: f64) -> Option<Candle> {
+        Some(Candle {
+            open,
+            close,
+            high,
+            low,
+        })
+    }
+
+    #[test]
+    fn test_heikin_ashi() {
+        let candles = vec![
+            candle(10.0, 12.0, 9.0, 11.0),
+            None,
+            candle(11.0, 13.0, 10.0, 12.0),
+            candle(12.0, 12.0, 11.5, 11.5),
+        ];
+
+        let results = heikin_ashi(candles);
+
+        assert_eq!(results.len(), 4);
+        assert!(results[1].is_none());
+
+        // First open is the midpoint of its own open / close, later ones the
+        // midpoint of the previous HA candle, carried across the gap. The last
+        // low comes from the HA open
+        let expected = [
+            (0, 10.5, 12.0, 9.0, 10.5),
+            (2, 10.5, 13.0, 10.0, 11.5),
+            (3, 11.0, 12.0, 11.0, 11.75),
+        ];
+
+        for (idx, open, high, low, close) in expected {
+            let actual = results[idx].unwrap();
+
+            assert_eq!(
+                (actual.open, actual.high, actual.low, actual.close),
+                (open, high, low, close),
+                "candle {}",
+                idx
+            );
+        }
+    }
+}
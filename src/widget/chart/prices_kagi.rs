@@ -7,10 +7,11 @@ use ratatui::widgets::canvas::{Canvas, Line};
 use ratatui::widgets::{Block, Borders, StatefulWidget, Widget};
 use serde::Deserialize;
 
+use super::date_labels;
 use crate::common::{Price, TimeFrame};
 use crate::draw::{add_padding, PaddingDirection};
 use crate::theme::style;
-use crate::widget::chart_configuration::{KagiOptions, KagiReversalOption};
+use crate::widget::chart_configuration::{default_kagi_reversal_amount, KagiOptions};
 use crate::widget::StockState;
 use crate::{HIDE_PREV_CLOSE, THEME};
 
@@ -310,23 +311,14 @@ impl StatefulWidget for PricesKagiChart<'_> {
             return;
         }
 
-        let default_reversal_option = match state.time_frame {
-            TimeFrame::Day1 => ReversalOption::Pct(0.01),
-            _ => ReversalOption::Pct(0.04),
-        };
-
         let reversal_option = self
             .kagi_options
             .reversal_option
             .as_ref()
-            .map(|o| match o {
-                KagiReversalOption::Single(option) => *option,
-                KagiReversalOption::ByTimeFrame(options_by_timeframe) => options_by_timeframe
-                    .get(&state.time_frame)
-                    .copied()
-                    .unwrap_or(default_reversal_option),
-            })
-            .unwrap_or(default_reversal_option);
+            .and_then(|o| o.get(state.time_frame))
+            .unwrap_or(ReversalOption::Pct(default_kagi_reversal_amount(
+                state.time_frame,
+            )));
 
         let price_option = self.kagi_options.price_option.unwrap_or(PriceOption::Close);
 
@@ -359,11 +351,7 @@ impl StatefulWidget for PricesKagiChart<'_> {
                 Constraint::Length(if !self.loaded {
                     8
                 } else if self.show_x_labels {
-                    match state.time_frame {
-                        TimeFrame::Day1 => 9,
-                        TimeFrame::Week1 => 12,
-                        _ => 11,
-                    }
+                    state.time_frame.y_label_width()
                 } else {
                     9
                 }),
@@ -471,16 +459,17 @@ impl StatefulWidget for PricesKagiChart<'_> {
                 .x_bounds([0.0, chart_width])
                 .y_bounds(state.y_bounds(min, max))
                 .paint(move |ctx| {
-                    if state.time_frame == TimeFrame::Day1
-                        && self.loaded
-                        && !*HIDE_PREV_CLOSE
-                        && state.prev_close_price.is_some()
-                    {
+                    if let (TimeFrame::Day1, true, false, Some(prev_close)) = (
+                        state.time_frame,
+                        self.loaded,
+                        *HIDE_PREV_CLOSE,
+                        state.prev_close_price,
+                    ) {
                         ctx.draw(&Line {
                             x1: 0.0,
                             x2: chart_width,
-                            y1: state.prev_close_price.unwrap(),
-                            y2: state.prev_close_price.unwrap(),
+                            y1: prev_close,
+                            y2: prev_close,
                             color: THEME.gray(),
                         });
                     }
@@ -590,41 +579,11 @@ impl StatefulWidget for PricesKagiChart<'_> {
     }
 }
 
-fn x_labels(width: u16, trends: &[Trend], time_frame: TimeFrame) -> Vec<Span> {
-    let mut labels = vec![];
-
-    let trends = trends
+fn x_labels(width: u16, trends: &[Trend], time_frame: TimeFrame) -> Vec<Span<'static>> {
+    let dates = trends
         .iter()
         .map(|t| t.first_price.date)
         .collect::<Vec<_>>();
 
-    if trends.is_empty() {
-        return labels;
-    }
-
-    let label_len = trends
-        .first()
-        .map_or(0, |d| time_frame.format_time(*d).len())
-        + 5;
-
-    let num_labels = width as usize / label_len;
-
-    if num_labels == 0 {
-        return labels;
-    }
-
-    for i in 0..num_labels {
-        let idx = i * (trends.len() - 1) / (num_labels.max(2) - 1);
-
-        let timestamp = trends.get(idx).unwrap();
-
-        let label = Span::styled(
-            time_frame.format_time(*timestamp),
-            style().fg(THEME.text_normal()),
-        );
-
-        labels.push(label);
-    }
-
-    labels
+    date_labels(width, &dates, time_frame)
 }
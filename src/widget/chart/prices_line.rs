@@ -2,8 +2,10 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::symbols::Marker;
 use ratatui::text::Span;
+use ratatui::widgets::canvas::{Canvas, Line};
 use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, StatefulWidget, Widget};
 
+use super::{overlay_min_max, Overlay};
 use crate::common::{
     cast_as_dataset, cast_historical_as_price, zeros_as_pre, Price, TimeFrame, TradingPeriod,
 };
@@ -18,7 +20,11 @@ pub struct PricesLineChart<'a> {
     pub show_x_labels: bool,
     pub is_profit: bool,
     pub is_summary: bool,
+    /// Fill between the price and the previous close, colored by whether the
+    /// price is above or below it
+    pub area: bool,
     pub data: &'a [Price],
+    pub overlays: &'a [Overlay],
 }
 
 impl StatefulWidget for PricesLineChart<'_> {
@@ -37,13 +43,19 @@ impl StatefulWidget for PricesLineChart<'_> {
             area = add_padding(area, 1, PaddingDirection::Top);
         }
 
-        let (min, max) = state.min_max(self.data);
+        let (min, max) = overlay_min_max(
+            self.overlays,
+            state.visible_range(self.data),
+            state.visible_min_max(self.data),
+        );
         let (start, end) = state.start_end();
+        let x_bounds = state.x_bounds(start, end, self.data);
+        let y_bounds = state.y_bounds(min, max);
 
         let mut prices: Vec<_> = self.data.iter().map(cast_historical_as_price).collect();
 
         prices.pop();
-        prices.push(state.current_price());
+        prices.push(state.end_price(self.data));
         zeros_as_pre(&mut prices);
 
         // Need more than one price for GraphType::Line to work
@@ -141,6 +153,16 @@ impl StatefulWidget for PricesLineChart<'_> {
             None
         };
 
+        let cursor_line = state
+            .cursor_idx(self.data)
+            .filter(|_| self.loaded && !self.is_summary)
+            .map(|idx| {
+                vec![
+                    ((idx + 1) as f64, y_bounds[0]),
+                    ((idx + 1) as f64, y_bounds[1]),
+                ]
+            });
+
         let mut datasets = vec![Dataset::default()
             .marker(Marker::Braille)
             .style(style().fg(
@@ -188,6 +210,40 @@ impl StatefulWidget for PricesLineChart<'_> {
             );
         }
 
+        let overlays = if self.loaded {
+            self.overlays
+                .iter()
+                .map(|overlay| {
+                    let points = overlay
+                        .values
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, value)| value.map(|value| ((idx + 1) as f64, value)))
+                        .collect::<Vec<_>>();
+
+                    (overlay.color, points)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        // Drawn beneath the price line
+        for (color, points) in overlays.iter().rev() {
+            datasets.insert(
+                0,
+                Dataset::default()
+                    .marker(Marker::Braille)
+                    .style(style().fg(*color))
+                    .graph_type(if points.len() <= 1 {
+                        GraphType::Scatter
+                    } else {
+                        GraphType::Line
+                    })
+                    .data(points),
+            );
+        }
+
         if let Some(data) = prev_close_line.as_ref() {
             datasets.insert(
                 0,
@@ -199,6 +255,17 @@ impl StatefulWidget for PricesLineChart<'_> {
             );
         }
 
+        if let Some(data) = cursor_line.as_ref() {
+            datasets.insert(
+                0,
+                Dataset::default()
+                    .marker(Marker::Braille)
+                    .style(style().fg(THEME.text_normal()))
+                    .graph_type(GraphType::Line)
+                    .data(data),
+            );
+        }
+
         let chart = Chart::new(datasets)
             .block(
                 Block::default()
@@ -211,8 +278,8 @@ impl StatefulWidget for PricesLineChart<'_> {
                     .border_style(style().fg(THEME.border_axis())),
             )
             .style(style())
-            .x_axis(Axis::default().bounds(state.x_bounds(start, end, self.data)))
-            .y_axis(Axis::default().bounds(state.y_bounds(min, max)));
+            .x_axis(Axis::default().bounds(x_bounds))
+            .y_axis(Axis::default().bounds(y_bounds));
 
         // x_layout[0] - chart + y labels
         // x_layout[1] - (x labels)
@@ -233,11 +300,7 @@ impl StatefulWidget for PricesLineChart<'_> {
                 Constraint::Length(if !self.loaded {
                     8
                 } else if self.show_x_labels {
-                    match state.time_frame {
-                        TimeFrame::Day1 => 9,
-                        TimeFrame::Week1 => 12,
-                        _ => 11,
-                    }
+                    state.time_frame.y_label_width()
                 } else {
                     9
                 }),
@@ -250,6 +313,15 @@ impl StatefulWidget for PricesLineChart<'_> {
         layout[1].x = layout[1].x.saturating_sub(1);
         layout[1].width += 1;
 
+        if !self.is_summary {
+            state.chart_area = Some(Rect {
+                x: layout[1].x + 1,
+                width: layout[1].width - 1,
+                height: layout[1].height - u16::from(self.show_x_labels),
+                ..layout[1]
+            });
+        }
+
         // Draw x labels
         if self.show_x_labels && self.loaded {
             // Fix for y label render
@@ -294,6 +366,73 @@ impl StatefulWidget for PricesLineChart<'_> {
             }
         }
 
+        // Drawn beneath the chart, which only paints over the cells its lines
+        // pass through
+        if self.area && self.loaded {
+            if let Some(baseline) = state.fill_baseline(self.data) {
+                let range = state.visible_range(self.data);
+
+                // Lines with an end out of bounds aren't drawn
+                let baseline = baseline.clamp(y_bounds[0], y_bounds[1]);
+
+                // Width of a braille dot, so every column under the line is filled
+                let step = (x_bounds[1] - x_bounds[0]) / (layout[1].width.max(2) - 1) as f64 / 2.0;
+
+                Canvas::default()
+                    .background_color(THEME.background())
+                    .block(
+                        Block::default()
+                            .style(style())
+                            .borders(if self.show_x_labels {
+                                Borders::LEFT | Borders::BOTTOM
+                            } else {
+                                Borders::LEFT
+                            })
+                            .border_style(style().fg(THEME.border_axis())),
+                    )
+                    .marker(Marker::Braille)
+                    .x_bounds(x_bounds)
+                    .y_bounds(y_bounds)
+                    .paint(|ctx| {
+                        // Includes the segments leading into and out of view
+                        let segments = prices
+                            .windows(2)
+                            .enumerate()
+                            .skip(range.start.saturating_sub(1))
+                            .take(range.len() + 1);
+
+                        for (idx, pair) in segments {
+                            let x1 = (idx + 1) as f64;
+
+                            // Zoomed out of view
+                            if x1 + 1.0 < x_bounds[0] || x1 > x_bounds[1] {
+                                continue;
+                            }
+
+                            let mut x = x1;
+                            while x < x1 + 1.0 {
+                                let y = pair[0] + (pair[1] - pair[0]) * (x - x1);
+
+                                ctx.draw(&Line {
+                                    x1: x,
+                                    y1: baseline,
+                                    x2: x,
+                                    y2: y,
+                                    color: if y >= baseline {
+                                        THEME.profit()
+                                    } else {
+                                        THEME.loss()
+                                    },
+                                });
+
+                                x += step;
+                            }
+                        }
+                    })
+                    .render(layout[1], buf);
+            }
+        }
+
         chart.render(layout[1], buf);
     }
 }
@@ -0,0 +1,446 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::Span;
+use ratatui::widgets::canvas::{Canvas, Line, Rectangle};
+use ratatui::widgets::{Block, Borders, StatefulWidget, Widget};
+
+use super::date_labels;
+use super::prices_kagi::PriceOption;
+use crate::common::{Price, TimeFrame};
+use crate::draw::{add_padding, PaddingDirection};
+use crate::theme::style;
+use crate::widget::chart_configuration::PointFigureOptions;
+use crate::widget::StockState;
+use crate::{HIDE_PREV_CLOSE, THEME};
+
+/// Boxes the price has to move against the current column to start a new one
+pub const DEFAULT_REVERSAL: usize = 3;
+
+/// Stops a box size that's tiny compared to the price range from drawing an
+/// endless number of boxes
+const MAX_BOXES: i64 = 10_000;
+
+/// Column of X's (rising) or O's (falling), spanning the boxes from `low` to
+/// `high`, in multiples of the box size. Boxes are marked by the level at their
+/// top, so an O column's last box is the one below the level the price fell to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Column {
+    up: bool,
+    low: i64,
+    high: i64,
+    date: i64,
+}
+
+fn calculate_columns(
+    data: &[Price],
+    box_size: f64,
+    reversal: usize,
+    price_option: PriceOption,
+) -> Vec<Column> {
+    let mut columns: Vec<Column> = vec![];
+
+    let data = data.iter().filter(|p| p.close.gt(&0.0)).collect::<Vec<_>>();
+
+    let Some(first) = data.first() else {
+        return columns;
+    };
+
+    let (high, low) = data.iter().fold((f64::MIN, f64::MAX), |(high, low), p| {
+        (high.max(p.high.max(p.close)), low.min(p.low.min(p.close)))
+    });
+
+    if (high - low) / box_size > MAX_BOXES as f64 {
+        return columns;
+    }
+
+    let reversal = reversal as i64;
+    let anchor = (first.close / box_size).floor() as i64;
+
+    let mut column: Option<Column> = None;
+
+    for price in data.iter() {
+        let (price_high, price_low) = match price_option {
+            PriceOption::Close => (price.close, price.close),
+            PriceOption::HighLow => (price.high, price.low),
+        };
+
+        let high = (price_high / box_size).floor() as i64;
+        let low = (price_low / box_size).ceil() as i64;
+
+        column = match column {
+            None if high > anchor => Some(Column {
+                up: true,
+                low: anchor,
+                high,
+                date: price.date,
+            }),
+            None if low < anchor => Some(Column {
+                up: false,
+                low: low - 1,
+                high: anchor - 1,
+                date: price.date,
+            }),
+            None => None,
+            // Extending the current column takes priority over a reversal, which
+            // starts the new column a box away from the end of the current one
+            Some(mut current) if current.up => {
+                if high > current.high {
+                    current.high = high;
+                } else if low <= current.high - reversal {
+                    columns.push(current);
+
+                    current = Column {
+                        up: false,
+                        low: low - 1,
+                        high: current.high - 1,
+                        date: price.date,
+                    };
+                }
+
+                Some(current)
+            }
+            Some(mut current) => {
+                if low - 1 < current.low {
+                    current.low = low - 1;
+                } else if high >= current.low + 1 + reversal {
+                    columns.push(current);
+
+                    current = Column {
+                        up: true,
+                        low: current.low + 1,
+                        high,
+                        date: price.date,
+                    };
+                }
+
+                Some(current)
+            }
+        };
+    }
+
+    columns.extend(column);
+
+    columns
+}
+
+pub struct PricesPointFigureChart<'a> {
+    pub loaded: bool,
+    pub data: &'a [Price],
+    pub is_summary: bool,
+    pub show_x_labels: bool,
+    pub pnf_options: PointFigureOptions,
+}
+
+impl StatefulWidget for PricesPointFigureChart<'_> {
+    type State = StockState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if area.width <= 9 || area.height <= 3 {
+            return;
+        }
+
+        let box_size_option = self
+            .pnf_options
+            .box_size_option
+            .as_ref()
+            .and_then(|o| o.get(state.time_frame))
+            .unwrap_or_default();
+        let reversal = self.pnf_options.reversal.unwrap_or(DEFAULT_REVERSAL).max(1);
+        let price_option = self.pnf_options.price_option.unwrap_or(PriceOption::Close);
+
+        let box_size = box_size_option.resolve(self.data).unwrap_or_default();
+        let columns = if box_size > 0.0 {
+            calculate_columns(self.data, box_size, reversal, price_option)
+        } else {
+            vec![]
+        };
+
+        if !self.is_summary {
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(style().fg(THEME.border_secondary()))
+                .render(area, buf);
+            area = add_padding(area, 1, PaddingDirection::Top);
+        }
+
+        // x_layout[0] - chart + y labels
+        // x_layout[1] - (x labels)
+        let x_layout: Vec<Rect> = Layout::default()
+            .constraints(if self.show_x_labels {
+                &[Constraint::Min(0), Constraint::Length(1)][..]
+            } else {
+                &[Constraint::Min(0)][..]
+            })
+            .split(area)
+            .to_vec();
+
+        // layout[0] - Y lables
+        // layout[1] - chart
+        let mut layout: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(if !self.loaded {
+                    8
+                } else if self.show_x_labels {
+                    state.time_frame.y_label_width()
+                } else {
+                    9
+                }),
+                Constraint::Min(0),
+            ])
+            .split(x_layout[0])
+            .to_vec();
+
+        // Fix for border render
+        layout[1].x = layout[1].x.saturating_sub(1);
+        layout[1].width += 1;
+
+        let width = layout[1].width - 1;
+        let num_columns_can_render = (width / 2) as usize;
+        let max_offset = columns.len().saturating_sub(num_columns_can_render);
+
+        let chart_width = num_columns_can_render as f64 * 4.0;
+
+        let offset = if self.is_summary {
+            max_offset
+        } else if let Some(chart_state) = state.chart_state_mut() {
+            if let Some(direction) = chart_state.queued_scroll.take() {
+                chart_state.scroll(direction, max_offset);
+            }
+
+            chart_state.offset(max_offset)
+        } else {
+            max_offset
+        };
+
+        let columns = &columns[offset..(offset + num_columns_can_render).min(columns.len())];
+
+        let (min, max) = min_max(columns, box_size, state.time_frame, state.prev_close_price);
+
+        // Draw x labels
+        if self.show_x_labels && self.loaded {
+            // Plot labels on
+            let mut x_area = x_layout[1];
+            x_area.x = layout[1].x + 1;
+            x_area.width = layout[1].width - 1;
+
+            // Fix for y label render
+            layout[0] = add_padding(layout[0], 1, PaddingDirection::Bottom);
+
+            let dates = columns.iter().map(|c| c.date).collect::<Vec<_>>();
+
+            let labels = date_labels(x_area.width + x_area.left(), &dates, state.time_frame);
+            let total_width = labels.iter().map(Span::width).sum::<usize>() as u16;
+            let labels_len = labels.len() as u16;
+            if total_width <= (x_area.width + x_area.x) && labels_len >= 1 {
+                for (i, label) in labels.iter().enumerate() {
+                    buf.set_span(
+                        x_area.left() + i as u16 * (x_area.width - 1) / (labels_len.max(2) - 1)
+                            - label.width() as u16,
+                        x_area.top(),
+                        label,
+                        label.width() as u16,
+                    );
+                }
+            }
+        }
+
+        // Draw y labels
+        if self.loaded {
+            let y_area = layout[0];
+
+            let labels = state.y_labels(min, max);
+            let labels_len = labels.len() as u16;
+            for (i, label) in labels.iter().enumerate() {
+                let dy = i as u16 * (y_area.height - 1) / (labels_len - 1);
+                if dy < y_area.bottom() {
+                    buf.set_span(
+                        y_area.left(),
+                        y_area.bottom() - 1 - dy,
+                        label,
+                        label.width() as u16,
+                    );
+                }
+            }
+        }
+
+        if self.loaded {
+            Canvas::default()
+                .background_color(THEME.background())
+                .block(
+                    Block::default()
+                        .style(style())
+                        .borders(if self.show_x_labels {
+                            Borders::LEFT | Borders::BOTTOM
+                        } else {
+                            Borders::LEFT
+                        })
+                        .border_style(style().fg(THEME.border_axis())),
+                )
+                .x_bounds([0.0, chart_width])
+                .y_bounds(state.y_bounds(min, max))
+                .paint(move |ctx| {
+                    if let (TimeFrame::Day1, true, false, Some(prev_close)) = (
+                        state.time_frame,
+                        self.loaded,
+                        *HIDE_PREV_CLOSE,
+                        state.prev_close_price,
+                    ) {
+                        ctx.draw(&Line {
+                            x1: 0.0,
+                            x2: chart_width,
+                            y1: prev_close,
+                            y2: prev_close,
+                            color: THEME.gray(),
+                        });
+                    }
+
+                    ctx.layer();
+
+                    for (idx, column) in columns.iter().enumerate() {
+                        let x1 = idx as f64 * 4.0 + 1.0;
+                        let x2 = idx as f64 * 4.0 + 3.0;
+
+                        for level in column.low..column.high {
+                            let y1 = level as f64 * box_size;
+                            let y2 = (level + 1) as f64 * box_size;
+
+                            if column.up {
+                                ctx.draw(&Line {
+                                    x1,
+                                    x2,
+                                    y1,
+                                    y2,
+                                    color: THEME.profit(),
+                                });
+                                ctx.draw(&Line {
+                                    x1,
+                                    x2,
+                                    y1: y2,
+                                    y2: y1,
+                                    color: THEME.profit(),
+                                });
+                            } else {
+                                ctx.draw(&Rectangle {
+                                    x: x1,
+                                    y: y1,
+                                    width: x2 - x1,
+                                    height: y2 - y1,
+                                    color: THEME.loss(),
+                                });
+                            }
+                        }
+                    }
+                })
+                .render(layout[1], buf);
+        } else {
+            Block::default()
+                .borders(if self.show_x_labels {
+                    Borders::LEFT | Borders::BOTTOM
+                } else {
+                    Borders::LEFT
+                })
+                .border_style(style().fg(THEME.border_axis()))
+                .render(layout[1], buf);
+        }
+    }
+}
+
+fn min_max(
+    columns: &[Column],
+    box_size: f64,
+    time_frame: TimeFrame,
+    prev_close_price: Option<f64>,
+) -> (f64, f64) {
+    let mut min = columns
+        .iter()
+        .map(|c| c.low as f64 * box_size)
+        .min_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap_or(0.0);
+    let mut max = columns
+        .iter()
+        .map(|c| c.high as f64 * box_size)
+        .max_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap_or(1.0);
+
+    if time_frame == TimeFrame::Day1 && !*HIDE_PREV_CLOSE {
+        if let Some(prev_close) = prev_close_price {
+            min = min.min(prev_close);
+            max = max.max(prev_close);
+        }
+    }
+
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices(closes: &[f64]) -> Vec<Price> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(idx, close)| Price {
+                close: *close,
+                high: *close,
+                low: *close,
+                open: *close,
+                date: idx as i64,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_calculate_columns() {
+        let data = prices(&[10.0, 11.0, 12.0, 13.0, 11.0, 10.0, 9.0, 12.0]);
+
+        let columns = calculate_columns(&data, 1.0, 3, PriceOption::Close);
+
+        // X's at 11 to 13, then 2 boxes down to 11 isn't enough to reverse.
+        // Falling 3 boxes to 10 starts O's at 12, which extend to 9, and
+        // rising 3 boxes from there to 12 starts X's at 10
+        assert_eq!(
+            columns,
+            vec![
+                Column {
+                    up: true,
+                    low: 10,
+                    high: 13,
+                    date: 1,
+                },
+                Column {
+                    up: false,
+                    low: 8,
+                    high: 12,
+                    date: 5,
+                },
+                Column {
+                    up: true,
+                    low: 9,
+                    high: 12,
+                    date: 7,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calculate_columns_falling_start() {
+        let data = prices(&[10.0, 9.5, 8.0, 9.0]);
+
+        let columns = calculate_columns(&data, 1.0, 3, PriceOption::Close);
+
+        // O's at 9 and 8, with the 1 box rise not enough to reverse
+        assert_eq!(
+            columns,
+            vec![Column {
+                up: false,
+                low: 7,
+                high: 9,
+                date: 2,
+            }]
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use std::hash::{Hash, Hasher};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::Span;
+use ratatui::widgets::canvas::{Canvas, Line, Rectangle};
+use ratatui::widgets::{Block, Borders, StatefulWidget, Widget};
+use serde::Deserialize;
+
+use super::date_labels;
+use crate::common::{Price, TimeFrame};
+use crate::draw::{add_padding, PaddingDirection};
+use crate::theme::style;
+use crate::widget::chart_configuration::RenkoOptions;
+use crate::widget::StockState;
+use crate::{indicator, HIDE_PREV_CLOSE, THEME};
+
+/// Stops a box size that's tiny compared to the price range from building an
+/// endless number of bricks
+const MAX_BRICKS: usize = 10_000;
+
+/// Price range of each brick / box of Renko and Point & Figure charts
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum BoxSizeOption {
+    /// Fraction of the latest price
+    #[serde(rename = "pct")]
+    Pct(f64),
+    #[serde(rename = "amount")]
+    Amount(f64),
+    /// Latest average true range over this many bars
+    #[serde(rename = "atr")]
+    Atr(usize),
+}
+
+impl Default for BoxSizeOption {
+    fn default() -> Self {
+        BoxSizeOption::Atr(14)
+    }
+}
+
+impl Hash for BoxSizeOption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            BoxSizeOption::Pct(amount) => {
+                0.hash(state);
+                amount.to_bits().hash(state);
+            }
+            BoxSizeOption::Amount(amount) => {
+                1.hash(state);
+                amount.to_bits().hash(state);
+            }
+            BoxSizeOption::Atr(period) => {
+                2.hash(state);
+                period.hash(state);
+            }
+        }
+    }
+}
+
+impl BoxSizeOption {
+    /// Box size in price for `data`, if one can be worked out
+    pub fn resolve(self, data: &[Price]) -> Option<f64> {
+        let data = data
+            .iter()
+            .filter(|p| p.close.gt(&0.0))
+            .copied()
+            .collect::<Vec<_>>();
+
+        let size = match self {
+            BoxSizeOption::Pct(pct) => data.last()?.close * pct,
+            BoxSizeOption::Amount(amount) => amount,
+            BoxSizeOption::Atr(period) => indicator::atr(&data, period).into_iter().last()??,
+        };
+
+        (size > 0.0 && size.is_finite()).then_some(size)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Brick {
+    low: f64,
+    high: f64,
+    up: bool,
+    date: i64,
+}
+
+fn calculate_bricks(data: &[Price], box_size: f64) -> Vec<Brick> {
+    let mut bricks: Vec<Brick> = vec![];
+
+    let mut data = data.iter().filter(|p| p.close.gt(&0.0));
+
+    let Some(first) = data.next() else {
+        return bricks;
+    };
+
+    // Range of the last brick, which the close has to clear by a full box in
+    // either direction to add another
+    let (mut low, mut high) = (first.close, first.close);
+
+    for price in data {
+        while price.close >= high + box_size && bricks.len() < MAX_BRICKS {
+            bricks.push(Brick {
+                low: high,
+                high: high + box_size,
+                up: true,
+                date: price.date,
+            });
+
+            (low, high) = (high, high + box_size);
+        }
+
+        while price.close <= low - box_size && bricks.len() < MAX_BRICKS {
+            bricks.push(Brick {
+                low: low - box_size,
+                high: low,
+                up: false,
+                date: price.date,
+            });
+
+            (low, high) = (low - box_size, low);
+        }
+    }
+
+    bricks
+}
+
+pub struct PricesRenkoChart<'a> {
+    pub loaded: bool,
+    pub data: &'a [Price],
+    pub is_summary: bool,
+    pub show_x_labels: bool,
+    pub renko_options: RenkoOptions,
+}
+
+impl StatefulWidget for PricesRenkoChart<'_> {
+    type State = StockState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if area.width <= 9 || area.height <= 3 {
+            return;
+        }
+
+        let box_size_option = self
+            .renko_options
+            .box_size_option
+            .as_ref()
+            .and_then(|o| o.get(state.time_frame))
+            .unwrap_or_default();
+
+        let bricks = box_size_option
+            .resolve(self.data)
+            .map(|box_size| calculate_bricks(self.data, box_size))
+            .unwrap_or_default();
+
+        if !self.is_summary {
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(style().fg(THEME.border_secondary()))
+                .render(area, buf);
+            area = add_padding(area, 1, PaddingDirection::Top);
+        }
+
+        // x_layout[0] - chart + y labels
+        // x_layout[1] - (x labels)
+        let x_layout: Vec<Rect> = Layout::default()
+            .constraints(if self.show_x_labels {
+                &[Constraint::Min(0), Constraint::Length(1)][..]
+            } else {
+                &[Constraint::Min(0)][..]
+            })
+            .split(area)
+            .to_vec();
+
+        // layout[0] - Y lables
+        // layout[1] - chart
+        let mut layout: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(if !self.loaded {
+                    8
+                } else if self.show_x_labels {
+                    state.time_frame.y_label_width()
+                } else {
+                    9
+                }),
+                Constraint::Min(0),
+            ])
+            .split(x_layout[0])
+            .to_vec();
+
+        // Fix for border render
+        layout[1].x = layout[1].x.saturating_sub(1);
+        layout[1].width += 1;
+
+        let width = layout[1].width - 1;
+        let num_bricks_can_render = (width / 2) as usize;
+        let max_offset = bricks.len().saturating_sub(num_bricks_can_render);
+
+        let chart_width = num_bricks_can_render as f64 * 4.0;
+
+        let offset = if self.is_summary {
+            max_offset
+        } else if let Some(chart_state) = state.chart_state_mut() {
+            if let Some(direction) = chart_state.queued_scroll.take() {
+                chart_state.scroll(direction, max_offset);
+            }
+
+            chart_state.offset(max_offset)
+        } else {
+            max_offset
+        };
+
+        let bricks = &bricks[offset..(offset + num_bricks_can_render).min(bricks.len())];
+
+        let (min, max) = min_max(bricks, state.time_frame, state.prev_close_price);
+
+        // Draw x labels
+        if self.show_x_labels && self.loaded {
+            // Plot labels on
+            let mut x_area = x_layout[1];
+            x_area.x = layout[1].x + 1;
+            x_area.width = layout[1].width - 1;
+
+            // Fix for y label render
+            layout[0] = add_padding(layout[0], 1, PaddingDirection::Bottom);
+
+            let dates = bricks.iter().map(|b| b.date).collect::<Vec<_>>();
+
+            let labels = date_labels(x_area.width + x_area.left(), &dates, state.time_frame);
+            let total_width = labels.iter().map(Span::width).sum::<usize>() as u16;
+            let labels_len = labels.len() as u16;
+            if total_width <= (x_area.width + x_area.x) && labels_len >= 1 {
+                for (i, label) in labels.iter().enumerate() {
+                    buf.set_span(
+                        x_area.left() + i as u16 * (x_area.width - 1) / (labels_len.max(2) - 1)
+                            - label.width() as u16,
+                        x_area.top(),
+                        label,
+                        label.width() as u16,
+                    );
+                }
+            }
+        }
+
+        // Draw y labels
+        if self.loaded {
+            let y_area = layout[0];
+
+            let labels = state.y_labels(min, max);
+            let labels_len = labels.len() as u16;
+            for (i, label) in labels.iter().enumerate() {
+                let dy = i as u16 * (y_area.height - 1) / (labels_len - 1);
+                if dy < y_area.bottom() {
+                    buf.set_span(
+                        y_area.left(),
+                        y_area.bottom() - 1 - dy,
+                        label,
+                        label.width() as u16,
+                    );
+                }
+            }
+        }
+
+        if self.loaded {
+            Canvas::default()
+                .background_color(THEME.background())
+                .block(
+                    Block::default()
+                        .style(style())
+                        .borders(if self.show_x_labels {
+                            Borders::LEFT | Borders::BOTTOM
+                        } else {
+                            Borders::LEFT
+                        })
+                        .border_style(style().fg(THEME.border_axis())),
+                )
+                .x_bounds([0.0, chart_width])
+                .y_bounds(state.y_bounds(min, max))
+                .paint(move |ctx| {
+                    if let (TimeFrame::Day1, true, false, Some(prev_close)) = (
+                        state.time_frame,
+                        self.loaded,
+                        *HIDE_PREV_CLOSE,
+                        state.prev_close_price,
+                    ) {
+                        ctx.draw(&Line {
+                            x1: 0.0,
+                            x2: chart_width,
+                            y1: prev_close,
+                            y2: prev_close,
+                            color: THEME.gray(),
+                        });
+                    }
+
+                    ctx.layer();
+
+                    for (idx, brick) in bricks.iter().enumerate() {
+                        ctx.draw(&Rectangle {
+                            x: idx as f64 * 4.0 + 1.0,
+                            y: brick.low,
+                            width: 2.0,
+                            height: brick.high - brick.low,
+                            color: if brick.up {
+                                THEME.profit()
+                            } else {
+                                THEME.loss()
+                            },
+                        });
+                    }
+                })
+                .render(layout[1], buf);
+        } else {
+            Block::default()
+                .borders(if self.show_x_labels {
+                    Borders::LEFT | Borders::BOTTOM
+                } else {
+                    Borders::LEFT
+                })
+                .border_style(style().fg(THEME.border_axis()))
+                .render(layout[1], buf);
+        }
+    }
+}
+
+fn min_max(bricks: &[Brick], time_frame: TimeFrame, prev_close_price: Option<f64>) -> (f64, f64) {
+    let mut min = bricks
+        .iter()
+        .map(|b| b.low)
+        .min_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap_or(0.0);
+    let mut max = bricks
+        .iter()
+        .map(|b| b.high)
+        .max_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap_or(1.0);
+
+    if time_frame == TimeFrame::Day1 && !*HIDE_PREV_CLOSE {
+        if let Some(prev_close) = prev_close_price {
+            min = min.min(prev_close);
+            max = max.max(prev_close);
+        }
+    }
+
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_bricks() {
+        let data = [10.0, 12.5, 11.5, 9.0, 8.0]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, close)| Price {
+                close,
+                date: idx as i64,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let bricks = calculate_bricks(&data, 1.0)
+            .into_iter()
+            .map(|brick| (brick.low, brick.high, brick.up, brick.date))
+            .collect::<Vec<_>>();
+
+        // Falling back to 11.5 is within the last brick, and reversing needs a
+        // full box below it
+        assert_eq!(
+            bricks,
+            vec![
+                (10.0, 11.0, true, 1),
+                (11.0, 12.0, true, 1),
+                (10.0, 11.0, false, 3),
+                (9.0, 10.0, false, 3),
+                (8.0, 9.0, false, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_box_size() {
+        let data = [
+            Price {
+                close: 100.0,
+                ..Default::default()
+            },
+            Price::default(),
+        ];
+
+        assert_eq!(BoxSizeOption::Pct(0.01).resolve(&data), Some(1.0));
+        assert_eq!(BoxSizeOption::Amount(2.5).resolve(&data), Some(2.5));
+        assert_eq!(BoxSizeOption::Amount(0.0).resolve(&data), None);
+        assert_eq!(BoxSizeOption::Atr(14).resolve(&data), None);
+    }
+}
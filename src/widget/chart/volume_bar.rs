@@ -4,7 +4,7 @@ use ratatui::layout::Rect;
 use ratatui::symbols::bar;
 use ratatui::widgets::{BarChart, Block, Borders, StatefulWidget, Widget};
 
-use crate::common::{Price, TimeFrame};
+use crate::common::Price;
 use crate::theme::style;
 use crate::widget::StockState;
 use crate::THEME;
@@ -25,11 +25,7 @@ impl StatefulWidget for VolumeBarChart<'_> {
         let x_offset = if !self.loaded {
             8
         } else if self.show_x_labels {
-            match state.time_frame {
-                TimeFrame::Day1 => 9,
-                TimeFrame::Week1 => 12,
-                _ => 11,
-            }
+            state.time_frame.y_label_width()
         } else {
             9
         };
@@ -40,11 +36,12 @@ impl StatefulWidget for VolumeBarChart<'_> {
         let num_bars = width as usize;
 
         let volumes = state.volumes(self.data);
-        let vol_count = volumes.len();
+        let range = state.visible_range(self.data);
+        let vol_count = range.end.min(volumes.len()).saturating_sub(range.start);
 
         if vol_count > 0 {
-            let volumes = self
-                .data
+            let volumes = state
+                .visible(self.data)
                 .iter()
                 .flat_map(|p| [p.volume].repeat(num_bars))
                 .chunks(vol_count)
@@ -9,25 +9,47 @@ use ratatui::widgets::{Block, Borders, Paragraph, StatefulWidget, Widget};
 use serde::Deserialize;
 
 use super::chart::prices_kagi::{self, ReversalOption};
+use super::chart::prices_pnf;
+use super::chart::prices_renko::BoxSizeOption;
 use super::{block, CachableWidget, CacheState};
 use crate::common::{ChartType, TimeFrame};
 use crate::draw::{add_padding, PaddingDirection};
 use crate::theme::style;
 use crate::THEME;
 
+/// Width of the overlay text inputs
+const OVERLAY_INPUT_WIDTH: usize = 6;
+
 #[derive(Default, Debug, Clone)]
 pub struct ChartConfigurationState {
     pub input: Input,
+    /// Chart type the form was last reset for
+    pub chart_type: ChartType,
     pub selection: Option<KagiSelection>,
+    pub overlay_selection: Option<OverlaySelection>,
+    pub box_selection: Option<BoxSelection>,
     pub error_message: Option<String>,
     pub kagi_options: KagiOptions,
+    pub overlay_options: OverlayOptions,
+    pub renko_options: RenkoOptions,
+    pub pnf_options: PointFigureOptions,
     pub cache_state: CacheState,
 }
 
 impl ChartConfigurationState {
     pub fn add_char(&mut self, c: char) {
-        let input_field = match self.selection {
-            Some(KagiSelection::ReversalValue) => &mut self.input.kagi_reversal_value,
+        if let Some(input_field) = self.overlay_input_field() {
+            if input_field.len() < OVERLAY_INPUT_WIDTH {
+                input_field.push(c);
+            }
+
+            return;
+        }
+
+        let input_field = match (self.selection, self.box_selection) {
+            (Some(KagiSelection::ReversalValue), _) => &mut self.input.kagi_reversal_value,
+            (_, Some(BoxSelection::BoxSizeValue)) => &mut self.input.box_size_value,
+            (_, Some(BoxSelection::Reversal)) => &mut self.input.pnf_reversal,
             _ => return,
         };
 
@@ -40,8 +62,16 @@ impl ChartConfigurationState {
     }
 
     pub fn del_char(&mut self) {
-        let input_field = match self.selection {
-            Some(KagiSelection::ReversalValue) => &mut self.input.kagi_reversal_value,
+        if let Some(input_field) = self.overlay_input_field() {
+            input_field.pop();
+
+            return;
+        }
+
+        let input_field = match (self.selection, self.box_selection) {
+            (Some(KagiSelection::ReversalValue), _) => &mut self.input.kagi_reversal_value,
+            (_, Some(BoxSelection::BoxSizeValue)) => &mut self.input.box_size_value,
+            (_, Some(BoxSelection::Reversal)) => &mut self.input.pnf_reversal,
             _ => return,
         };
 
@@ -49,27 +79,59 @@ impl ChartConfigurationState {
     }
 
     fn get_tab_artifacts(&mut self) -> Option<(&mut usize, usize)> {
-        let tab_field = match self.selection {
-            Some(KagiSelection::ReversalType) => &mut self.input.kagi_reversal_type,
-            Some(KagiSelection::PriceType) => &mut self.input.kagi_price_type,
+        let tab_field = match (self.selection, self.box_selection) {
+            (Some(KagiSelection::ReversalType), _) => &mut self.input.kagi_reversal_type,
+            (Some(KagiSelection::PriceType), _) => &mut self.input.kagi_price_type,
+            (_, Some(BoxSelection::PriceType)) => &mut self.input.pnf_price_type,
+            (_, Some(BoxSelection::BoxSizeType)) => &mut self.input.box_size_type,
             _ => return None,
         };
 
-        let mod_value = match self.selection {
-            Some(KagiSelection::ReversalType) => 2,
-            Some(KagiSelection::PriceType) => 2,
+        let mod_value = match (self.selection, self.box_selection) {
+            (Some(KagiSelection::ReversalType), _) => 2,
+            (Some(KagiSelection::PriceType), _) => 2,
+            (_, Some(BoxSelection::PriceType)) => 2,
+            (_, Some(BoxSelection::BoxSizeType)) => 3,
             _ => 1,
         };
         Some((tab_field, mod_value))
     }
 
+    fn overlay_input_field(&mut self) -> Option<&mut String> {
+        match self.overlay_selection? {
+            OverlaySelection::SmaPeriod => Some(&mut self.input.sma_period),
+            OverlaySelection::EmaPeriod => Some(&mut self.input.ema_period),
+            OverlaySelection::BollingerPeriod => Some(&mut self.input.bollinger_period),
+            OverlaySelection::BollingerStdDev => Some(&mut self.input.bollinger_std_dev),
+            _ => None,
+        }
+    }
+
+    fn overlay_toggle_field(&mut self) -> Option<&mut bool> {
+        match self.overlay_selection? {
+            OverlaySelection::Sma => Some(&mut self.input.sma),
+            OverlaySelection::Ema => Some(&mut self.input.ema),
+            OverlaySelection::Bollinger => Some(&mut self.input.bollinger),
+            OverlaySelection::Vwap => Some(&mut self.input.vwap),
+            _ => None,
+        }
+    }
+
     pub fn tab(&mut self) {
+        if let Some(toggle) = self.overlay_toggle_field() {
+            *toggle = !*toggle;
+        }
+
         if let Some((tab_field, mod_value)) = self.get_tab_artifacts() {
             *tab_field = (*tab_field + 1) % mod_value;
         }
     }
 
     pub fn back_tab(&mut self) {
+        if let Some(toggle) = self.overlay_toggle_field() {
+            *toggle = !*toggle;
+        }
+
         if let Some((tab_field, mod_value)) = self.get_tab_artifacts() {
             *tab_field = (*tab_field + mod_value - 1) % mod_value;
         }
@@ -78,6 +140,16 @@ impl ChartConfigurationState {
     pub fn enter(&mut self, time_frame: TimeFrame) {
         self.error_message.take();
 
+        if self.overlay_selection.is_some() {
+            self.enter_overlays();
+            return;
+        }
+
+        if self.box_selection.is_some() {
+            self.enter_box_options(time_frame);
+            return;
+        }
+
         // Validate Kagi reversal option
         let new_kagi_reversal_option = {
             let input_value = &self.input.kagi_reversal_value;
@@ -104,56 +176,132 @@ impl ChartConfigurationState {
         });
 
         // Everything validated, save the form values to our state
-        match &mut self.kagi_options.reversal_option {
-            reversal_options @ None => {
-                let mut options_by_timeframe = BTreeMap::new();
-                for iter_time_frame in TimeFrame::ALL.iter() {
-                    let default_reversal_amount = match iter_time_frame {
-                        TimeFrame::Day1 => 0.01,
-                        _ => 0.04,
-                    };
-
-                    // If this is the time frame we are submitting for, store that value,
-                    // otherwise use the default still
-                    if *iter_time_frame == time_frame {
-                        options_by_timeframe.insert(*iter_time_frame, new_kagi_reversal_option);
-                    } else {
-                        options_by_timeframe.insert(
-                            *iter_time_frame,
-                            ReversalOption::Pct(default_reversal_amount),
-                        );
-                    }
-                }
+        TimeFrameOption::set(
+            &mut self.kagi_options.reversal_option,
+            time_frame,
+            new_kagi_reversal_option,
+            |time_frame| ReversalOption::Pct(default_kagi_reversal_amount(time_frame)),
+        );
+
+        self.kagi_options.price_option = new_kagi_price_option;
+    }
+
+    fn enter_overlays(&mut self) {
+        let parse_period = |input: &str, name: &str| match input.parse::<usize>() {
+            Ok(period) if period > 0 => Ok(period),
+            _ => Err(format!("{} must be a whole number above 0", name)),
+        };
 
-                *reversal_options = Some(KagiReversalOption::ByTimeFrame(options_by_timeframe));
+        let validated = (|| {
+            let sma_period = parse_period(&self.input.sma_period, "SMA Period")?;
+            let ema_period = parse_period(&self.input.ema_period, "EMA Period")?;
+            let bollinger_period = parse_period(&self.input.bollinger_period, "Bollinger Period")?;
+            let bollinger_std_dev = match self.input.bollinger_std_dev.parse::<f64>() {
+                Ok(std_dev) if std_dev > 0.0 => std_dev,
+                _ => return Err("Bollinger Std Dev must be a number above 0".to_string()),
+            };
+
+            Ok(OverlayOptions {
+                sma: self.input.sma,
+                sma_period,
+                ema: self.input.ema,
+                ema_period,
+                bollinger: self.input.bollinger,
+                bollinger_period,
+                bollinger_std_dev,
+                vwap: self.input.vwap,
+            })
+        })();
+
+        match validated {
+            Ok(options) => self.overlay_options = options,
+            Err(message) => self.error_message = Some(message),
+        }
+    }
+
+    fn enter_box_options(&mut self, time_frame: TimeFrame) {
+        let validated = (|| {
+            let value = match self.input.box_size_value.parse::<f64>() {
+                Ok(value) if value > 0.0 => value,
+                _ => return Err("Box Size must be a number above 0".to_string()),
+            };
+
+            let box_size_option = match self.input.box_size_type {
+                0 => BoxSizeOption::Pct(value),
+                1 => BoxSizeOption::Amount(value),
+                2 if value.fract() == 0.0 => BoxSizeOption::Atr(value as usize),
+                2 => return Err("ATR Period must be a whole number above 0".to_string()),
+                _ => unreachable!(),
+            };
+
+            let reversal = match self.input.pnf_reversal.parse::<usize>() {
+                Ok(reversal) if reversal > 0 => reversal,
+                _ => return Err("Reversal must be a whole number above 0".to_string()),
+            };
+
+            Ok((box_size_option, reversal))
+        })();
+
+        let (box_size_option, reversal) = match validated {
+            Ok(validated) => validated,
+            Err(message) => {
+                self.error_message = Some(message);
+                return;
             }
-            reversal_options @ Some(KagiReversalOption::Single(_)) => {
-                // Always succeeds since we already pattern matched it
-                if let KagiReversalOption::Single(config_option) = reversal_options.clone().unwrap()
-                {
-                    let mut options_by_timeframe = BTreeMap::new();
-                    for iter_time_frame in TimeFrame::ALL.iter() {
-                        // If this is the time frame we are submitting for, store that value,
-                        // otherwise use the single value defined from the config
-                        if *iter_time_frame == time_frame {
-                            options_by_timeframe.insert(*iter_time_frame, new_kagi_reversal_option);
-                        } else {
-                            options_by_timeframe.insert(*iter_time_frame, config_option);
-                        }
-                    }
+        };
 
-                    *reversal_options = Some(KagiReversalOption::ByTimeFrame(options_by_timeframe));
-                }
+        // Everything validated, save the form values to our state
+        match self.chart_type {
+            ChartType::Renko => {
+                TimeFrameOption::set(
+                    &mut self.renko_options.box_size_option,
+                    time_frame,
+                    box_size_option,
+                    |_| BoxSizeOption::default(),
+                );
             }
-            Some(KagiReversalOption::ByTimeFrame(options_by_timeframe)) => {
-                options_by_timeframe.insert(time_frame, new_kagi_reversal_option);
+            ChartType::PointFigure => {
+                TimeFrameOption::set(
+                    &mut self.pnf_options.box_size_option,
+                    time_frame,
+                    box_size_option,
+                    |_| BoxSizeOption::default(),
+                );
+
+                self.pnf_options.reversal = Some(reversal);
+                self.pnf_options.price_option = Some(match self.input.pnf_price_type {
+                    0 => prices_kagi::PriceOption::Close,
+                    1 => prices_kagi::PriceOption::HighLow,
+                    _ => unreachable!(),
+                });
             }
+            _ => {}
         }
-
-        self.kagi_options.price_option = new_kagi_price_option;
     }
 
     pub fn selection_up(&mut self) {
+        if let Some(selection) = self.overlay_selection {
+            let idx = OverlaySelection::ALL
+                .iter()
+                .position(|s| *s == selection)
+                .unwrap_or_default();
+            let len = OverlaySelection::ALL.len();
+
+            self.overlay_selection = Some(OverlaySelection::ALL[(idx + len - 1) % len]);
+            return;
+        }
+
+        if let Some(selection) = self.box_selection {
+            let rows = BoxSelection::rows(self.chart_type);
+            let idx = rows
+                .iter()
+                .position(|s| *s == selection)
+                .unwrap_or_default();
+
+            self.box_selection = Some(rows[(idx + rows.len() - 1) % rows.len()]);
+            return;
+        }
+
         let new_selection = match self.selection {
             None => KagiSelection::ReversalValue,
             Some(KagiSelection::ReversalValue) => KagiSelection::ReversalType,
@@ -165,6 +313,28 @@ impl ChartConfigurationState {
     }
 
     pub fn selection_down(&mut self) {
+        if let Some(selection) = self.overlay_selection {
+            let idx = OverlaySelection::ALL
+                .iter()
+                .position(|s| *s == selection)
+                .unwrap_or_default();
+
+            self.overlay_selection =
+                Some(OverlaySelection::ALL[(idx + 1) % OverlaySelection::ALL.len()]);
+            return;
+        }
+
+        if let Some(selection) = self.box_selection {
+            let rows = BoxSelection::rows(self.chart_type);
+            let idx = rows
+                .iter()
+                .position(|s| *s == selection)
+                .unwrap_or_default();
+
+            self.box_selection = Some(rows[(idx + 1) % rows.len()]);
+            return;
+        }
+
         let new_selection = match self.selection {
             None => KagiSelection::PriceType,
             Some(KagiSelection::PriceType) => KagiSelection::ReversalType,
@@ -175,34 +345,79 @@ impl ChartConfigurationState {
         self.selection = Some(new_selection);
     }
 
-    pub fn reset_form(&mut self, time_frame: TimeFrame) {
+    pub fn reset_form(&mut self, chart_type: ChartType, time_frame: TimeFrame) {
         self.input = Default::default();
+        self.chart_type = chart_type;
         self.error_message.take();
+        self.selection.take();
+        self.overlay_selection.take();
+        self.box_selection.take();
 
-        let default_reversal_amount = match time_frame {
-            TimeFrame::Day1 => 0.01,
-            _ => 0.04,
-        };
+        if matches!(chart_type, ChartType::Renko | ChartType::PointFigure) {
+            let box_size_option = match chart_type {
+                ChartType::Renko => self.renko_options.box_size_option.as_ref(),
+                _ => self.pnf_options.box_size_option.as_ref(),
+            }
+            .and_then(|o| o.get(time_frame))
+            .unwrap_or_default();
+
+            let (box_size_type, box_size_value) = match box_size_option {
+                BoxSizeOption::Pct(pct) => (0, pct.to_string()),
+                BoxSizeOption::Amount(amount) => (1, amount.to_string()),
+                BoxSizeOption::Atr(period) => (2, period.to_string()),
+            };
+
+            let price_type = self
+                .pnf_options
+                .price_option
+                .map(|p| match p {
+                    prices_kagi::PriceOption::Close => 0,
+                    prices_kagi::PriceOption::HighLow => 1,
+                })
+                .unwrap_or(0);
+
+            self.box_selection = Some(BoxSelection::rows(chart_type)[0]);
+            self.input.box_size_type = box_size_type;
+            self.input.box_size_value = box_size_value;
+            self.input.pnf_price_type = price_type;
+            self.input.pnf_reversal = self
+                .pnf_options
+                .reversal
+                .unwrap_or(prices_pnf::DEFAULT_REVERSAL)
+                .to_string();
+
+            return;
+        }
+
+        if chart_type.is_time_based() {
+            let options = self.overlay_options;
+
+            self.overlay_selection = Some(OverlaySelection::Sma);
+            self.input.sma = options.sma;
+            self.input.sma_period = options.sma_period.to_string();
+            self.input.ema = options.ema;
+            self.input.ema_period = options.ema_period.to_string();
+            self.input.bollinger = options.bollinger;
+            self.input.bollinger_period = options.bollinger_period.to_string();
+            self.input.bollinger_std_dev = options.bollinger_std_dev.to_string();
+            self.input.vwap = options.vwap;
 
-        let (reversal_type, reversal_amount) = self
+            return;
+        }
+
+        let reversal_option = self
             .kagi_options
             .reversal_option
             .as_ref()
-            .map(|o| {
-                let option = match o {
-                    KagiReversalOption::Single(option) => *option,
-                    KagiReversalOption::ByTimeFrame(options_by_timeframe) => options_by_timeframe
-                        .get(&time_frame)
-                        .copied()
-                        .unwrap_or(ReversalOption::Pct(default_reversal_amount)),
-                };
+            .and_then(|o| o.get(time_frame))
+            .unwrap_or(ReversalOption::Pct(default_kagi_reversal_amount(
+                time_frame,
+            )));
 
-                match option {
-                    ReversalOption::Pct(amount) => (0, amount),
-                    ReversalOption::Amount(amount) => (1, amount),
-                }
-            })
-            .unwrap_or((0, default_reversal_amount));
+        let (reversal_type, reversal_amount) = match reversal_option {
+            ReversalOption::Pct(amount) => (0, amount),
+            ReversalOption::Amount(amount) => (1, amount),
+        };
 
         let price_type = self
             .kagi_options
@@ -224,8 +439,14 @@ impl Hash for ChartConfigurationState {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.input.hash(state);
         self.selection.hash(state);
+        self.chart_type.hash(state);
+        self.overlay_selection.hash(state);
+        self.box_selection.hash(state);
         self.error_message.hash(state);
         self.kagi_options.hash(state);
+        self.overlay_options.hash(state);
+        self.renko_options.hash(state);
+        self.pnf_options.hash(state);
     }
 }
 
@@ -234,6 +455,61 @@ pub struct Input {
     pub kagi_reversal_type: usize,
     pub kagi_reversal_value: String,
     pub kagi_price_type: usize,
+    pub sma: bool,
+    pub sma_period: String,
+    pub ema: bool,
+    pub ema_period: String,
+    pub bollinger: bool,
+    pub bollinger_period: String,
+    pub bollinger_std_dev: String,
+    pub vwap: bool,
+    pub box_size_type: usize,
+    pub box_size_value: String,
+    pub pnf_price_type: usize,
+    pub pnf_reversal: String,
+}
+
+/// Indicators drawn over the line and candlestick charts
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct OverlayOptions {
+    pub sma: bool,
+    pub sma_period: usize,
+    pub ema: bool,
+    pub ema_period: usize,
+    pub bollinger: bool,
+    pub bollinger_period: usize,
+    pub bollinger_std_dev: f64,
+    /// Only drawn on the 1D time frame
+    pub vwap: bool,
+}
+
+impl Default for OverlayOptions {
+    fn default() -> Self {
+        OverlayOptions {
+            sma: false,
+            sma_period: 20,
+            ema: false,
+            ema_period: 50,
+            bollinger: false,
+            bollinger_period: 20,
+            bollinger_std_dev: 2.0,
+            vwap: false,
+        }
+    }
+}
+
+impl Hash for OverlayOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sma.hash(state);
+        self.sma_period.hash(state);
+        self.ema.hash(state);
+        self.ema_period.hash(state);
+        self.bollinger.hash(state);
+        self.bollinger_period.hash(state);
+        self.bollinger_std_dev.to_bits().hash(state);
+        self.vwap.hash(state);
+    }
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Hash)]
@@ -244,11 +520,75 @@ pub struct KagiOptions {
     pub price_option: Option<prices_kagi::PriceOption>,
 }
 
+pub type KagiReversalOption = TimeFrameOption<prices_kagi::ReversalOption>;
+
+#[derive(Default, Debug, Clone, Deserialize, Hash)]
+pub struct RenkoOptions {
+    #[serde(rename = "box_size")]
+    pub box_size_option: Option<TimeFrameOption<BoxSizeOption>>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Hash)]
+pub struct PointFigureOptions {
+    #[serde(rename = "box_size")]
+    pub box_size_option: Option<TimeFrameOption<BoxSizeOption>>,
+    /// Number of boxes needed to reverse into a new column
+    pub reversal: Option<usize>,
+    #[serde(rename = "price")]
+    pub price_option: Option<prices_kagi::PriceOption>,
+}
+
+/// Option that's either shared by all time frames or set for each one
 #[derive(Debug, Clone, Deserialize, Hash)]
 #[serde(untagged)]
-pub enum KagiReversalOption {
-    Single(prices_kagi::ReversalOption),
-    ByTimeFrame(BTreeMap<TimeFrame, prices_kagi::ReversalOption>),
+pub enum TimeFrameOption<T> {
+    Single(T),
+    ByTimeFrame(BTreeMap<TimeFrame, T>),
+}
+
+impl<T: Copy> TimeFrameOption<T> {
+    pub fn get(&self, time_frame: TimeFrame) -> Option<T> {
+        match self {
+            TimeFrameOption::Single(option) => Some(*option),
+            TimeFrameOption::ByTimeFrame(options_by_timeframe) => {
+                options_by_timeframe.get(&time_frame).copied()
+            }
+        }
+    }
+
+    /// Sets the option for `time_frame` only, so the other time frames keep
+    /// what they had before, falling back to `default`
+    fn set(
+        option: &mut Option<Self>,
+        time_frame: TimeFrame,
+        value: T,
+        default: fn(TimeFrame) -> T,
+    ) {
+        let options_by_timeframe = TimeFrame::all()
+            .iter()
+            .map(|iter_time_frame| {
+                let value = if *iter_time_frame == time_frame {
+                    value
+                } else {
+                    option
+                        .as_ref()
+                        .and_then(|o| o.get(*iter_time_frame))
+                        .unwrap_or_else(|| default(*iter_time_frame))
+                };
+
+                (*iter_time_frame, value)
+            })
+            .collect();
+
+        *option = Some(TimeFrameOption::ByTimeFrame(options_by_timeframe));
+    }
+}
+
+pub fn default_kagi_reversal_amount(time_frame: TimeFrame) -> f64 {
+    match time_frame {
+        TimeFrame::Day1 => 0.01,
+        _ => 0.04,
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -258,6 +598,76 @@ pub enum KagiSelection {
     ReversalValue,
 }
 
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum OverlaySelection {
+    Sma,
+    SmaPeriod,
+    Ema,
+    EmaPeriod,
+    Bollinger,
+    BollingerPeriod,
+    BollingerStdDev,
+    Vwap,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum BoxSelection {
+    PriceType,
+    BoxSizeType,
+    BoxSizeValue,
+    Reversal,
+}
+
+impl BoxSelection {
+    /// Rows of the form for each chart type
+    fn rows(chart_type: ChartType) -> &'static [BoxSelection] {
+        match chart_type {
+            ChartType::PointFigure => &[
+                BoxSelection::PriceType,
+                BoxSelection::BoxSizeType,
+                BoxSelection::BoxSizeValue,
+                BoxSelection::Reversal,
+            ],
+            _ => &[BoxSelection::BoxSizeType, BoxSelection::BoxSizeValue],
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            BoxSelection::PriceType => "Price Type",
+            BoxSelection::BoxSizeType => "Box Size Type",
+            BoxSelection::BoxSizeValue => "Box Size Value",
+            BoxSelection::Reversal => "Reversal Boxes",
+        }
+    }
+}
+
+impl OverlaySelection {
+    const ALL: [OverlaySelection; 8] = [
+        OverlaySelection::Sma,
+        OverlaySelection::SmaPeriod,
+        OverlaySelection::Ema,
+        OverlaySelection::EmaPeriod,
+        OverlaySelection::Bollinger,
+        OverlaySelection::BollingerPeriod,
+        OverlaySelection::BollingerStdDev,
+        OverlaySelection::Vwap,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            OverlaySelection::Sma => "SMA",
+            OverlaySelection::SmaPeriod => "SMA Period",
+            OverlaySelection::Ema => "EMA",
+            OverlaySelection::EmaPeriod => "EMA Period",
+            OverlaySelection::Bollinger => "Bollinger",
+            OverlaySelection::BollingerPeriod => "Bollinger Period",
+            OverlaySelection::BollingerStdDev => "Bollinger Std Dev",
+            OverlaySelection::Vwap => "VWAP (1D)",
+        }
+    }
+}
+
 pub struct ChartConfigurationWidget {
     pub chart_type: ChartType,
 }
@@ -321,9 +731,15 @@ impl CachableWidget<ChartConfigurationState> for ChartConfigurationWidget {
             .render(layout[0], buf);
 
         match self.chart_type {
-            ChartType::Line => {}
-            ChartType::Candlestick => {}
+            ChartType::Line
+            | ChartType::Area
+            | ChartType::Candlestick
+            | ChartType::Ohlc
+            | ChartType::HeikinAshi => render_overlay_options(layout[1], buf, state),
             ChartType::Kagi => render_kagi_options(layout[1], buf, state),
+            ChartType::Renko | ChartType::PointFigure => {
+                render_box_options(layout[1], buf, state, self.chart_type)
+            }
         }
     }
 }
@@ -480,3 +896,265 @@ fn render_kagi_options(mut area: Rect, buf: &mut Buffer, state: &ChartConfigurat
         }
     }
 }
+
+fn render_overlay_options(mut area: Rect, buf: &mut Buffer, state: &ChartConfigurationState) {
+    Block::default()
+        .style(style())
+        .title(vec![Span::styled(
+            "Overlay Options ",
+            style().fg(THEME.text_normal()),
+        )])
+        .borders(Borders::TOP)
+        .border_style(style().fg(THEME.border_secondary()))
+        .render(area, buf);
+
+    area = add_padding(area, 1, PaddingDirection::Top);
+
+    // layout[0] - Left column
+    // layout[1] - Divider
+    // layout[2] - Right Column
+    let layout = Layout::default()
+        .direction(ratatui::layout::Direction::Horizontal)
+        .constraints([
+            Constraint::Length(19),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area)
+        .to_vec();
+
+    let mut left_column = vec![Line::default()];
+    let mut right_column = vec![Line::default()];
+
+    for selection in OverlaySelection::ALL {
+        let selected = state.overlay_selection == Some(selection);
+
+        left_column.push(Line::from(vec![
+            Span::styled(
+                if selected { "> " } else { "  " },
+                style().fg(THEME.text_primary()),
+            ),
+            Span::styled(selection.label(), style().fg(THEME.text_normal())),
+        ]));
+
+        let toggle = match selection {
+            OverlaySelection::Sma => Some(state.input.sma),
+            OverlaySelection::Ema => Some(state.input.ema),
+            OverlaySelection::Bollinger => Some(state.input.bollinger),
+            OverlaySelection::Vwap => Some(state.input.vwap),
+            _ => None,
+        };
+
+        let value = match selection {
+            OverlaySelection::SmaPeriod => &state.input.sma_period,
+            OverlaySelection::EmaPeriod => &state.input.ema_period,
+            OverlaySelection::BollingerPeriod => &state.input.bollinger_period,
+            OverlaySelection::BollingerStdDev => &state.input.bollinger_std_dev,
+            _ => "",
+        };
+
+        right_column.push(match toggle {
+            Some(enabled) => {
+                let option_style = |highlighted: bool| {
+                    style()
+                        .fg(THEME.text_normal())
+                        .bg(match (selected, highlighted) {
+                            (true, true) => THEME.highlight_focused(),
+                            (false, true) => THEME.highlight_unfocused(),
+                            (_, false) => THEME.background(),
+                        })
+                };
+
+                Line::from(vec![
+                    Span::styled("On", option_style(enabled)),
+                    Span::styled(" | ", style().fg(THEME.text_normal())),
+                    Span::styled("Off", option_style(!enabled)),
+                ])
+            }
+            None => Line::from(Span::styled(
+                format!("{: <width$}", value, width = OVERLAY_INPUT_WIDTH + 2),
+                style()
+                    .fg(if selected {
+                        THEME.text_secondary()
+                    } else {
+                        THEME.text_normal()
+                    })
+                    .bg(if selected {
+                        THEME.highlight_unfocused()
+                    } else {
+                        THEME.background()
+                    }),
+            )),
+        });
+    }
+
+    Paragraph::new(left_column)
+        .style(style().fg(THEME.text_normal()))
+        .render(layout[0], buf);
+
+    Paragraph::new(right_column)
+        .style(style().fg(THEME.text_normal()))
+        .render(layout[2], buf);
+
+    // Set "cursor" color
+    if let Some(selection) = state.overlay_selection {
+        let value = match selection {
+            OverlaySelection::SmaPeriod => &state.input.sma_period,
+            OverlaySelection::EmaPeriod => &state.input.ema_period,
+            OverlaySelection::BollingerPeriod => &state.input.bollinger_period,
+            OverlaySelection::BollingerStdDev => &state.input.bollinger_std_dev,
+            _ => return,
+        };
+
+        let row = OverlaySelection::ALL
+            .iter()
+            .position(|s| *s == selection)
+            .unwrap_or_default();
+
+        let x = layout[2].left() + value.len().min(OVERLAY_INPUT_WIDTH) as u16;
+        let y = layout[2].top() + 1 + row as u16;
+
+        if x < buf.area.right() && y < buf.area.bottom() {
+            buf.get_mut(x, y).bg = THEME.text_secondary();
+        }
+    }
+}
+
+fn render_box_options(
+    mut area: Rect,
+    buf: &mut Buffer,
+    state: &ChartConfigurationState,
+    chart_type: ChartType,
+) {
+    Block::default()
+        .style(style())
+        .title(vec![Span::styled(
+            if chart_type == ChartType::Renko {
+                "Renko Options "
+            } else {
+                "Point & Figure Options "
+            },
+            style().fg(THEME.text_normal()),
+        )])
+        .borders(Borders::TOP)
+        .border_style(style().fg(THEME.border_secondary()))
+        .render(area, buf);
+
+    area = add_padding(area, 1, PaddingDirection::Top);
+
+    // layout[0] - Left column
+    // layout[1] - Divider
+    // layout[2] - Right Column
+    let layout = Layout::default()
+        .direction(ratatui::layout::Direction::Horizontal)
+        .constraints([
+            Constraint::Length(16),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area)
+        .to_vec();
+
+    let rows = BoxSelection::rows(chart_type);
+
+    let mut left_column = vec![];
+    let mut right_column = vec![];
+
+    for selection in rows {
+        let selected = state.box_selection == Some(*selection);
+
+        left_column.push(Line::default());
+        left_column.push(Line::from(vec![
+            Span::styled(
+                if selected { "> " } else { "  " },
+                style().fg(THEME.text_primary()),
+            ),
+            Span::styled(selection.label(), style().fg(THEME.text_normal())),
+        ]));
+
+        let choices = match selection {
+            BoxSelection::PriceType => {
+                Some((&["Close", "High / Low"][..], state.input.pnf_price_type))
+            }
+            BoxSelection::BoxSizeType => {
+                Some((&["Pct", "Amount", "ATR"][..], state.input.box_size_type))
+            }
+            _ => None,
+        };
+
+        let value = match selection {
+            BoxSelection::BoxSizeValue => &state.input.box_size_value,
+            BoxSelection::Reversal => &state.input.pnf_reversal,
+            _ => "",
+        };
+
+        right_column.push(Line::default());
+        right_column.push(match choices {
+            Some((choices, chosen)) => {
+                let mut spans = vec![];
+
+                for (idx, choice) in choices.iter().enumerate() {
+                    if idx > 0 {
+                        spans.push(Span::styled(" | ", style().fg(THEME.text_normal())));
+                    }
+
+                    spans.push(Span::styled(
+                        *choice,
+                        style()
+                            .fg(THEME.text_normal())
+                            .bg(match (selected, idx == chosen) {
+                                (true, true) => THEME.highlight_focused(),
+                                (false, true) => THEME.highlight_unfocused(),
+                                (_, false) => THEME.background(),
+                            }),
+                    ));
+                }
+
+                Line::from(spans)
+            }
+            None => Line::from(Span::styled(
+                format!("{: <22}", value),
+                style()
+                    .fg(if selected {
+                        THEME.text_secondary()
+                    } else {
+                        THEME.text_normal()
+                    })
+                    .bg(if selected {
+                        THEME.highlight_unfocused()
+                    } else {
+                        THEME.background()
+                    }),
+            )),
+        });
+    }
+
+    Paragraph::new(left_column)
+        .style(style().fg(THEME.text_normal()))
+        .render(layout[0], buf);
+
+    Paragraph::new(right_column)
+        .style(style().fg(THEME.text_normal()))
+        .render(layout[2], buf);
+
+    // Set "cursor" color
+    if let Some(selection) = state.box_selection {
+        let value = match selection {
+            BoxSelection::BoxSizeValue => &state.input.box_size_value,
+            BoxSelection::Reversal => &state.input.pnf_reversal,
+            _ => return,
+        };
+
+        let row = rows
+            .iter()
+            .position(|s| *s == selection)
+            .unwrap_or_default();
+
+        let x = layout[2].left() + value.len().min(20) as u16;
+        let y = layout[2].top() + 1 + row as u16 * 2;
+
+        if x < buf.area.right() && y < buf.area.bottom() {
+            buf.get_mut(x, y).bg = THEME.text_secondary();
+        }
+    }
+}
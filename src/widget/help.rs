@@ -13,6 +13,7 @@ Quit: q or <Ctrl+c>
 Add Stock:
   - /: open prompt
   - (while adding):
+    - <Up / Down>: select match
     - <Enter>: accept
     - <Escape>: quit
 Change Tab:
@@ -24,9 +25,19 @@ Reorder Current Tab:
 Change Time Frame:
   - <Right / l>: next time frame
   - <Left / h>: prev time frame
+  - r: enter date range
 Toggle Summary Pane:
   - s: toggle pane
   - <Up/k / Down/j>: scroll pane
+Crosshair Cursor:
+  - m: toggle cursor
+  - <Left / Right> or mouse: move
+Compare Symbols:
+  - a: add symbol
+  - d: clear symbols
+Toggle Alerts Pane:
+  - n: toggle pane, h: history
+  - a / d: add / delete alert
 "#;
 
 const RIGHT_TEXT: &str = r#"
@@ -35,11 +46,17 @@ Graphing Display:
   - c: switch chart type
   - p: toggle pre / post market
   - v: toggle volumes graph
+  - i: cycle oscillator panel
   - x: toggle date labels
 Toggle Options Pane:
   - o: toggle pane
   - <Escape>: close pane
   - <Tab>: toggle calls / puts
+  - f / g: full chain / greeks
+  - s: IV smile / term structure
+  - m: open interest / max pain
+  - (in chain) b / s: buy / sell
+  - (in chain) <Enter>: strategy
   - Navigate with arrow keys
   - Cryptocurrency not supported
 Toggle Chart Configurations Pane:
@@ -47,13 +64,18 @@ Toggle Chart Configurations Pane:
   - <Up / Down>: move up/down
   - <Tab / Shift+Tab>: move up/down
   - <Left / Right>: select options
-  - <Enter>: submit changes
+  - <Enter>: apply, session only
+Zoom Chart:
+  - + / -: zoom in / out
+  - [ / ]: pan left / right
+  - 0: reset zoom
+  - Wheel / drag while cursor is on
 "#;
 
 const LEFT_WIDTH: usize = 34;
 const RIGHT_WIDTH: usize = 35;
 pub const HELP_WIDTH: usize = 2 + LEFT_WIDTH + 2 + RIGHT_WIDTH + 2;
-pub const HELP_HEIGHT: usize = 2 + 18 + 1;
+pub const HELP_HEIGHT: usize = 2 + 29 + 1;
 
 #[derive(Copy, Clone)]
 pub struct HelpWidget {}
@@ -1,28 +1,43 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
 
-use chrono::NaiveDateTime;
+use anyhow::Result;
+use chrono::{NaiveDateTime, Utc};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
-use ratatui::style::Modifier;
+use ratatui::style::{Color, Modifier};
+use ratatui::symbols::Marker;
 use ratatui::text::{Line, Span};
+use ratatui::widgets::canvas::{self, Canvas};
 use ratatui::widgets::{
     Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, StatefulWidget, Table,
-    TableState, Widget,
+    TableState, Widget, Wrap,
 };
 
 use super::{block, CachableWidget, CacheState};
-use crate::api::model::{OptionsData, OptionsQuote};
+use crate::api::model::{OptionsContract, OptionsData, OptionsQuote};
 use crate::draw::{add_padding, PaddingDirection};
+use crate::greeks::{Contract, Greeks};
 use crate::service::{self, Service};
+use crate::strategy::{Leg, OptionType, Side, Strategy};
 use crate::theme::style;
-use crate::THEME;
+use crate::{OPTS, RISK_FREE_RATE, SHOW_GREEKS, STRATEGIES, THEME};
 
-#[derive(Clone, Copy, PartialEq, Hash)]
-enum OptionType {
-    Call,
-    Put,
-}
+/// Extra width the options pane needs to fit the greek columns
+pub const GREEK_COLUMNS_WIDTH: u16 = 45;
+
+/// Contracts expire at the close on their expiration date, which is given as
+/// midnight UTC
+const EXPIRATION_CLOSE: i64 = 20 * 60 * 60;
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// Contracts Yahoo! Finance couldn't solve the implied volatility of are given
+/// one of almost zero
+const MIN_IMPLIED_VOLATILITY: f64 = 0.001;
+
+/// Width of the y labels of the payoff and volatility charts
+const Y_LABEL_WIDTH: u16 = 10;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SelectionMode {
@@ -31,6 +46,7 @@ pub enum SelectionMode {
 }
 
 pub struct OptionsState {
+    symbol: String,
     options_service: service::options::OptionsService,
     exp_dates: Vec<i64>,
     exp_date: Option<i64>,
@@ -38,7 +54,13 @@ pub struct OptionsState {
     selected_type: OptionType,
     pub selection_mode: SelectionMode,
     selected_option: Option<usize>,
+    selected_strike: Option<usize>,
     quote: Option<OptionsQuote>,
+    dividend_yield: f64,
+    strategy: Strategy,
+    selected_leg: usize,
+    interest_metric: InterestMetric,
+    errors: BTreeMap<service::options::Fetch, String>,
     cache_state: CacheState,
 }
 
@@ -51,14 +73,23 @@ impl Hash for OptionsState {
         self.selection_mode.hash(state);
         self.selected_option.hash(state);
         self.quote.hash(state);
+        self.errors.hash(state);
+        SHOW_GREEKS.read().hash(state);
     }
 }
 
 impl OptionsState {
     pub fn new(symbol: String) -> OptionsState {
-        let options_service = service::options::OptionsService::new(symbol);
+        let dividend_yield = OPTS
+            .dividend_yields
+            .get(&symbol)
+            .copied()
+            .unwrap_or_default();
+
+        let options_service = service::options::OptionsService::new(symbol.clone());
 
         OptionsState {
+            symbol,
             options_service,
             exp_dates: vec![],
             exp_date: None,
@@ -66,11 +97,22 @@ impl OptionsState {
             selected_type: OptionType::Call,
             selection_mode: SelectionMode::Dates,
             selected_option: None,
+            selected_strike: None,
             quote: None,
+            dividend_yield,
+            strategy: Default::default(),
+            selected_leg: 0,
+            interest_metric: InterestMetric::OpenInterest,
+            errors: BTreeMap::new(),
             cache_state: Default::default(),
         }
     }
 
+    /// Error from one of the fetches that is currently failing
+    pub fn error(&self) -> Option<&str> {
+        self.errors.values().next().map(String::as_str)
+    }
+
     pub fn data(&self) -> Option<&OptionsData> {
         if let Some(date) = self.exp_date {
             self.data.get(&date)
@@ -79,15 +121,93 @@ impl OptionsState {
         }
     }
 
+    /// Years left until the selected expiration date, as of the last quote
+    fn years_to_expiration(&self) -> Option<f64> {
+        self.years_until(self.data()?.expiration_date)
+    }
+
+    /// Years left until `expiration_date`, as of the last quote
+    fn years_until(&self, expiration_date: i64) -> Option<f64> {
+        let now = self
+            .quote
+            .as_ref()?
+            .regular_market_time
+            .unwrap_or_else(|| Utc::now().timestamp());
+
+        let expiration = expiration_date + EXPIRATION_CLOSE;
+
+        Some((expiration - now).max(0) as f64 / SECONDS_PER_YEAR)
+    }
+
+    /// Implied volatility of the call and put, averaged, at the strike closest
+    /// to the market price
+    fn atm_implied_volatility(&self, data: &OptionsData) -> Option<f64> {
+        atm_implied_volatility(data, self.quote.as_ref()?.regular_market_price)
+    }
+
+    /// Implied volatility of each contract of the selected expiration date by
+    /// strike, lowest strike first
+    fn smile(&self, option_type: OptionType) -> Vec<(f64, f64)> {
+        self.data()
+            .map(|data| smile(data, option_type))
+            .unwrap_or_default()
+    }
+
+    /// At the money implied volatility of each expiration date loaded, by days
+    /// left until it expires
+    fn term_structure(&self) -> Vec<(f64, f64)> {
+        self.exp_dates
+            .iter()
+            .filter_map(|date| {
+                let data = self.data.get(date)?;
+
+                Some((
+                    self.years_until(*date)? * 365.0,
+                    self.atm_implied_volatility(data)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Starts loading every expiration date, for the term structure
+    pub fn fetch_all_expiration_dates(&mut self) {
+        if !self.exp_dates.is_empty() && !self.options_service.is_fetching_all_expiration_dates() {
+            self.options_service
+                .fetch_all_expiration_dates(self.exp_dates.clone());
+        }
+    }
+
+    pub fn stop_fetching_all_expiration_dates(&mut self) {
+        self.options_service.stop_fetching_all_expiration_dates();
+
+        self.errors
+            .remove(&service::options::Fetch::ExpirationsData);
+    }
+
+    fn greeks(&self, option_type: OptionType, option: &OptionsContract) -> Option<Greeks> {
+        Contract {
+            call: option_type == OptionType::Call,
+            spot: self.quote.as_ref()?.regular_market_price,
+            strike: option.strike,
+            years: self.years_to_expiration()?,
+            volatility: option.implied_volatility?,
+            rate: *RISK_FREE_RATE,
+            dividend_yield: self.dividend_yield,
+        }
+        .greeks()
+    }
+
     fn set_exp_date(&mut self, date: i64) {
         self.exp_date = Some(date);
 
         self.options_service.set_expiration_date(date);
 
         self.selected_option.take();
+        self.selected_strike.take();
 
         if self.data().is_some() {
             self.set_selected_as_closest();
+            self.set_strike_as_closest();
         }
     }
 
@@ -126,6 +246,152 @@ impl OptionsState {
         self.selected_option = Some(closest_idx);
     }
 
+    /// Calls and puts of the selected expiration date paired up by strike,
+    /// highest strike first
+    fn straddles(&self) -> Vec<Straddle<'_>> {
+        self.data().map(straddles).unwrap_or_default()
+    }
+
+    /// Selects the strike closest to the market price
+    fn set_strike_as_closest(&mut self) {
+        let market_price = if let Some(ref quote) = self.quote {
+            quote.regular_market_price
+        } else {
+            0.0
+        };
+
+        self.selected_strike = self
+            .straddles()
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (a.strike - market_price)
+                    .abs()
+                    .total_cmp(&(b.strike - market_price).abs())
+            })
+            .map(|(idx, _)| idx);
+    }
+
+    /// Strike the underlying could expire at for holders of the selected
+    /// expiration date's open interest to be paid the least
+    fn max_pain(&self) -> Option<f64> {
+        max_pain(self.data()?)
+    }
+
+    /// Total puts over total calls of the selected expiration date
+    fn put_call_ratio(&self, metric: InterestMetric) -> Option<f64> {
+        put_call_ratio(self.data()?, metric)
+    }
+
+    pub fn toggle_interest_metric(&mut self) {
+        self.interest_metric = match self.interest_metric {
+            InterestMetric::OpenInterest => InterestMetric::Volume,
+            InterestMetric::Volume => InterestMetric::OpenInterest,
+        };
+    }
+
+    pub fn previous_strike(&mut self) {
+        if let Some(idx) = self.selected_strike {
+            self.selected_strike = Some(idx.saturating_sub(1));
+        }
+    }
+
+    pub fn next_strike(&mut self) {
+        if let Some(idx) = self.selected_strike {
+            let len = self.straddles().len();
+
+            self.selected_strike = Some((idx + 1).min(len.saturating_sub(1)));
+        }
+    }
+
+    /// Adds the selected contract of the options chain to the strategy
+    pub fn add_leg(&mut self, side: Side) {
+        let Some(expiration) = self
+            .exp_date
+            .and_then(|d| NaiveDateTime::from_timestamp_opt(d, 0))
+        else {
+            return;
+        };
+
+        let straddles = self.straddles();
+
+        let contract = self
+            .selected_strike
+            .and_then(|idx| straddles.get(idx))
+            .and_then(|straddle| match self.selected_type {
+                OptionType::Call => straddle.call,
+                OptionType::Put => straddle.put,
+            });
+
+        let Some(contract) = contract else {
+            return;
+        };
+
+        // Open at the middle of the spread when there is one
+        let price = match (contract.bid, contract.ask) {
+            (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 => (bid + ask) / 2.0,
+            _ => contract.last_price,
+        };
+
+        let leg = Leg {
+            side,
+            quantity: 1,
+            option_type: self.selected_type,
+            strike: contract.strike,
+            expiration: expiration.date(),
+            price,
+        };
+
+        self.strategy.add(leg);
+    }
+
+    pub fn remove_leg(&mut self) {
+        if self.selected_leg < self.strategy.legs.len() {
+            self.strategy.legs.remove(self.selected_leg);
+            self.selected_leg = self
+                .selected_leg
+                .min(self.strategy.legs.len().saturating_sub(1));
+        }
+    }
+
+    pub fn clear_legs(&mut self) {
+        self.strategy = Default::default();
+        self.selected_leg = 0;
+    }
+
+    pub fn previous_leg(&mut self) {
+        self.selected_leg = self.selected_leg.saturating_sub(1);
+    }
+
+    pub fn next_leg(&mut self) {
+        self.selected_leg = (self.selected_leg + 1).min(self.strategy.legs.len().saturating_sub(1));
+    }
+
+    /// Saves the strategy under `name` to `config.yml`, replacing any saved with
+    /// the same name
+    pub fn save_strategy(&mut self, name: String) -> Result<()> {
+        self.strategy.name = name;
+
+        STRATEGIES.write().save(&self.symbol, self.strategy.clone())
+    }
+
+    /// Opens the strategy saved after the one open, wrapping around
+    pub fn open_next_strategy(&mut self) {
+        let saved = STRATEGIES.read().strategies(&self.symbol);
+
+        if saved.is_empty() {
+            return;
+        }
+
+        let next = saved
+            .iter()
+            .position(|s| s.name == self.strategy.name)
+            .map_or(0, |idx| (idx + 1) % saved.len());
+
+        self.strategy = saved[next].clone();
+        self.selected_leg = 0;
+    }
+
     pub fn previous_date(&mut self) {
         if let Some(idx) = self
             .exp_dates
@@ -204,6 +470,9 @@ impl OptionsState {
         for update in updates {
             match update {
                 service::options::Update::ExpirationDates(dates) => {
+                    self.errors
+                        .remove(&service::options::Fetch::ExpirationDates);
+
                     let prev_len = self.exp_dates.len();
 
                     self.exp_dates = dates;
@@ -213,6 +482,8 @@ impl OptionsState {
                     }
                 }
                 service::options::Update::OptionsData(mut header) => {
+                    self.errors.remove(&service::options::Fetch::OptionsData);
+
                     if header.options.len() == 1 {
                         header.options[0].calls.reverse();
                         header.options[0].puts.reverse();
@@ -225,13 +496,284 @@ impl OptionsState {
                         if self.selected_option.is_none() {
                             self.set_selected_as_closest();
                         }
+
+                        if self.selected_strike.is_none() {
+                            self.set_strike_as_closest();
+                        }
+                    }
+                }
+                service::options::Update::ExpirationsData(headers) => {
+                    self.errors
+                        .remove(&service::options::Fetch::ExpirationsData);
+
+                    for mut header in headers {
+                        if header.options.len() == 1 {
+                            header.options[0].calls.reverse();
+                            header.options[0].puts.reverse();
+
+                            self.quote = Some(header.quote);
+
+                            let data = header.options.remove(0);
+                            self.data.insert(data.expiration_date, data);
+                        }
                     }
                 }
+                service::options::Update::Error(fetch, error) => {
+                    self.errors.insert(fetch, format!("{:#}", error));
+                }
             }
         }
     }
 }
 
+/// Call and put sharing a strike, either of which may not be listed
+struct Straddle<'a> {
+    strike: f64,
+    call: Option<&'a OptionsContract>,
+    put: Option<&'a OptionsContract>,
+}
+
+fn implied_volatility(contract: &OptionsContract) -> Option<f64> {
+    contract
+        .implied_volatility
+        .filter(|iv| *iv >= MIN_IMPLIED_VOLATILITY)
+}
+
+/// Implied volatility of the call and put, averaged, at the strike closest to
+/// `market_price`
+fn atm_implied_volatility(data: &OptionsData, market_price: f64) -> Option<f64> {
+    let closest = |contracts: &[OptionsContract]| {
+        contracts
+            .iter()
+            .min_by(|a, b| {
+                (a.strike - market_price)
+                    .abs()
+                    .total_cmp(&(b.strike - market_price).abs())
+            })
+            .map(|c| c.strike)
+    };
+
+    let strike = [closest(&data.calls), closest(&data.puts)]
+        .into_iter()
+        .flatten()
+        .min_by(|a, b| {
+            (a - market_price)
+                .abs()
+                .total_cmp(&(b - market_price).abs())
+        })?;
+
+    let ivs = data
+        .calls
+        .iter()
+        .chain(&data.puts)
+        .filter(|c| c.strike == strike)
+        .filter_map(implied_volatility)
+        .collect::<Vec<_>>();
+
+    (!ivs.is_empty()).then(|| ivs.iter().sum::<f64>() / ivs.len() as f64)
+}
+
+/// Implied volatility of each contract by strike, lowest strike first
+fn smile(data: &OptionsData, option_type: OptionType) -> Vec<(f64, f64)> {
+    let contracts = match option_type {
+        OptionType::Call => &data.calls,
+        OptionType::Put => &data.puts,
+    };
+
+    contracts
+        .iter()
+        .rev()
+        .filter_map(|c| Some((c.strike, implied_volatility(c)?)))
+        .collect()
+}
+
+/// Calls and puts paired up by strike, highest strike first
+fn straddles(data: &OptionsData) -> Vec<Straddle<'_>> {
+    let mut calls = data.calls.iter().peekable();
+    let mut puts = data.puts.iter().peekable();
+
+    let mut straddles = vec![];
+
+    loop {
+        let straddle = match (calls.peek(), puts.peek()) {
+            (Some(call), Some(put)) if call.strike == put.strike => Straddle {
+                strike: call.strike,
+                call: calls.next(),
+                put: puts.next(),
+            },
+            (Some(call), Some(put)) if call.strike < put.strike => Straddle {
+                strike: put.strike,
+                call: None,
+                put: puts.next(),
+            },
+            (Some(call), _) => Straddle {
+                strike: call.strike,
+                call: calls.next(),
+                put: None,
+            },
+            (None, Some(put)) => Straddle {
+                strike: put.strike,
+                call: None,
+                put: puts.next(),
+            },
+            (None, None) => break,
+        };
+
+        straddles.push(straddle);
+    }
+
+    straddles
+}
+
+/// Strike the underlying could expire at for holders of the open interest to
+/// be paid the least
+fn max_pain(data: &OptionsData) -> Option<f64> {
+    let open_interest = |contract: &OptionsContract| contract.open_interest.unwrap_or_default();
+
+    let has_open_interest = data
+        .calls
+        .iter()
+        .chain(&data.puts)
+        .any(|c| open_interest(c) > 0);
+
+    if !has_open_interest {
+        return None;
+    }
+
+    let paid = |price: f64| {
+        let calls = data
+            .calls
+            .iter()
+            .map(|c| open_interest(c) as f64 * (price - c.strike).max(0.0));
+        let puts = data
+            .puts
+            .iter()
+            .map(|c| open_interest(c) as f64 * (c.strike - price).max(0.0));
+
+        calls.chain(puts).sum::<f64>()
+    };
+
+    straddles(data)
+        .iter()
+        .map(|straddle| (straddle.strike, paid(straddle.strike)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(strike, _)| strike)
+}
+
+/// Total puts over total calls
+fn put_call_ratio(data: &OptionsData, metric: InterestMetric) -> Option<f64> {
+    let total = |contracts: &[OptionsContract]| {
+        contracts
+            .iter()
+            .filter_map(|c| metric.value(c))
+            .sum::<u64>()
+    };
+
+    let calls = total(&data.calls);
+
+    (calls > 0).then(|| total(&data.puts) as f64 / calls as f64)
+}
+
+/// Draws `y_labels` down the left of `area` and `x_labels` along its bottom,
+/// returning the area left for the chart. The first and last x labels are
+/// aligned to the edges of the chart, and the rest centered on their value
+fn render_axis_labels(
+    area: Rect,
+    buf: &mut Buffer,
+    bounds: [[f64; 2]; 2],
+    x_labels: &[(f64, String)],
+    y_labels: &[(f64, String)],
+) -> Rect {
+    let [x_bounds, y_bounds] = bounds;
+
+    let y_area = Rect {
+        width: Y_LABEL_WIDTH,
+        height: area.height - 1,
+        ..area
+    };
+
+    for (value, label) in y_labels {
+        let pct = (value - y_bounds[0]) / (y_bounds[1] - y_bounds[0]);
+        let dy = (pct * (y_area.height - 1) as f64).round() as u16;
+
+        buf.set_span(
+            y_area.left(),
+            y_area.bottom() - 1 - dy,
+            &Span::styled(
+                format!("{:>1$}", label, Y_LABEL_WIDTH as usize - 1),
+                style().fg(THEME.text_normal()),
+            ),
+            y_area.width,
+        );
+    }
+
+    let mut chart_area = add_padding(area, Y_LABEL_WIDTH, PaddingDirection::Left);
+
+    let x_labels_y = chart_area.bottom() - 1;
+
+    for (idx, (value, label)) in x_labels.iter().enumerate() {
+        let pct = (value - x_bounds[0]) / (x_bounds[1] - x_bounds[0]);
+        let x = chart_area.left() + (pct * (chart_area.width - 1) as f64).round() as u16;
+
+        let x = if idx == 0 {
+            x
+        } else if idx == x_labels.len() - 1 {
+            x.saturating_sub(label.len() as u16 - 1)
+        } else {
+            x.saturating_sub(label.len() as u16 / 2)
+        };
+
+        buf.set_span(
+            x,
+            x_labels_y,
+            &Span::styled(label.as_str(), style().fg(THEME.text_normal())),
+            chart_area.right().saturating_sub(x),
+        );
+    }
+
+    chart_area.height -= 1;
+
+    chart_area
+}
+
+/// Draws the error of a failing fetch over the bottom border of the panel
+fn render_error(area: Rect, buf: &mut Buffer, state: &OptionsState) {
+    let Some(error) = state.error() else {
+        return;
+    };
+
+    if area.height < 2 || area.width < 6 {
+        return;
+    }
+
+    buf.set_stringn(
+        area.x + 2,
+        area.bottom() - 1,
+        format!(" {} ", error),
+        area.width as usize - 4,
+        style().fg(THEME.loss()),
+    );
+}
+
+/// Braille canvas with its axes drawn on the left and bottom
+fn chart_canvas<F>(bounds: [[f64; 2]; 2], painter: F) -> Canvas<'static, F>
+where
+    F: Fn(&mut canvas::Context),
+{
+    Canvas::default()
+        .background_color(THEME.background())
+        .block(
+            Block::default()
+                .style(style())
+                .borders(Borders::LEFT | Borders::BOTTOM)
+                .border_style(style().fg(THEME.border_axis())),
+        )
+        .marker(Marker::Braille)
+        .x_bounds(bounds[0])
+        .y_bounds(bounds[1])
+        .paint(painter)
+}
+
 pub struct OptionsWidget {}
 
 impl StatefulWidget for OptionsWidget {
@@ -249,16 +791,19 @@ impl CachableWidget<OptionsState> for OptionsWidget {
 
     fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut OptionsState) {
         block::new(" Options ").render(area, buf);
+        render_error(area, buf, state);
         area = add_padding(area, 1, PaddingDirection::All);
 
         // chunks[0] - call / put selector
         // chunks[1] - option info
-        // chunks[2] - remainder (date selector | option selector)
+        // chunks[2] - option greeks
+        // chunks[3] - remainder (date selector | option selector)
         let mut chunks: Vec<Rect> = Layout::default()
             .constraints(
                 [
                     Constraint::Length(2),
                     Constraint::Length(8),
+                    Constraint::Length(4),
                     Constraint::Min(0),
                 ]
                 .as_ref(),
@@ -313,7 +858,7 @@ impl CachableWidget<OptionsState> for OptionsWidget {
         let mut selector_chunks: Vec<Rect> = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Length(12), Constraint::Min(0)].as_ref())
-            .split(chunks[2])
+            .split(chunks[3])
             .to_vec();
 
         // Draw date selector
@@ -371,15 +916,36 @@ impl CachableWidget<OptionsState> for OptionsWidget {
                     &data.puts[..]
                 };
 
+                let show_greeks = *SHOW_GREEKS.read();
+
                 let rows = selected_data
                     .iter()
                     .map(|d| {
-                        Row::new(vec![
+                        let mut cells = vec![
                             Cell::from(format!("{: <7.2}", d.strike)),
                             Cell::from(format!("{: <7.2}", d.last_price)),
                             Cell::from(format!("{: >7.2}%", d.percent_change)),
-                        ])
-                        .style(style().fg(if d.percent_change >= 0.0 {
+                        ];
+
+                        if show_greeks {
+                            let greeks = state.greeks(state.selected_type, d);
+
+                            cells.extend(
+                                [
+                                    greeks.map(|g| format!("{:.3}", g.delta)),
+                                    greeks.map(|g| format!("{:.4}", g.gamma)),
+                                    greeks.map(|g| format!("{:.3}", g.theta)),
+                                    greeks.map(|g| format!("{:.3}", g.vega)),
+                                    greeks.map(|g| format!("{:.3}", g.rho)),
+                                ]
+                                .into_iter()
+                                .map(|greek| {
+                                    Cell::from(format!("{: >7}", greek.as_deref().unwrap_or("-")))
+                                }),
+                            );
+                        }
+
+                        Row::new(cells).style(style().fg(if d.percent_change >= 0.0 {
                             THEME.profit()
                         } else {
                             THEME.loss()
@@ -387,29 +953,50 @@ impl CachableWidget<OptionsState> for OptionsWidget {
                     })
                     .collect::<Vec<_>>();
 
-                let header = Row::new(vec!["Strike", "Price", "% Change"])
+                let (header, widths) = if show_greeks {
+                    (
+                        vec![
+                            "Strike", "Price", "% Change", "  Delta", "  Gamma", "  Theta",
+                            "   Vega", "    Rho",
+                        ],
+                        vec![
+                            Constraint::Length(8),
+                            Constraint::Length(8),
+                            Constraint::Length(8),
+                            Constraint::Length(7),
+                            Constraint::Length(7),
+                            Constraint::Length(7),
+                            Constraint::Length(7),
+                            Constraint::Length(7),
+                        ],
+                    )
+                } else {
+                    (
+                        vec!["Strike", "Price", "% Change"],
+                        vec![
+                            Constraint::Length(8),
+                            Constraint::Length(8),
+                            Constraint::Length(8),
+                        ],
+                    )
+                };
+
+                let header = Row::new(header)
                     .style(style().fg(THEME.text_secondary()))
                     .bottom_margin(1);
-                let table = Table::new(
-                    rows,
-                    [
-                        Constraint::Length(8),
-                        Constraint::Length(8),
-                        Constraint::Min(0),
-                    ],
-                )
-                .header(header)
-                .style(style().fg(THEME.text_normal()))
-                .highlight_style(
-                    style()
-                        .bg(if state.selection_mode == SelectionMode::Options {
-                            THEME.highlight_focused()
-                        } else {
-                            THEME.highlight_unfocused()
-                        })
-                        .fg(THEME.text_normal()),
-                )
-                .column_spacing(2);
+                let table = Table::new(rows, widths)
+                    .header(header)
+                    .style(style().fg(THEME.text_normal()))
+                    .highlight_style(
+                        style()
+                            .bg(if state.selection_mode == SelectionMode::Options {
+                                THEME.highlight_focused()
+                            } else {
+                                THEME.highlight_unfocused()
+                            })
+                            .fg(THEME.text_normal()),
+                    )
+                    .column_spacing(2);
 
                 let mut table_state = TableState::default();
                 if let Some(idx) = state.selected_option {
@@ -541,5 +1128,1312 @@ impl CachableWidget<OptionsState> for OptionsWidget {
                 }
             }
         }
+
+        // Draw selected option greeks
+        {
+            chunks[2] = add_padding(chunks[2], 1, PaddingDirection::Left);
+            chunks[2] = add_padding(chunks[2], 1, PaddingDirection::Right);
+
+            Block::default()
+                .style(style().fg(THEME.border_secondary()))
+                .borders(Borders::BOTTOM)
+                .render(chunks[2], buf);
+
+            chunks[2] = add_padding(chunks[2], 1, PaddingDirection::Bottom);
+
+            let option = state.selected_option.and_then(|idx| {
+                let data = state.data()?;
+
+                if state.selected_type == OptionType::Call {
+                    data.calls.get(idx)
+                } else {
+                    data.puts.get(idx)
+                }
+            });
+
+            if let Some(option) = option {
+                let greeks = state.greeks(state.selected_type, option);
+
+                let mut columns: Vec<Rect> = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Length(20), Constraint::Length(20)].as_ref())
+                    .split(chunks[2])
+                    .to_vec();
+
+                columns[1] = add_padding(columns[1], 2, PaddingDirection::Left);
+
+                // Values are right aligned to the same column as those above
+                let line = |label: &str, value: Option<String>, width: usize| {
+                    let value = value.unwrap_or_else(|| "-".to_string());
+
+                    Line::from(Span::styled(
+                        format!(
+                            "{}:{}{}",
+                            label,
+                            " ".repeat(width.saturating_sub(label.len() + 1 + value.len())),
+                            value
+                        ),
+                        style(),
+                    ))
+                };
+
+                let column_0 = vec![
+                    line("Delta", greeks.map(|g| format!("{:.3}", g.delta)), 15),
+                    line("Gamma", greeks.map(|g| format!("{:.4}", g.gamma)), 15),
+                    line("Theta", greeks.map(|g| format!("{:.3}", g.theta)), 15),
+                ];
+
+                let column_1 = vec![
+                    line("Vega", greeks.map(|g| format!("{:.3}", g.vega)), 18),
+                    line("Rho", greeks.map(|g| format!("{:.3}", g.rho)), 18),
+                    line(
+                        "Days Left",
+                        state
+                            .years_to_expiration()
+                            .map(|years| format!("{:.1}", years * 365.0)),
+                        18,
+                    ),
+                ];
+
+                Paragraph::new(column_0)
+                    .style(style().fg(THEME.text_normal()))
+                    .render(columns[0], buf);
+                Paragraph::new(column_1)
+                    .style(style().fg(THEME.text_normal()))
+                    .render(columns[1], buf);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum InterestMetric {
+    OpenInterest,
+    Volume,
+}
+
+impl InterestMetric {
+    fn label(self) -> &'static str {
+        match self {
+            InterestMetric::OpenInterest => "Open Interest",
+            InterestMetric::Volume => "Volume",
+        }
+    }
+
+    fn value(self, contract: &OptionsContract) -> Option<u64> {
+        match self {
+            InterestMetric::OpenInterest => contract.open_interest,
+            InterestMetric::Volume => contract.volume,
+        }
+    }
+}
+
+/// Columns of each side of the options chain, mirrored so bid and ask sit
+/// next to the strike
+const CALL_COLUMNS: [ChainColumn; 6] = [
+    ChainColumn::ImpliedVol,
+    ChainColumn::OpenInterest,
+    ChainColumn::Volume,
+    ChainColumn::Last,
+    ChainColumn::Bid,
+    ChainColumn::Ask,
+];
+const PUT_COLUMNS: [ChainColumn; 6] = [
+    ChainColumn::Bid,
+    ChainColumn::Ask,
+    ChainColumn::Last,
+    ChainColumn::Volume,
+    ChainColumn::OpenInterest,
+    ChainColumn::ImpliedVol,
+];
+
+#[derive(Clone, Copy)]
+enum ChainColumn {
+    ImpliedVol,
+    OpenInterest,
+    Volume,
+    Last,
+    Bid,
+    Ask,
+}
+
+impl ChainColumn {
+    fn label(self) -> &'static str {
+        match self {
+            ChainColumn::ImpliedVol => "IV",
+            ChainColumn::OpenInterest => "Open Int",
+            ChainColumn::Volume => "Volume",
+            ChainColumn::Last => "Last",
+            ChainColumn::Bid => "Bid",
+            ChainColumn::Ask => "Ask",
+        }
+    }
+
+    fn width(self) -> u16 {
+        match self {
+            ChainColumn::ImpliedVol => 8,
+            ChainColumn::OpenInterest | ChainColumn::Volume => 10,
+            ChainColumn::Last | ChainColumn::Bid | ChainColumn::Ask => 9,
+        }
+    }
+
+    fn value(self, contract: &OptionsContract) -> Option<String> {
+        match self {
+            ChainColumn::ImpliedVol => contract
+                .implied_volatility
+                .map(|iv| format!("{:.1}%", iv * 100.0)),
+            ChainColumn::OpenInterest => contract.open_interest.map(|oi| oi.to_string()),
+            ChainColumn::Volume => contract.volume.map(|volume| volume.to_string()),
+            ChainColumn::Last => Some(format!("{:.2}", contract.last_price)),
+            ChainColumn::Bid => contract.bid.map(|bid| format!("{:.2}", bid)),
+            ChainColumn::Ask => contract.ask.map(|ask| format!("{:.2}", ask)),
+        }
+    }
+}
+
+/// Calls and puts of the selected expiration date side by side, with the
+/// selected strike kept in the middle and in the money contracts shaded
+pub struct OptionsChainWidget {}
+
+impl StatefulWidget for OptionsChainWidget {
+    type State = OptionsState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        block::new(" Options Chain - <ESC> to go back ").render(area, buf);
+        render_error(area, buf, state);
+        area = add_padding(area, 1, PaddingDirection::All);
+        area = add_padding(area, 1, PaddingDirection::Left);
+        area = add_padding(area, 1, PaddingDirection::Right);
+
+        // layout[0] - Expiration date / key hints
+        // layout[1] - Calls / puts labels
+        // layout[2] - Chain
+        let layout = Layout::default()
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        // Draw expiration date
+        {
+            let expiration = state
+                .exp_date
+                .and_then(|d| NaiveDateTime::from_timestamp_opt(d, 0))
+                .map(|d| d.date().format("%b-%d-%y").to_string())
+                .unwrap_or_default();
+
+            let days_left = state
+                .years_to_expiration()
+                .map(|years| format!(" ({:.1} days left)", years * 365.0))
+                .unwrap_or_default();
+
+            let market_price = state
+                .quote
+                .as_ref()
+                .map(|quote| format!("{:.2}", quote.regular_market_price))
+                .unwrap_or_default();
+
+            Paragraph::new(Line::from(vec![
+                Span::styled("Expiration: ", style().fg(THEME.text_secondary())),
+                Span::styled(
+                    format!("{}{}", expiration, days_left),
+                    style().fg(THEME.text_normal()),
+                ),
+                Span::styled("   Underlying: ", style().fg(THEME.text_secondary())),
+                Span::styled(market_price, style().fg(THEME.text_normal())),
+            ]))
+            .render(layout[0], buf);
+
+            Paragraph::new(vec![
+                Line::from("<Up / Down>: strike  <Left / Right>: expiration"),
+                Line::from("<Tab>: calls / puts  b / s: buy / sell  <Enter>: strategy"),
+            ])
+            .style(style().fg(THEME.gray()))
+            .alignment(Alignment::Right)
+            .render(layout[0], buf);
+        }
+
+        let straddles = state.straddles();
+
+        if straddles.is_empty() {
+            return;
+        }
+
+        let side_width: u16 = CALL_COLUMNS.iter().map(|column| column.width()).sum();
+        let strike_width = 12;
+
+        let widths = CALL_COLUMNS
+            .iter()
+            .map(|column| column.width())
+            .chain([strike_width])
+            .chain(PUT_COLUMNS.iter().map(|column| column.width()))
+            .collect::<Vec<_>>();
+
+        let x_offset = area.width.saturating_sub(side_width * 2 + strike_width) / 2;
+
+        // Draw calls / puts labels
+        {
+            let labels_area = add_padding(layout[1], x_offset, PaddingDirection::Left);
+
+            let labels: Vec<Rect> = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(side_width),
+                    Constraint::Length(strike_width),
+                    Constraint::Length(side_width),
+                ])
+                .split(labels_area)
+                .to_vec();
+
+            // The side contracts are bought / sold from is underlined
+            let label_style = |option_type: OptionType| {
+                if state.selected_type == option_type {
+                    Modifier::BOLD | Modifier::UNDERLINED
+                } else {
+                    Modifier::empty()
+                }
+            };
+
+            Paragraph::new(Span::styled(
+                "Calls",
+                style()
+                    .fg(THEME.profit())
+                    .add_modifier(label_style(OptionType::Call)),
+            ))
+            .alignment(Alignment::Center)
+            .render(labels[0], buf);
+            Paragraph::new(Span::styled(
+                "Puts",
+                style()
+                    .fg(THEME.loss())
+                    .add_modifier(label_style(OptionType::Put)),
+            ))
+            .alignment(Alignment::Center)
+            .render(labels[2], buf);
+        }
+
+        // Draw chain
+        {
+            let chain_area = add_padding(layout[2], x_offset, PaddingDirection::Left);
+
+            // Values are right aligned with a space after, so shading runs
+            // unbroken across the side
+            let side_cells = |columns: &[ChainColumn], contract: Option<&OptionsContract>| {
+                let shading = if contract.and_then(|c| c.in_the_money) == Some(true) {
+                    style().bg(THEME.highlight_unfocused())
+                } else {
+                    style()
+                };
+
+                columns
+                    .iter()
+                    .map(|column| {
+                        let value = contract.and_then(|c| column.value(c)).unwrap_or_default();
+
+                        Cell::from(format!("{:>1$} ", value, column.width() as usize - 1))
+                            .style(shading)
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let rows = straddles.iter().map(|straddle| {
+                let strike = Cell::from(format!(
+                    "{:^1$}",
+                    format!("{:.2}", straddle.strike),
+                    strike_width as usize
+                ))
+                .style(
+                    style()
+                        .fg(THEME.text_secondary())
+                        .add_modifier(Modifier::BOLD),
+                );
+
+                let mut cells = side_cells(&CALL_COLUMNS, straddle.call);
+                cells.push(strike);
+                cells.extend(side_cells(&PUT_COLUMNS, straddle.put));
+
+                Row::new(cells)
+            });
+
+            let side_header = |columns: &[ChainColumn]| {
+                columns
+                    .iter()
+                    .map(|column| format!("{:>1$} ", column.label(), column.width() as usize - 1))
+                    .collect::<Vec<_>>()
+            };
+
+            let mut header = side_header(&CALL_COLUMNS);
+            header.push(format!("{:^1$}", "Strike", strike_width as usize));
+            header.extend(side_header(&PUT_COLUMNS));
+
+            let header = Row::new(header)
+                .style(style().fg(THEME.text_secondary()))
+                .bottom_margin(1);
+
+            let table = Table::new(
+                rows,
+                widths
+                    .into_iter()
+                    .map(Constraint::Length)
+                    .collect::<Vec<_>>(),
+            )
+            .header(header)
+            .style(style().fg(THEME.text_normal()))
+            .highlight_style(style().bg(THEME.highlight_focused()))
+            .column_spacing(0);
+
+            // Keep the selected strike in the middle of the rows shown
+            let selected = state.selected_strike.unwrap_or_default();
+            let shown = chain_area.height.saturating_sub(2) as usize;
+            let offset = selected
+                .saturating_sub(shown / 2)
+                .min(straddles.len().saturating_sub(shown));
+
+            let mut table_state = TableState::default().with_offset(offset);
+            table_state.select(Some(selected));
+
+            <Table as StatefulWidget>::render(table, chain_area, buf, &mut table_state);
+        }
+    }
+}
+
+/// Legs of the strategy being built, with its payoff at expiration charted
+/// against the price of the underlying
+pub struct OptionsStrategyWidget {}
+
+impl StatefulWidget for OptionsStrategyWidget {
+    type State = OptionsState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        block::new(" Strategy - <ESC> to go back ").render(area, buf);
+        render_error(area, buf, state);
+        area = add_padding(area, 1, PaddingDirection::All);
+        area = add_padding(area, 1, PaddingDirection::Left);
+        area = add_padding(area, 1, PaddingDirection::Right);
+
+        // layout[0] - Legs / summary
+        // layout[1] - Payoff chart
+        let mut layout: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(48), Constraint::Min(0)])
+            .split(area)
+            .to_vec();
+
+        let strategy = &state.strategy;
+
+        // Draw legs
+        {
+            let name = if strategy.name.is_empty() {
+                Span::styled("Unsaved strategy", style().fg(THEME.gray()))
+            } else {
+                Span::styled(
+                    strategy.name.as_str(),
+                    style()
+                        .fg(THEME.text_primary())
+                        .add_modifier(Modifier::BOLD),
+                )
+            };
+
+            Paragraph::new(name).render(layout[0], buf);
+            layout[0] = add_padding(layout[0], 2, PaddingDirection::Top);
+
+            let hints = Paragraph::new(vec![
+                Line::from("<Up / Down>: leg  d: remove  D: clear"),
+                Line::from("w: save  <Tab>: open next saved"),
+            ])
+            .style(style().fg(THEME.gray()));
+
+            let hints_area = Rect {
+                y: layout[0].bottom().saturating_sub(2),
+                height: 2.min(layout[0].height),
+                ..layout[0]
+            };
+            hints.render(hints_area, buf);
+            layout[0].height = layout[0].height.saturating_sub(3);
+
+            if strategy.legs.is_empty() {
+                Paragraph::new(vec![
+                    Line::from("Add legs from the options chain ('f' from"),
+                    Line::from("the options pane) with 'b' to buy and 's' to"),
+                    Line::from("sell the selected contract"),
+                ])
+                .style(style().fg(THEME.text_normal()))
+                .render(layout[0], buf);
+
+                return;
+            }
+
+            let rows = strategy.legs.iter().map(|leg| {
+                let (side, color) = match leg.side {
+                    Side::Long => ("Long", THEME.profit()),
+                    Side::Short => ("Short", THEME.loss()),
+                };
+
+                Row::new(vec![
+                    Cell::from(Span::styled(side, style().fg(color))),
+                    Cell::from(format!("{:>3}", leg.quantity)),
+                    Cell::from(match leg.option_type {
+                        OptionType::Call => "Call",
+                        OptionType::Put => "Put",
+                    }),
+                    Cell::from(format!("{:>8.2}", leg.strike)),
+                    Cell::from(leg.expiration.format("%b-%d-%y").to_string()),
+                    Cell::from(format!("{:>7.2}", leg.price)),
+                ])
+            });
+
+            let header = Row::new(vec!["Side", "Qty", "Type", "  Strike", "Expiry", "Premium"])
+                .style(style().fg(THEME.text_secondary()))
+                .bottom_margin(1);
+
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(5),
+                    Constraint::Length(3),
+                    Constraint::Length(4),
+                    Constraint::Length(8),
+                    Constraint::Length(9),
+                    Constraint::Length(7),
+                ],
+            )
+            .header(header)
+            .style(style().fg(THEME.text_normal()))
+            .highlight_style(style().bg(THEME.highlight_focused()))
+            .column_spacing(2);
+
+            let mut legs_area = layout[0];
+            legs_area.height = (strategy.legs.len() as u16 + 2).min(layout[0].height);
+
+            let mut table_state = TableState::default();
+            table_state.select(Some(state.selected_leg));
+
+            <Table as StatefulWidget>::render(table, legs_area, buf, &mut table_state);
+
+            layout[0] = add_padding(layout[0], legs_area.height + 1, PaddingDirection::Top);
+        }
+
+        // Draw summary
+        {
+            let net_debit = strategy.net_debit();
+
+            let limit = |value: Option<f64>| {
+                value
+                    .map(|value| format!("{:.2}", value))
+                    .unwrap_or_else(|| "Unlimited".to_string())
+            };
+
+            let break_evens = strategy
+                .break_evens()
+                .iter()
+                .map(|price| format!("{:.2}", price))
+                .collect::<Vec<_>>();
+
+            let line = |label: &str, value: String| {
+                Line::from(vec![
+                    Span::styled(format!("{:<13}", label), style().fg(THEME.text_secondary())),
+                    Span::styled(value, style().fg(THEME.text_normal())),
+                ])
+            };
+
+            let summary = vec![
+                line(
+                    if net_debit >= 0.0 {
+                        "Net Debit:"
+                    } else {
+                        "Net Credit:"
+                    },
+                    format!("{:.2}", net_debit.abs()),
+                ),
+                line("Max Profit:", limit(strategy.max_profit())),
+                line("Max Loss:", limit(strategy.max_loss().map(f64::abs))),
+                line(
+                    "Break Even:",
+                    if break_evens.is_empty() {
+                        "None".to_string()
+                    } else {
+                        break_evens.join(", ")
+                    },
+                ),
+            ];
+
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(style().fg(THEME.border_secondary()))
+                .title(Span::styled(" Summary ", style().fg(THEME.text_normal())))
+                .render(layout[0], buf);
+            layout[0] = add_padding(layout[0], 1, PaddingDirection::Top);
+
+            Paragraph::new(summary)
+                .wrap(Wrap { trim: false })
+                .render(layout[0], buf);
+        }
+
+        // Draw payoff chart
+        {
+            let mut chart_area = add_padding(layout[1], 2, PaddingDirection::Left);
+
+            if chart_area.width <= 12 || chart_area.height <= 4 {
+                return;
+            }
+
+            let market_price = state.quote.as_ref().map(|quote| quote.regular_market_price);
+
+            let strikes = strategy.strikes();
+            let low = strikes
+                .iter()
+                .chain(&market_price)
+                .copied()
+                .fold(f64::MAX, f64::min);
+            let high = strikes
+                .iter()
+                .chain(&market_price)
+                .copied()
+                .fold(f64::MIN, f64::max);
+            let margin = (high - low).max(high * 0.05) / 2.0;
+
+            let x_bounds = [(low - margin).max(0.0), high + margin];
+
+            let points = [x_bounds[0]]
+                .into_iter()
+                .chain(strikes)
+                .chain([x_bounds[1]])
+                .map(|price| (price, strategy.payoff(price)))
+                .collect::<Vec<_>>();
+
+            let min = points.iter().map(|(_, y)| *y).fold(0.0, f64::min);
+            let max = points.iter().map(|(_, y)| *y).fold(0.0, f64::max);
+            let padding = ((max - min) * 0.1).max(1.0);
+
+            let y_bounds = [min - padding, max + padding];
+
+            // Split where the payoff crosses zero, so profit and loss are colored
+            // separately
+            let segments = points
+                .windows(2)
+                .flat_map(|pair| {
+                    let [(x0, y0), (x1, y1)] = [pair[0], pair[1]];
+
+                    if y0 * y1 < 0.0 {
+                        let x = x0 + (x1 - x0) * y0 / (y0 - y1);
+
+                        vec![(x0, y0, x, 0.0), (x, 0.0, x1, y1)]
+                    } else {
+                        vec![(x0, y0, x1, y1)]
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let x_labels = [
+                x_bounds[0],
+                market_price.unwrap_or(x_bounds[0]),
+                x_bounds[1],
+            ]
+            .map(|value| (value, format!("{:.2}", value)));
+            let y_labels =
+                [y_bounds[0], 0.0, y_bounds[1]].map(|value| (value, format!("{:.2}", value)));
+
+            chart_area =
+                render_axis_labels(chart_area, buf, [x_bounds, y_bounds], &x_labels, &y_labels);
+
+            chart_canvas([x_bounds, y_bounds], move |ctx| {
+                ctx.draw(&canvas::Line {
+                    x1: x_bounds[0],
+                    y1: 0.0,
+                    x2: x_bounds[1],
+                    y2: 0.0,
+                    color: THEME.gray(),
+                });
+
+                if let Some(price) = market_price {
+                    ctx.draw(&canvas::Line {
+                        x1: price,
+                        y1: y_bounds[0],
+                        x2: price,
+                        y2: y_bounds[1],
+                        color: THEME.text_secondary(),
+                    });
+                }
+
+                ctx.layer();
+
+                for (x1, y1, x2, y2) in segments.iter().copied() {
+                    ctx.draw(&canvas::Line {
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        color: if y1 + y2 >= 0.0 {
+                            THEME.profit()
+                        } else {
+                            THEME.loss()
+                        },
+                    });
+                }
+            })
+            .render(chart_area, buf);
+        }
+    }
+}
+
+/// Implied volatility of the selected expiration date by strike, beside the at
+/// the money implied volatility of every expiration date
+pub struct OptionsVolatilityWidget {}
+
+impl StatefulWidget for OptionsVolatilityWidget {
+    type State = OptionsState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        block::new(" Volatility - <ESC> to go back ").render(area, buf);
+        render_error(area, buf, state);
+        area = add_padding(area, 1, PaddingDirection::All);
+        area = add_padding(area, 1, PaddingDirection::Left);
+        area = add_padding(area, 1, PaddingDirection::Right);
+
+        // layout[0] - Expiration date / key hints
+        // layout[1] - Smile / term structure
+        let layout = Layout::default()
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(area);
+
+        let market_price = state.quote.as_ref().map(|quote| quote.regular_market_price);
+
+        // Draw expiration date
+        {
+            let expiration = state
+                .exp_date
+                .and_then(|d| NaiveDateTime::from_timestamp_opt(d, 0))
+                .map(|d| d.date().format("%b-%d-%y").to_string())
+                .unwrap_or_default();
+
+            let atm_iv = state
+                .data()
+                .and_then(|data| state.atm_implied_volatility(data))
+                .map(|iv| format!("{:.1}%", iv * 100.0))
+                .unwrap_or_else(|| "-".to_string());
+
+            Paragraph::new(Line::from(vec![
+                Span::styled("Expiration: ", style().fg(THEME.text_secondary())),
+                Span::styled(expiration, style().fg(THEME.text_normal())),
+                Span::styled("   Underlying: ", style().fg(THEME.text_secondary())),
+                Span::styled(
+                    market_price
+                        .map(|price| format!("{:.2}", price))
+                        .unwrap_or_default(),
+                    style().fg(THEME.text_normal()),
+                ),
+                Span::styled("   ATM IV: ", style().fg(THEME.text_secondary())),
+                Span::styled(atm_iv, style().fg(THEME.text_normal())),
+            ]))
+            .render(layout[0], buf);
+
+            Paragraph::new("<Left / Right>: expiration")
+                .style(style().fg(THEME.gray()))
+                .alignment(Alignment::Right)
+                .render(layout[0], buf);
+        }
+
+        // charts[0] - Smile
+        // charts[1] - Term structure
+        let charts = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(layout[1]);
+
+        let iv_label = |iv: f64| format!("{:.1}%", iv * 100.0);
+
+        // Draw smile
+        {
+            let calls = state.smile(OptionType::Call);
+            let puts = state.smile(OptionType::Put);
+
+            let legend = Line::from(vec![
+                Span::styled("Calls", style().fg(THEME.profit())),
+                Span::styled("  Puts", style().fg(THEME.loss())),
+            ]);
+
+            if let Some(chart_area) = render_volatility_panel(
+                " IV Smile ",
+                legend,
+                calls.is_empty() && puts.is_empty(),
+                charts[0],
+                buf,
+            ) {
+                let x_bounds = padded_bounds(calls.iter().chain(&puts).map(|(x, _)| *x), 0.0);
+                let y_bounds = padded_bounds(calls.iter().chain(&puts).map(|(_, y)| *y), 0.1);
+
+                let mut x_labels = vec![(x_bounds[0], format!("{:.2}", x_bounds[0]))];
+                x_labels.extend(
+                    market_price
+                        .filter(|price| *price > x_bounds[0] && *price < x_bounds[1])
+                        .map(|price| (price, format!("{:.2}", price))),
+                );
+                x_labels.push((x_bounds[1], format!("{:.2}", x_bounds[1])));
+
+                let y_labels = y_bounds.map(|iv| (iv, iv_label(iv)));
+
+                let chart_area =
+                    render_axis_labels(chart_area, buf, [x_bounds, y_bounds], &x_labels, &y_labels);
+
+                chart_canvas([x_bounds, y_bounds], move |ctx| {
+                    if let Some(price) = market_price {
+                        ctx.draw(&canvas::Line {
+                            x1: price,
+                            y1: y_bounds[0],
+                            x2: price,
+                            y2: y_bounds[1],
+                            color: THEME.text_secondary(),
+                        });
+                    }
+
+                    ctx.layer();
+
+                    for (points, color) in [(&puts, THEME.loss()), (&calls, THEME.profit())] {
+                        draw_series(ctx, points, color);
+                    }
+                })
+                .render(chart_area, buf);
+            }
+        }
+
+        // Draw term structure
+        {
+            let term_structure = state.term_structure();
+
+            let selected_days = state.years_to_expiration().map(|years| years * 365.0);
+
+            let loaded = state
+                .exp_dates
+                .iter()
+                .filter(|date| state.data.contains_key(date))
+                .count();
+
+            let legend = if loaded < state.exp_dates.len() {
+                Line::from(Span::styled(
+                    format!(
+                        "Loading expirations, {} of {}",
+                        loaded,
+                        state.exp_dates.len()
+                    ),
+                    style().fg(THEME.gray()),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    "Days until expiration",
+                    style().fg(THEME.text_primary()),
+                ))
+            };
+
+            if let Some(chart_area) = render_volatility_panel(
+                " ATM Term Structure ",
+                legend,
+                term_structure.is_empty(),
+                charts[1],
+                buf,
+            ) {
+                let x_bounds = padded_bounds(term_structure.iter().map(|(x, _)| *x), 0.0);
+                let y_bounds = padded_bounds(term_structure.iter().map(|(_, y)| *y), 0.1);
+
+                let mut x_labels = vec![(x_bounds[0], format!("{:.0}d", x_bounds[0]))];
+                x_labels.extend(
+                    selected_days
+                        .filter(|days| *days > x_bounds[0] && *days < x_bounds[1])
+                        .map(|days| (days, format!("{:.0}d", days))),
+                );
+                x_labels.push((x_bounds[1], format!("{:.0}d", x_bounds[1])));
+
+                let y_labels = y_bounds.map(|iv| (iv, iv_label(iv)));
+
+                let chart_area =
+                    render_axis_labels(chart_area, buf, [x_bounds, y_bounds], &x_labels, &y_labels);
+
+                chart_canvas([x_bounds, y_bounds], move |ctx| {
+                    if let Some(days) = selected_days {
+                        ctx.draw(&canvas::Line {
+                            x1: days,
+                            y1: y_bounds[0],
+                            x2: days,
+                            y2: y_bounds[1],
+                            color: THEME.text_secondary(),
+                        });
+                    }
+
+                    ctx.layer();
+
+                    draw_series(ctx, &term_structure, THEME.text_primary());
+                })
+                .render(chart_area, buf);
+            }
+        }
+    }
+}
+
+/// Draws the titled panel of a volatility chart, returning the area left for
+/// the chart if there's data and room to draw it
+fn render_volatility_panel(
+    title: &str,
+    legend: Line,
+    empty: bool,
+    mut area: Rect,
+    buf: &mut Buffer,
+) -> Option<Rect> {
+    area = add_padding(area, 1, PaddingDirection::Right);
+
+    Block::default()
+        .borders(Borders::TOP)
+        .border_style(style().fg(THEME.border_secondary()))
+        .title(Span::styled(title, style().fg(THEME.text_normal())))
+        .render(area, buf);
+    area = add_padding(area, 1, PaddingDirection::Top);
+
+    Paragraph::new(legend).render(area, buf);
+    area = add_padding(area, 2, PaddingDirection::Top);
+
+    if empty {
+        Paragraph::new("No implied volatility to chart")
+            .style(style().fg(THEME.text_normal()))
+            .render(area, buf);
+
+        return None;
+    }
+
+    (area.width > Y_LABEL_WIDTH + 2 && area.height > 4).then_some(area)
+}
+
+/// Lowest and highest of `values`, widened by `padding` of their range, or by
+/// 1 either side if they're all the same
+fn padded_bounds(values: impl Iterator<Item = f64>, padding: f64) -> [f64; 2] {
+    let (min, max) = values.fold((f64::MAX, f64::MIN), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+
+    if min >= max {
+        [min - 1.0, max + 1.0]
+    } else {
+        let padding = (max - min) * padding;
+
+        [min - padding, max + padding]
+    }
+}
+
+/// Line through `points` with a dot on each, so lone points still show
+fn draw_series(ctx: &mut canvas::Context, points: &[(f64, f64)], color: Color) {
+    for pair in points.windows(2) {
+        ctx.draw(&canvas::Line {
+            x1: pair[0].0,
+            y1: pair[0].1,
+            x2: pair[1].0,
+            y2: pair[1].1,
+            color,
+        });
+    }
+
+    ctx.draw(&canvas::Points {
+        coords: points,
+        color,
+    });
+}
+
+/// Open interest or volume of the calls and puts of the selected expiration
+/// date as bars either side of their strike, along with the max pain strike and
+/// put / call ratios
+pub struct OptionsInterestWidget {}
+
+impl StatefulWidget for OptionsInterestWidget {
+    type State = OptionsState;
+
+    fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        block::new(" Open Interest - <ESC> to go back ").render(area, buf);
+        render_error(area, buf, state);
+        area = add_padding(area, 1, PaddingDirection::All);
+        area = add_padding(area, 1, PaddingDirection::Left);
+        area = add_padding(area, 1, PaddingDirection::Right);
+
+        // layout[0] - Expiration date / max pain / key hints
+        // layout[1] - Calls / puts labels
+        // layout[2] - Bars
+        let layout = Layout::default()
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let max_pain = state.max_pain();
+        let metric = state.interest_metric;
+
+        // Draw expiration date
+        {
+            let expiration = state
+                .exp_date
+                .and_then(|d| NaiveDateTime::from_timestamp_opt(d, 0))
+                .map(|d| d.date().format("%b-%d-%y").to_string())
+                .unwrap_or_default();
+
+            let market_price = state
+                .quote
+                .as_ref()
+                .map(|quote| format!("{:.2}", quote.regular_market_price))
+                .unwrap_or_default();
+
+            let ratio = |metric: InterestMetric| {
+                state
+                    .put_call_ratio(metric)
+                    .map(|ratio| format!("{:.2}", ratio))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+
+            Paragraph::new(vec![
+                Line::from(vec![
+                    Span::styled("Expiration: ", style().fg(THEME.text_secondary())),
+                    Span::styled(expiration, style().fg(THEME.text_normal())),
+                    Span::styled("   Underlying: ", style().fg(THEME.text_secondary())),
+                    Span::styled(market_price, style().fg(THEME.text_normal())),
+                ]),
+                Line::from(vec![
+                    Span::styled("Max Pain: ", style().fg(THEME.text_secondary())),
+                    Span::styled(
+                        max_pain
+                            .map(|strike| format!("{:.2}", strike))
+                            .unwrap_or_else(|| "-".to_string()),
+                        style()
+                            .fg(THEME.text_primary())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled("   P/C Open Int: ", style().fg(THEME.text_secondary())),
+                    Span::styled(
+                        ratio(InterestMetric::OpenInterest),
+                        style().fg(THEME.text_normal()),
+                    ),
+                    Span::styled("   P/C Volume: ", style().fg(THEME.text_secondary())),
+                    Span::styled(
+                        ratio(InterestMetric::Volume),
+                        style().fg(THEME.text_normal()),
+                    ),
+                ]),
+            ])
+            .render(layout[0], buf);
+
+            Paragraph::new(vec![
+                Line::from("<Up / Down>: strike  <Left / Right>: expiration"),
+                Line::from("<Tab>: open interest / volume"),
+            ])
+            .style(style().fg(THEME.gray()))
+            .alignment(Alignment::Right)
+            .render(layout[0], buf);
+        }
+
+        let straddles = state.straddles();
+
+        if straddles.is_empty() {
+            return;
+        }
+
+        let strike_width = 12;
+        let side_width = layout[2].width.saturating_sub(strike_width) / 2;
+        let value_width = 9;
+        let bar_width = side_width.saturating_sub(value_width + 2);
+
+        // sides[0] - Calls
+        // sides[1] - Strike
+        // sides[2] - Puts
+        let sides = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(side_width),
+                Constraint::Length(strike_width),
+                Constraint::Length(side_width),
+            ])
+            .split(layout[1]);
+
+        // Draw calls / puts labels
+        {
+            Paragraph::new(Span::styled(
+                format!("Call {}", metric.label()),
+                style().fg(THEME.profit()).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center)
+            .render(sides[0], buf);
+            Paragraph::new(Span::styled("Strike", style().fg(THEME.text_secondary())))
+                .alignment(Alignment::Center)
+                .render(sides[1], buf);
+            Paragraph::new(Span::styled(
+                format!("Put {}", metric.label()),
+                style().fg(THEME.loss()).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center)
+            .render(sides[2], buf);
+        }
+
+        // Draw bars
+        {
+            let value = |contract: Option<&OptionsContract>| contract.and_then(|c| metric.value(c));
+
+            let max = straddles
+                .iter()
+                .flat_map(|straddle| [value(straddle.call), value(straddle.put)])
+                .flatten()
+                .max()
+                .unwrap_or_default();
+
+            // Bars are scaled to the highest value, with any value above zero
+            // getting at least one cell
+            let bar = |value: Option<u64>| {
+                let len = match value {
+                    Some(value) if max > 0 && value > 0 => {
+                        ((value as f64 / max as f64 * bar_width as f64).round() as usize).max(1)
+                    }
+                    _ => 0,
+                };
+
+                "█".repeat(len)
+            };
+
+            let label = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+            // Keep the selected strike in the middle of the rows shown
+            let selected = state.selected_strike.unwrap_or_default();
+            let shown = layout[2].height as usize;
+            let offset = selected
+                .saturating_sub(shown / 2)
+                .min(straddles.len().saturating_sub(shown));
+
+            for (idx, straddle) in straddles.iter().enumerate().skip(offset).take(shown) {
+                let y = layout[2].top() + (idx - offset) as u16;
+
+                let call = value(straddle.call);
+                let put = value(straddle.put);
+
+                let (call_bar, call_label) = (bar(call), label(call));
+                let padding = (side_width as usize)
+                    .saturating_sub(call_bar.chars().count() + call_label.len() + 2);
+
+                let calls = Line::from(vec![
+                    Span::raw(" ".repeat(padding)),
+                    Span::styled(call_label, style().fg(THEME.text_normal())),
+                    Span::raw(" "),
+                    Span::styled(call_bar, style().fg(THEME.profit())),
+                ]);
+
+                let strike_style = if Some(straddle.strike) == max_pain {
+                    style()
+                        .fg(THEME.text_primary())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    style()
+                        .fg(THEME.text_secondary())
+                        .add_modifier(Modifier::BOLD)
+                };
+
+                let strike = Span::styled(
+                    format!(
+                        "{:^1$}",
+                        format!("{:.2}", straddle.strike),
+                        strike_width as usize
+                    ),
+                    if idx == selected {
+                        strike_style.bg(THEME.highlight_focused())
+                    } else {
+                        strike_style
+                    },
+                );
+
+                let puts = Line::from(vec![
+                    Span::raw(" "),
+                    Span::styled(bar(put), style().fg(THEME.loss())),
+                    Span::raw(" "),
+                    Span::styled(label(put), style().fg(THEME.text_normal())),
+                ]);
+
+                buf.set_line(sides[0].left(), y, &calls, side_width);
+                buf.set_span(sides[1].left(), y, &strike, strike_width);
+                buf.set_line(sides[2].left(), y, &puts, side_width);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Strike, open interest, volume and implied volatility
+    type Row = (f64, Option<u64>, Option<u64>, Option<f64>);
+
+    fn contracts(rows: &[Row]) -> Vec<OptionsContract> {
+        rows.iter()
+            .map(
+                |&(strike, open_interest, volume, implied_volatility)| OptionsContract {
+                    strike,
+                    last_price: 1.0,
+                    change: 0.0,
+                    percent_change: 0.0,
+                    volume,
+                    open_interest,
+                    bid: None,
+                    ask: None,
+                    implied_volatility,
+                    in_the_money: None,
+                    currency: None,
+                },
+            )
+            .collect()
+    }
+
+    /// Highest strike first, as the chain is stored
+    fn chain(calls: &[Row], puts: &[Row]) -> OptionsData {
+        OptionsData {
+            expiration_date: 0,
+            calls: contracts(calls),
+            puts: contracts(puts),
+        }
+    }
+
+    fn assert_approx(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    fn example() -> OptionsData {
+        chain(
+            &[
+                (110.0, Some(10), Some(100), Some(0.18)),
+                (100.0, Some(20), Some(50), Some(0.2)),
+                (90.0, Some(5), None, Some(0.24)),
+            ],
+            &[
+                (110.0, Some(5), Some(30), Some(0.22)),
+                (100.0, Some(10), None, Some(0.3)),
+                (90.0, Some(30), Some(20), Some(0.35)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_max_pain() {
+        // Paid out at 110: 20 * 10 + 5 * 20 = 300
+        // Paid out at 100: 5 * 10 + 5 * 10 = 100
+        // Paid out at 90: 5 * 20 + 10 * 10 = 200
+        assert_eq!(max_pain(&example()), Some(100.0));
+    }
+
+    #[test]
+    fn test_max_pain_without_puts() {
+        let calls = [
+            (110.0, Some(10), None, None),
+            (100.0, Some(20), None, None),
+            (90.0, Some(5), None, None),
+        ];
+
+        // Calls pay nothing at the lowest strike
+        assert_eq!(max_pain(&chain(&calls, &[])), Some(90.0));
+    }
+
+    #[test]
+    fn test_max_pain_without_open_interest() {
+        let data = chain(
+            &[(100.0, Some(0), Some(10), None)],
+            &[(100.0, None, Some(10), None)],
+        );
+
+        assert_eq!(max_pain(&data), None);
+        assert_eq!(max_pain(&chain(&[], &[])), None);
+    }
+
+    #[test]
+    fn test_put_call_ratio() {
+        let data = example();
+
+        assert_approx(
+            put_call_ratio(&data, InterestMetric::OpenInterest),
+            45.0 / 35.0,
+        );
+        assert_approx(put_call_ratio(&data, InterestMetric::Volume), 50.0 / 150.0);
+    }
+
+    #[test]
+    fn test_put_call_ratio_edge_cases() {
+        let calls = [(100.0, Some(20), None, None)];
+        assert_approx(
+            put_call_ratio(&chain(&calls, &[]), InterestMetric::OpenInterest),
+            0.0,
+        );
+
+        // No calls to divide by
+        let calls = [(100.0, Some(0), None, None)];
+        let puts = [(100.0, Some(10), None, None)];
+        assert_eq!(
+            put_call_ratio(&chain(&calls, &puts), InterestMetric::OpenInterest),
+            None
+        );
+        assert_eq!(
+            put_call_ratio(&chain(&calls, &puts), InterestMetric::Volume),
+            None
+        );
+    }
+
+    #[test]
+    fn test_atm_implied_volatility() {
+        // Call and put at 100 averaged
+        assert_approx(atm_implied_volatility(&example(), 101.0), 0.25);
+        assert_approx(
+            atm_implied_volatility(&example(), 94.0),
+            (0.24 + 0.35) / 2.0,
+        );
+
+        // Closest strike is only listed as a put
+        let data = chain(
+            &[
+                (110.0, None, None, Some(0.18)),
+                (100.0, None, None, Some(0.2)),
+            ],
+            &[(104.0, None, None, Some(0.4))],
+        );
+        assert_approx(atm_implied_volatility(&data, 103.0), 0.4);
+    }
+
+    #[test]
+    fn test_atm_implied_volatility_missing() {
+        // Unsolved volatility at the money isn't averaged in
+        let data = chain(
+            &[(100.0, None, None, Some(0.0))],
+            &[(100.0, None, None, Some(0.3))],
+        );
+        assert_approx(atm_implied_volatility(&data, 100.0), 0.3);
+
+        let data = chain(
+            &[(110.0, None, None, Some(0.18)), (100.0, None, None, None)],
+            &[(100.0, None, None, Some(0.0005))],
+        );
+        assert_eq!(atm_implied_volatility(&data, 100.0), None);
+        assert_eq!(atm_implied_volatility(&chain(&[], &[]), 100.0), None);
+    }
+
+    #[test]
+    fn test_smile() {
+        let data = chain(
+            &[
+                (110.0, None, None, Some(0.18)),
+                (105.0, None, None, Some(0.0)),
+                (100.0, None, None, None),
+                (95.0, None, None, Some(0.22)),
+            ],
+            &[],
+        );
+
+        // Lowest strike first, without zero or missing volatility
+        assert_eq!(
+            smile(&data, OptionType::Call),
+            vec![(95.0, 0.22), (110.0, 0.18)]
+        );
+        assert!(smile(&data, OptionType::Put).is_empty());
     }
 }
@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, StatefulWidget, Widget};
+
+use super::block;
+use crate::theme::style;
+use crate::THEME;
+
+/// Prompt for a line of input that's parsed once entered, such as a date range
+/// or alert
+#[derive(Default)]
+pub struct PromptState {
+    input: String,
+    error_msg: Option<String>,
+}
+
+impl PromptState {
+    pub fn add_char(&mut self, c: char) {
+        self.input.push(c);
+        self.error_msg = None;
+    }
+
+    pub fn del_char(&mut self) {
+        self.input.pop();
+    }
+
+    pub fn set_error(&mut self, error_msg: String) {
+        self.error_msg = Some(error_msg);
+    }
+
+    pub fn reset(&mut self) {
+        self.input.drain(..);
+        self.error_msg = None;
+    }
+
+    /// Returns what was entered, or shows why it's invalid
+    pub fn enter<T: FromStr<Err = String>>(&mut self) -> Option<T> {
+        match self.input.parse() {
+            Ok(value) => {
+                self.reset();
+
+                Some(value)
+            }
+            Err(error) => {
+                self.input.drain(..);
+                self.error_msg = Some(error);
+
+                None
+            }
+        }
+    }
+}
+
+pub struct PromptWidget {
+    pub title: &'static str,
+    /// Shown in place of the input until something is typed
+    pub hint: &'static str,
+}
+
+impl StatefulWidget for PromptWidget {
+    type State = PromptState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let prompt = Span::styled("> ", style().fg(THEME.text_normal()));
+
+        let spans = match (&state.error_msg, state.input.is_empty()) {
+            (Some(error_msg), true) => Line::from(vec![
+                prompt,
+                Span::styled(
+                    error_msg,
+                    style().add_modifier(Modifier::BOLD).fg(THEME.loss()),
+                ),
+            ]),
+            (None, true) => Line::from(vec![
+                prompt,
+                Span::styled(self.hint, style().fg(THEME.gray())),
+            ]),
+            _ => Line::from(vec![
+                prompt,
+                Span::styled(
+                    &state.input,
+                    style()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(THEME.text_secondary()),
+                ),
+            ]),
+        };
+
+        Paragraph::new(spans)
+            .block(block::new(self.title))
+            .style(style())
+            .alignment(Alignment::Left)
+            .render(area, buf);
+    }
+}
@@ -1,5 +1,8 @@
+use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 
+use chrono::{Local, TimeZone, Utc};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::Modifier;
@@ -7,22 +10,59 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, StatefulWidget, Tabs, Widget, Wrap};
 
 use super::chart::{
-    ChartState, PricesCandlestickChart, PricesKagiChart, PricesLineChart, VolumeBarChart,
+    ChartState, OscillatorChart, Overlay, PricesCandlestickChart, PricesKagiChart, PricesLineChart,
+    PricesPointFigureChart, PricesRenkoChart, VolumeBarChart, XAxis, Zoom,
 };
 use super::chart_configuration::ChartConfigurationState;
 use super::{block, CachableWidget, CacheState, OptionsState};
-use crate::api::model::{ChartMeta, CompanyData};
+use crate::alert::{self, Alert, AlertState, Condition};
+use crate::api::model::{ChartMeta, ChartTradingPeriod, CompanyData, QuoteData};
+use crate::api::stream::Tick;
 use crate::common::*;
 use crate::draw::{add_padding, PaddingDirection};
+use crate::service::stock::Fetch;
 use crate::service::{self, Service};
 use crate::theme::style;
 use crate::{
-    DEFAULT_TIMESTAMPS, ENABLE_PRE_POST, HIDE_PREV_CLOSE, HIDE_TOGGLE, OPTS, SHOW_VOLUMES,
-    SHOW_X_LABELS, THEME, TIME_FRAME, TRUNC_PRE,
+    indicator, CUSTOM_TIME_FRAMES, DEFAULT_TIMESTAMPS, ENABLE_PRE_POST, HIDE_PREV_CLOSE,
+    HIDE_TOGGLE, OPTS, OSCILLATOR, SHOW_VOLUMES, SHOW_X_LABELS, THEME, TIME_FRAME, TRUNC_PRE,
 };
 
 const NUM_LOADING_TICKS: usize = 8;
 const ICON_LOADING_TICKS: [char; 8] = ['⣾', '⣽', '⣻', '⢿', '⡿', '⣟', '⣯', '⣷'];
+const ICON_ERROR: char = '✗';
+/// Fewest data points that can be zoomed into
+const MIN_ZOOM_POINTS: usize = 10;
+
+/// Symbol drawn over the chart to compare against, by its change over the
+/// time frame
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub symbol: String,
+    pub prices: BTreeMap<TimeFrame, Vec<Price>>,
+    pub prev_close_price: Option<f64>,
+    pub error: Option<String>,
+}
+
+impl Comparison {
+    pub fn new(symbol: String) -> Comparison {
+        Comparison {
+            symbol,
+            prices: BTreeMap::new(),
+            prev_close_price: None,
+            error: None,
+        }
+    }
+}
+
+impl Hash for Comparison {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.symbol.hash(state);
+        self.prices.hash(state);
+        self.prev_close_price.map(|f| f.to_bits()).hash(state);
+        self.error.hash(state);
+    }
+}
 
 pub struct StockState {
     pub symbol: String,
@@ -32,8 +72,11 @@ pub struct StockState {
     pub current_regular_price: f64,
     pub current_post_price: Option<f64>,
     pub prev_close_price: Option<f64>,
-    pub reg_mkt_volume: Option<String>,
-    pub prices: [Vec<Price>; 7],
+    pub reg_mkt_volume: Option<u64>,
+    pub avg_volume: Option<u64>,
+    pub prices: BTreeMap<TimeFrame, Vec<Price>>,
+    /// Close before the first price of each time frame
+    chart_prev_closes: BTreeMap<TimeFrame, f64>,
     pub time_frame: TimeFrame,
     pub show_options: bool,
     pub show_configure: bool,
@@ -43,6 +86,21 @@ pub struct StockState {
     pub prev_state_loaded: bool,
     pub chart_meta: Option<ChartMeta>,
     pub chart_state: Option<ChartState>,
+    /// Index into `prices()` of the crosshair, while cursor mode is on
+    pub cursor: Option<usize>,
+    /// Plot area of the price chart from the last render, to map the mouse
+    /// onto it
+    pub chart_area: Option<Rect>,
+    /// Column the mouse was last pressed or dragged at, for panning
+    pub drag_column: Option<u16>,
+    pub zoom: Option<Zoom>,
+    pub comparisons: Vec<Comparison>,
+    pub alerts: Vec<AlertState>,
+    pub errors: BTreeMap<Fetch, String>,
+    pub last_updated: Option<i64>,
+    /// Day volume from the last streamed tick, to work out the volume traded
+    /// between ticks
+    pub stream_day_volume: Option<u64>,
     pub cache_state: CacheState,
 }
 
@@ -64,6 +122,11 @@ impl Hash for StockState {
         self.loading_tick.hash(state);
         self.prev_state_loaded.hash(state);
         self.chart_meta.hash(state);
+        self.cursor.hash(state);
+        self.zoom.hash(state);
+        self.comparisons.hash(state);
+        self.errors.hash(state);
+        self.last_updated.hash(state);
 
         if let Some(chart_state) = self.chart_state.as_ref() {
             chart_state.hash(state);
@@ -71,9 +134,11 @@ impl Hash for StockState {
 
         // Hash globals since they affect "state" of how widget is rendered
         DEFAULT_TIMESTAMPS.read().get(&self.time_frame).hash(state);
+        CUSTOM_TIME_FRAMES.read().hash(state);
         ENABLE_PRE_POST.read().hash(state);
         HIDE_PREV_CLOSE.hash(state);
         HIDE_TOGGLE.hash(state);
+        OSCILLATOR.read().hash(state);
         SHOW_VOLUMES.read().hash(state);
         SHOW_X_LABELS.read().hash(state);
         TRUNC_PRE.hash(state);
@@ -86,8 +151,15 @@ impl StockState {
 
         let stock_service = service::stock::StockService::new(symbol.clone(), time_frame);
         let kagi_options = OPTS.kagi_options.get(&symbol).cloned().unwrap_or_default();
-
-        StockState {
+        let overlay_options = OPTS
+            .overlay_options
+            .get(&symbol)
+            .copied()
+            .unwrap_or_default();
+        let renko_options = OPTS.renko_options.get(&symbol).cloned().unwrap_or_default();
+        let pnf_options = OPTS.pnf_options.get(&symbol).cloned().unwrap_or_default();
+
+        let mut stock = StockState {
             symbol,
             chart_type,
             stock_service,
@@ -96,13 +168,18 @@ impl StockState {
             current_post_price: None,
             prev_close_price: None,
             reg_mkt_volume: None,
-            prices: [vec![], vec![], vec![], vec![], vec![], vec![], vec![]],
+            avg_volume: None,
+            prices: BTreeMap::new(),
+            chart_prev_closes: BTreeMap::new(),
             time_frame,
             show_options: false,
             show_configure: false,
             options: None,
             chart_configuration: ChartConfigurationState {
                 kagi_options,
+                overlay_options,
+                renko_options,
+                pnf_options,
                 ..Default::default()
             },
             loading_tick: 0,
@@ -110,7 +187,28 @@ impl StockState {
             chart_meta: None,
             cache_state: Default::default(),
             chart_state: None,
+            cursor: None,
+            chart_area: None,
+            drag_column: None,
+            zoom: None,
+            comparisons: vec![],
+            alerts: vec![],
+            errors: BTreeMap::new(),
+            last_updated: None,
+            stream_day_volume: None,
+        };
+
+        for symbol in OPTS.compare.iter().flatten() {
+            stock.add_comparison(Comparison::new(symbol.clone()));
         }
+
+        for alert in OPTS.alerts.iter() {
+            if alert.symbol.eq_ignore_ascii_case(&stock.symbol) {
+                stock.add_alert(alert.condition);
+            }
+        }
+
+        stock
     }
 
     pub fn symbol(&self) -> &str {
@@ -122,6 +220,8 @@ impl StockState {
 
         self.stock_service.update_time_frame(time_frame);
 
+        self.zoom.take();
+
         // Resets chart state where applicable
         self.set_chart_type(self.chart_type);
     }
@@ -129,7 +229,11 @@ impl StockState {
     pub fn prices(&self) -> impl Iterator<Item = Price> {
         let (start, end) = self.start_end();
 
-        let prices = self.prices[self.time_frame.idx()].clone();
+        let prices = self
+            .prices
+            .get(&self.time_frame)
+            .cloned()
+            .unwrap_or_default();
 
         let max_time = prices.last().map(|p| p.date).unwrap_or(end);
 
@@ -228,33 +332,189 @@ impl StockState {
 
         for update in updates {
             match update {
-                service::stock::Update::NewPrice((regular, post, vol)) => {
-                    self.current_regular_price = regular;
-                    self.current_post_price = post;
-                    self.reg_mkt_volume = Some(vol);
-                }
                 service::stock::Update::Prices((time_frame, chart_meta, prices)) => {
-                    self.prices[time_frame.idx()] = prices;
+                    self.prices.insert(time_frame, prices);
 
                     if time_frame == TimeFrame::Day1 {
                         self.prev_close_price = Some(chart_meta.chart_previous_close);
                     }
 
+                    if chart_meta.chart_previous_close > 0.0 {
+                        self.chart_prev_closes
+                            .insert(time_frame, chart_meta.chart_previous_close);
+                    }
+
                     self.chart_meta = Some(chart_meta);
+
+                    self.errors.remove(&Fetch::Prices);
+                    self.last_updated = Some(Utc::now().timestamp());
                 }
                 service::stock::Update::CompanyData(data) => {
                     self.profile = Some(*data);
+
+                    self.errors.remove(&Fetch::Company);
+                }
+                service::stock::Update::Error(fetch, error) => {
+                    self.set_error(fetch, format!("{:#}", error));
                 }
             }
         }
+
+        self.check_alerts();
     }
 
-    fn options_enabled(&self) -> bool {
-        !self.is_crypto() && !self.is_index()
+    pub fn add_alert(&mut self, condition: Condition) {
+        self.alerts.push(AlertState::new(condition));
     }
 
-    fn configure_enabled(&self) -> bool {
-        self.chart_type == ChartType::Kagi
+    pub fn remove_alert(&mut self, idx: usize) {
+        if idx < self.alerts.len() {
+            self.alerts.remove(idx);
+        }
+    }
+
+    /// Fires alerts whose condition has just been met
+    fn check_alerts(&mut self) {
+        let price = self.current_price();
+
+        for idx in 0..self.alerts.len() {
+            let condition = self.alerts[idx].condition;
+            let met = self.is_met(condition);
+
+            if self.alerts[idx].update(met) {
+                let symbol = self.symbol.clone();
+
+                alert::fire(Alert { symbol, condition }, price);
+            }
+        }
+    }
+
+    fn is_met(&self, condition: Condition) -> bool {
+        let prev_close = self
+            .profile
+            .as_ref()
+            .map(|profile| profile.price.regular_market_previous_close.price)
+            .or(self.prev_close_price);
+
+        condition.is_met(
+            self.current_price(),
+            prev_close,
+            self.reg_mkt_volume,
+            self.avg_volume,
+        )
+    }
+
+    /// Applies this stock's quote from the latest batch of quotes
+    pub fn set_quote(&mut self, quote: &QuoteData) {
+        if let Some(regular) = quote.regular_market_price {
+            self.current_regular_price = regular;
+        }
+        self.current_post_price = quote.post_market_price;
+        self.reg_mkt_volume = quote.regular_market_volume;
+        self.avg_volume = quote.average_daily_volume_3_month.or(self.avg_volume);
+
+        self.errors.remove(&Fetch::Quote);
+        self.last_updated = Some(Utc::now().timestamp());
+    }
+
+    /// Applies a real-time tick from the quote stream, extending the 1D
+    /// series with it until the next fetch replaces it. Ticks are dropped
+    /// unless they fall in the session of the loaded day, and in its regular
+    /// hours when pre / post market isn't enabled.
+    pub fn apply_tick(&mut self, tick: &Tick) {
+        let Some(period) = self
+            .chart_meta
+            .as_ref()
+            .and_then(|meta| meta.current_trading_period.as_ref())
+        else {
+            return;
+        };
+
+        let within = |period: &ChartTradingPeriod| (period.start..period.end).contains(&tick.time);
+        let regular = within(&period.regular);
+
+        if !(period.pre.start..period.post.end).contains(&tick.time)
+            || !(regular || *ENABLE_PRE_POST.read())
+        {
+            return;
+        }
+
+        if regular {
+            self.current_regular_price = tick.price;
+
+            if let Some(volume) = tick.day_volume {
+                self.reg_mkt_volume = Some(volume);
+            }
+        } else {
+            self.current_post_price = Some(tick.price);
+        }
+
+        let traded = match (self.stream_day_volume, tick.day_volume) {
+            (Some(prev), Some(volume)) => volume.saturating_sub(prev),
+            _ => 0,
+        };
+        self.stream_day_volume = tick.day_volume.or(self.stream_day_volume);
+
+        let prices = self.prices.entry(TimeFrame::Day1).or_default();
+        let minute = tick.time - tick.time.rem_euclid(60);
+
+        // Nothing to extend until the session has been fetched
+        match prices.last_mut() {
+            Some(last) if minute <= last.date => {
+                last.close = tick.price;
+                last.high = last.high.max(tick.price);
+                last.low = last.low.min(tick.price);
+                last.volume += traded;
+            }
+            Some(_) => prices.push(Price {
+                close: tick.price,
+                volume: traded,
+                high: tick.price,
+                low: tick.price,
+                open: tick.price,
+                date: minute,
+            }),
+            None => {}
+        }
+
+        self.errors.remove(&Fetch::Quote);
+        self.last_updated = Some(Utc::now().timestamp());
+    }
+
+    pub fn set_error(&mut self, fetch: Fetch, error: String) {
+        self.errors.insert(fetch, error);
+    }
+
+    pub fn clear_error(&mut self, fetch: Fetch) {
+        self.errors.remove(&fetch);
+    }
+
+    /// Error from one of the fetches that is currently failing, including
+    /// those of symbols compared against
+    pub fn error(&self) -> Option<&str> {
+        self.errors
+            .values()
+            .chain(self.comparisons.iter().filter_map(|c| c.error.as_ref()))
+            .next()
+            .map(String::as_str)
+    }
+
+    /// Describes how out of date the displayed data is while fetches are failing
+    pub fn stale_badge(&self) -> Option<String> {
+        self.error()?;
+
+        let since = self
+            .last_updated
+            .and_then(|timestamp| Local.timestamp_opt(timestamp, 0).single());
+
+        Some(match since {
+            Some(since) => format!("Stale since {}", since.format("%H:%M")),
+            None => "Failed to load".to_string(),
+        })
+    }
+
+    fn options_enabled(&self) -> bool {
+        !self.is_crypto() && !self.is_index()
     }
 
     fn is_crypto(&self) -> bool {
@@ -287,16 +547,11 @@ impl StockState {
         true
     }
 
-    pub fn toggle_configure(&mut self) -> bool {
-        if !self.configure_enabled() {
-            return false;
-        }
-
+    pub fn toggle_configure(&mut self) {
         self.show_configure = !self.show_configure;
 
-        self.chart_configuration.reset_form(self.time_frame);
-
-        true
+        self.chart_configuration
+            .reset_form(self.chart_type, self.time_frame);
     }
 
     pub fn start_end(&self) -> (i64, i64) {
@@ -403,12 +658,14 @@ impl StockState {
     }
 
     pub fn high_low(&self, data: &[Price]) -> (f64, f64) {
+        let end_price = self.end_price(data);
+
         let mut data = data.to_vec();
         data.push(Price {
-            close: self.current_price(),
-            open: self.current_price(),
-            high: self.current_price(),
-            low: self.current_price(),
+            close: end_price,
+            open: end_price,
+            high: end_price,
+            low: end_price,
             ..Default::default()
         });
         data.retain(|p| p.close.gt(&0.0));
@@ -430,13 +687,217 @@ impl StockState {
     pub fn x_bounds(&self, start: i64, end: i64, data: &[Price]) -> [f64; 2] {
         let num_points = ((end - start) / 60) as f64;
 
-        match self.time_frame {
+        let bounds = match self.time_frame {
             TimeFrame::Day1 => [0.0, num_points],
             _ => [0.0, (data.len() + 1) as f64],
+        };
+
+        if self.zoom.is_some() {
+            let range = self.visible_range(data);
+
+            [
+                range.start as f64,
+                bounds[1] - (self.num_points(data) - range.end) as f64,
+            ]
+        } else {
+            bounds
+        }
+    }
+
+    /// X-axis of the chart type being drawn. Candles are centered in their slot
+    /// and have an extra slot for the current price, where lines are drawn
+    /// from the first point after the start of the axis
+    pub fn x_axis(&self, data: &[Price]) -> XAxis {
+        let (start, end) = self.start_end();
+
+        match self.chart_type {
+            ChartType::Candlestick | ChartType::Ohlc | ChartType::HeikinAshi => {
+                let mut with_current = data.to_vec();
+                with_current.push(Price::default());
+
+                let bounds = self.x_bounds(start, end, &with_current);
+                let range_start = self.visible_range(data).start as f64;
+
+                XAxis {
+                    bounds: [range_start, range_start + bounds[1] - bounds[0]],
+                    offset: 0.5,
+                }
+            }
+            _ => XAxis {
+                bounds: self.x_bounds(start, end, data),
+                offset: 1.0,
+            },
+        }
+    }
+
+    /// Number of points the x-axis spans, which on 1D is the whole session
+    /// rather than the prices so far
+    fn num_points(&self, data: &[Price]) -> usize {
+        if self.time_frame == TimeFrame::Day1 {
+            let (start, end) = self.start_end();
+
+            ((end - start) / 60) as usize
+        } else {
+            data.len()
+        }
+    }
+
+    /// Range of points in view, which is all of them unless zoomed in. On 1D
+    /// this can extend past the end of `data`
+    pub fn visible_range(&self, data: &[Price]) -> Range<usize> {
+        let num_points = self.num_points(data);
+
+        match self.zoom {
+            Some(zoom) => zoom.range(num_points),
+            None => 0..num_points,
+        }
+    }
+
+    /// Prices in view
+    pub fn visible<'a>(&self, data: &'a [Price]) -> &'a [Price] {
+        let range = self.visible_range(data);
+
+        &data[range.start.min(data.len())..range.end.min(data.len())]
+    }
+
+    /// `min_max` of the prices in view, leaving out the current price when
+    /// zoomed into a window before it
+    pub fn visible_min_max(&self, data: &[Price]) -> (f64, f64) {
+        let visible = self.visible(data);
+
+        if self.visible_range(data).end >= data.len() {
+            return self.min_max(visible);
         }
+
+        let visible = visible
+            .iter()
+            .filter(|p| p.close.gt(&0.0))
+            .collect::<Vec<_>>();
+
+        let mut min = visible
+            .iter()
+            .map(|p| p.low)
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap_or(0.0);
+        let mut max = visible
+            .iter()
+            .map(|p| p.high)
+            .max_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap_or(1.0);
+
+        if self.time_frame == TimeFrame::Day1 && !*HIDE_PREV_CLOSE {
+            if let Some(prev_close) = self.prev_close_price {
+                min = min.min(prev_close);
+                max = max.max(prev_close);
+            }
+        }
+
+        (min, max)
     }
 
-    pub fn x_labels(&self, width: u16, start: i64, end: i64, data: &[Price]) -> Vec<Span> {
+    /// Halves the points in view, around the crosshair if it's on
+    pub fn zoom_in(&mut self) {
+        let data = self.prices().collect::<Vec<_>>();
+        let num_points = self.num_points(&data);
+        let range = self.visible_range(&data);
+
+        let len = (range.len() / 2).max(MIN_ZOOM_POINTS);
+        if len >= range.len() {
+            return;
+        }
+
+        let center = self
+            .cursor
+            .filter(|cursor| range.contains(cursor))
+            .unwrap_or(range.start + range.len() / 2);
+
+        self.zoom = Some(Zoom {
+            start: center.saturating_sub(len / 2).min(num_points - len),
+            len,
+        });
+    }
+
+    /// Doubles the points in view, until the whole time frame is shown
+    pub fn zoom_out(&mut self) {
+        let data = self.prices().collect::<Vec<_>>();
+        let num_points = self.num_points(&data);
+        let range = self.visible_range(&data);
+
+        let len = range.len() * 2;
+        if len >= num_points {
+            self.zoom.take();
+            return;
+        }
+
+        let center = range.start + range.len() / 2;
+
+        self.zoom = Some(Zoom {
+            start: center.saturating_sub(len / 2).min(num_points - len),
+            len,
+        });
+    }
+
+    pub fn reset_zoom(&mut self) {
+        self.zoom.take();
+    }
+
+    /// Moves the zoomed window by a quarter of its width
+    pub fn pan_left(&mut self) {
+        if let Some(zoom) = self.zoom {
+            self.pan_by(-((zoom.len / 4).max(1) as isize));
+        }
+    }
+
+    pub fn pan_right(&mut self) {
+        if let Some(zoom) = self.zoom {
+            self.pan_by((zoom.len / 4).max(1) as isize);
+        }
+    }
+
+    /// Moves the zoomed window by `points`, returning whether it moved
+    fn pan_by(&mut self, points: isize) -> bool {
+        let data = self.prices().collect::<Vec<_>>();
+        let num_points = self.num_points(&data);
+        let range = self.visible_range(&data);
+
+        let Some(zoom) = self.zoom.as_mut() else {
+            return false;
+        };
+
+        let start = range
+            .start
+            .saturating_add_signed(points)
+            .min(num_points - range.len());
+
+        let moved = start != range.start;
+        zoom.start = start;
+
+        moved
+    }
+
+    /// Pans the zoomed window along with the mouse while it's dragged,
+    /// returning whether it moved
+    pub fn drag_to(&mut self, column: u16) -> bool {
+        let (Some(area), Some(from)) = (self.chart_area, self.drag_column.replace(column)) else {
+            return false;
+        };
+
+        let Some(zoom) = self.zoom else {
+            return false;
+        };
+
+        let points = (from as f64 - column as f64) * zoom.len as f64 / area.width.max(1) as f64;
+
+        // Leave the remainder for the next drag, so slow drags still pan
+        if points.abs() < 1.0 {
+            self.drag_column = Some(from);
+            return false;
+        }
+
+        self.pan_by(points.round() as isize)
+    }
+
+    pub fn x_labels(&self, width: u16, start: i64, end: i64, data: &[Price]) -> Vec<Span<'_>> {
         let mut labels = vec![];
 
         let dates = if self.time_frame == TimeFrame::Day1 {
@@ -445,6 +906,9 @@ impl StockState {
             data.iter().map(|p| p.date).collect::<Vec<_>>()
         };
 
+        let range = self.visible_range(data);
+        let dates = &dates[range.start.min(dates.len())..range.end.min(dates.len())];
+
         if dates.is_empty() {
             return labels;
         }
@@ -480,21 +944,23 @@ impl StockState {
         [(min), (max)]
     }
 
-    pub fn y_labels(&self, min: f64, max: f64) -> Vec<Span> {
+    pub fn y_labels(&self, min: f64, max: f64) -> Vec<Span<'_>> {
+        // Compared symbols are measured by change, so label by change too
+        let baseline = self
+            .is_comparing()
+            .then(|| self.baseline_price(&self.prices().collect::<Vec<_>>()))
+            .flatten();
+
+        let label = |value: f64| match baseline {
+            Some(baseline) => format!("{:>+7.2}%", (value / baseline - 1.0) * 100.0),
+            None => format!("{:>8}", format_decimals(value)),
+        };
+
         if self.loaded() {
             vec![
-                Span::styled(
-                    format!("{:>8}", format_decimals(min)),
-                    style().fg(THEME.text_normal()),
-                ),
-                Span::styled(
-                    format!("{:>8}", format_decimals((min + max) / 2.0)),
-                    style().fg(THEME.text_normal()),
-                ),
-                Span::styled(
-                    format!("{:>8}", format_decimals(max)),
-                    style().fg(THEME.text_normal()),
-                ),
+                Span::styled(label(min), style().fg(THEME.text_normal())),
+                Span::styled(label((min + max) / 2.0), style().fg(THEME.text_normal())),
+                Span::styled(label(max), style().fg(THEME.text_normal())),
             ]
         } else {
             vec![
@@ -510,27 +976,58 @@ impl StockState {
             return 0.0;
         }
 
-        let baseline = if self.time_frame == TimeFrame::Day1 {
-            if let Some(prev_close) = self.prev_close_price {
-                prev_close
-            } else {
-                data.iter()
-                    .find(|p| p.close > 0.0)
-                    .map(|d| d.close)
-                    .unwrap()
-            }
+        let baseline = self.baseline_price(data).unwrap();
+
+        self.end_price(data) / baseline - 1.0
+    }
+
+    /// Price the area chart is filled from, the close before the first price in
+    /// view
+    pub fn fill_baseline(&self, data: &[Price]) -> Option<f64> {
+        if self.time_frame == TimeFrame::Day1 {
+            return self.baseline_price(data);
+        }
+
+        let range = self.visible_range(data);
+
+        data[..range.start.min(data.len())]
+            .iter()
+            .rev()
+            .find(|p| p.close > 0.0)
+            .map(|p| p.close)
+            .or_else(|| self.chart_prev_closes.get(&self.time_frame).copied())
+            .or_else(|| self.baseline_price(data))
+    }
+
+    /// Price the chart ends on, which is the current price unless viewing a
+    /// date range that's already ended
+    pub fn end_price(&self, data: &[Price]) -> f64 {
+        let last_close = || data.iter().rev().find(|p| p.close > 0.0).map(|p| p.close);
+
+        if self.time_frame.has_ended() {
+            last_close().unwrap_or_else(|| self.current_price())
         } else {
-            data.iter()
-                .find(|p| p.close > 0.0)
-                .map(|d| d.close)
-                .unwrap()
-        };
+            self.current_price()
+        }
+    }
+
+    /// Price that change is measured from, the previous close on 1D and
+    /// otherwise the first price of the time frame
+    pub fn baseline_price(&self, data: &[Price]) -> Option<f64> {
+        let first_price = || data.iter().find(|p| p.close > 0.0).map(|d| d.close);
 
-        self.current_price() / baseline - 1.0
+        if self.time_frame == TimeFrame::Day1 {
+            self.prev_close_price.or_else(first_price)
+        } else {
+            first_price()
+        }
     }
 
     pub fn loaded(&self) -> bool {
-        !self.prices[self.time_frame.idx()].is_empty() && self.current_price() > 0.0
+        self.prices
+            .get(&self.time_frame)
+            .is_some_and(|prices| !prices.is_empty())
+            && self.current_price() > 0.0
     }
 
     pub fn loading_tick(&mut self) {
@@ -549,16 +1046,267 @@ impl StockState {
         }
     }
 
+    /// Indicators to draw over the chart, lined up with `data`
+    pub fn overlays(&self, data: &[Price]) -> Vec<Overlay> {
+        let options = &self.chart_configuration.overlay_options;
+
+        let mut overlays = vec![];
+
+        if options.sma {
+            overlays.push(Overlay {
+                color: THEME.overlay_sma(),
+                values: indicator::on_closes(data, |closes| {
+                    indicator::sma(closes, options.sma_period)
+                }),
+            });
+        }
+
+        if options.ema {
+            overlays.push(Overlay {
+                color: THEME.overlay_ema(),
+                values: indicator::on_closes(data, |closes| {
+                    indicator::ema(closes, options.ema_period)
+                }),
+            });
+        }
+
+        if options.bollinger {
+            let bands = indicator::on_closes(data, |closes| {
+                indicator::bollinger(closes, options.bollinger_period, options.bollinger_std_dev)
+            });
+
+            for band in [
+                |(lower, _, _): (f64, f64, f64)| lower,
+                |(_, middle, _): (f64, f64, f64)| middle,
+                |(_, _, upper): (f64, f64, f64)| upper,
+            ] {
+                overlays.push(Overlay {
+                    color: THEME.overlay_bollinger(),
+                    values: bands.iter().map(|b| b.map(band)).collect(),
+                });
+            }
+        }
+
+        if options.vwap && self.time_frame == TimeFrame::Day1 {
+            // Anchored to the open, so pre market trading doesn't skew it
+            let start = self.regular_start_end_idx(data).0.unwrap_or_default();
+
+            let mut values = vec![None; start.min(data.len())];
+            values.extend(indicator::vwap(&data[start.min(data.len())..]));
+
+            overlays.push(Overlay {
+                color: THEME.overlay_vwap(),
+                values,
+            });
+        }
+
+        overlays.extend(self.comparison_overlays(data));
+
+        overlays
+    }
+
+    /// Adds a symbol to compare against, unless it's this one or already
+    /// added. Its prices are fetched by the app's `ComparisonService`, shared
+    /// with every other stock comparing against it
+    pub fn add_comparison(&mut self, comparison: Comparison) {
+        if comparison.symbol == self.symbol
+            || self
+                .comparisons
+                .iter()
+                .any(|c| c.symbol == comparison.symbol)
+        {
+            return;
+        }
+
+        self.comparisons.push(comparison);
+    }
+
+    pub fn clear_comparisons(&mut self) {
+        self.comparisons.clear();
+    }
+
+    pub fn set_comparison_prices(
+        &mut self,
+        symbol: &str,
+        time_frame: TimeFrame,
+        meta: &ChartMeta,
+        prices: &[Price],
+    ) {
+        if let Some(comparison) = self.comparisons.iter_mut().find(|c| c.symbol == symbol) {
+            comparison.prices.insert(time_frame, prices.to_vec());
+
+            if time_frame == TimeFrame::Day1 {
+                comparison.prev_close_price = Some(meta.chart_previous_close);
+            }
+
+            comparison.error = None;
+        }
+    }
+
+    pub fn set_comparison_error(&mut self, symbol: &str, error: String) {
+        if let Some(comparison) = self.comparisons.iter_mut().find(|c| c.symbol == symbol) {
+            comparison.error = Some(error);
+        }
+    }
+
+    /// Whether other symbols are drawn over the chart, in which case it's
+    /// labeled by change rather than price
+    pub fn is_comparing(&self) -> bool {
+        !self.comparisons.is_empty() && self.chart_type.is_time_based()
+    }
+
+    /// Each compared symbol's change from the start of the time frame, scaled
+    /// onto this stock's baseline price so they share its y-axis
+    fn comparison_overlays(&self, data: &[Price]) -> Vec<Overlay> {
+        (0..self.comparisons.len())
+            .filter_map(|idx| self.comparison_overlay(idx, data))
+            .collect()
+    }
+
+    fn comparison_overlay(&self, idx: usize, data: &[Price]) -> Option<Overlay> {
+        let comparison = &self.comparisons[idx];
+
+        let baseline = self.baseline_price(data)?;
+        let start = data.iter().position(|p| p.close > 0.0)?;
+        let round_by = self.time_frame.round_by();
+
+        let closes = comparison
+            .prices
+            .get(&self.time_frame)?
+            .iter()
+            .filter(|p| p.close > 0.0)
+            .map(|p| (p.date - p.date % round_by, p.close))
+            .collect::<HashMap<_, _>>();
+
+        // Lined up with `data`, starting from this stock's first price
+        let closes = data
+            .iter()
+            .enumerate()
+            .map(|(idx, p)| {
+                (idx >= start)
+                    .then(|| closes.get(&(p.date - p.date % round_by)).copied())
+                    .flatten()
+            })
+            .collect::<Vec<_>>();
+
+        let first_close = closes.iter().flatten().next().copied();
+        let comparison_baseline = match self.time_frame {
+            TimeFrame::Day1 => comparison.prev_close_price.or(first_close),
+            _ => first_close,
+        }?;
+
+        Some(Overlay {
+            color: THEME.comparison(idx),
+            values: closes
+                .into_iter()
+                .map(|close| close.map(|close| baseline * close / comparison_baseline))
+                .collect(),
+        })
+    }
+
     pub fn set_chart_type(&mut self, chart_type: ChartType) {
         self.chart_state.take();
 
-        if chart_type == ChartType::Kagi {
+        if !chart_type.is_time_based() {
             self.chart_state = Some(Default::default());
+            self.cursor.take();
         }
 
         self.chart_type = chart_type;
     }
 
+    /// Turns the crosshair on at the latest price, or off. Returns whether it's
+    /// now on
+    pub fn toggle_cursor(&mut self) -> bool {
+        if self.cursor.take().is_none() && self.chart_type.is_time_based() {
+            let data = self.prices().collect::<Vec<_>>();
+            let range = self.visible_range(&data);
+
+            self.cursor = data
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(idx, p)| p.close > 0.0 && range.contains(idx))
+                .map(|(idx, _)| idx);
+        }
+
+        self.cursor.is_some()
+    }
+
+    pub fn cursor_left(&mut self) {
+        if let Some(cursor) = self.cursor.as_mut() {
+            *cursor = cursor.saturating_sub(1);
+        }
+
+        self.pan_to_cursor();
+    }
+
+    pub fn cursor_right(&mut self) {
+        let len = self.prices().count();
+
+        if let Some(cursor) = self.cursor.as_mut() {
+            *cursor = (*cursor + 1).min(len.saturating_sub(1));
+        }
+
+        self.pan_to_cursor();
+    }
+
+    /// Keeps the crosshair within the zoomed window as it moves
+    fn pan_to_cursor(&mut self) {
+        let data = self.prices().collect::<Vec<_>>();
+        let range = self.visible_range(&data);
+
+        if let (Some(cursor), Some(_)) = (self.cursor, self.zoom) {
+            if cursor < range.start {
+                self.pan_by(cursor as isize - range.start as isize);
+            } else if cursor >= range.end {
+                self.pan_by((cursor + 1 - range.end) as isize);
+            }
+        }
+    }
+
+    /// Moves the crosshair to the price under the mouse, returning whether it
+    /// moved
+    pub fn cursor_to(&mut self, column: u16, row: u16) -> bool {
+        let Some(area) = self.chart_area.filter(|_| self.cursor.is_some()) else {
+            return false;
+        };
+
+        if !self.is_over_chart(column, row) {
+            return false;
+        }
+
+        let data = self.prices().collect::<Vec<_>>();
+
+        let pct = (column - area.left()) as f64 / area.width.saturating_sub(1).max(1) as f64;
+        let idx = self.x_axis(&data).idx(pct);
+
+        let cursor = Some(idx.min(data.len().saturating_sub(1)));
+
+        let moved = cursor != self.cursor;
+        self.cursor = cursor;
+
+        moved
+    }
+
+    /// Whether the mouse is over the price chart
+    pub fn is_over_chart(&self, column: u16, row: u16) -> bool {
+        self.chart_area.is_some_and(|area| {
+            column >= area.left()
+                && column < area.right()
+                && row >= area.top()
+                && row < area.bottom()
+        })
+    }
+
+    /// Crosshair index kept within `data`, which may have shrunk since it was
+    /// placed
+    pub fn cursor_idx(&self, data: &[Price]) -> Option<usize> {
+        self.cursor
+            .filter(|_| !data.is_empty())
+            .map(|cursor| cursor.min(data.len() - 1))
+    }
+
     pub fn chart_state_mut(&mut self) -> Option<&mut ChartState> {
         self.chart_state.as_mut()
     }
@@ -585,13 +1333,15 @@ impl CachableWidget<StockState> for StockWidget {
 
     fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut <Self as StatefulWidget>::State) {
         let data = state.prices().collect::<Vec<_>>();
+        let overlays = state.overlays(&data);
 
         let pct_change = state.pct_change(&data);
 
         let chart_type = state.chart_type;
         let show_x_labels = *SHOW_X_LABELS.read();
         let enable_pre_post = *ENABLE_PRE_POST.read();
-        let show_volumes = *SHOW_VOLUMES.read() && chart_type != ChartType::Kagi;
+        let show_volumes = *SHOW_VOLUMES.read() && chart_type.is_time_based();
+        let oscillator = OSCILLATOR.read().filter(|_| chart_type.is_time_based());
 
         let loaded = state.loaded();
 
@@ -663,11 +1413,11 @@ impl CachableWidget<StockState> for StockWidget {
             info_chunks[0] = add_padding(info_chunks[0], 1, PaddingDirection::Top);
 
             let (high, low) = state.high_low(&data);
-            let current_fmt = format_decimals(state.current_price());
+            let current_fmt = format_decimals(state.end_price(&data));
             let high_fmt = format_decimals(high);
             let low_fmt = format_decimals(low);
 
-            let vol = state.reg_mkt_volume.clone().unwrap_or_default();
+            let vol = state.reg_mkt_volume.map(format_volume).unwrap_or_default();
 
             let company_info = vec![
                 Line::from(vec![
@@ -711,7 +1461,7 @@ impl CachableWidget<StockState> for StockWidget {
                         style().fg(THEME.text_secondary()),
                     ),
                 ]),
-                Line::default(),
+                stale_line(state, info_chunks[0].width),
                 Line::from(vec![
                     Span::styled("Volume: ", style()),
                     Span::styled(
@@ -759,7 +1509,7 @@ impl CachableWidget<StockState> for StockWidget {
                     Line::from(vec![
                         Span::styled("P/L: ", style()),
                         Span::styled(
-                            format!("{}", format_decimals(profit_loss)),
+                            format_decimals(profit_loss),
                             style().add_modifier(Modifier::BOLD).fg(profit_loss_color),
                         ),
                         Span::styled(
@@ -814,7 +1564,7 @@ impl CachableWidget<StockState> for StockWidget {
                             } else {
                                 THEME.background()
                             })
-                            .fg(if chart_type == ChartType::Kagi {
+                            .fg(if !chart_type.is_time_based() {
                                 THEME.gray()
                             } else {
                                 THEME.text_normal()
@@ -841,16 +1591,25 @@ impl CachableWidget<StockState> for StockWidget {
 
                     right_info.push(Line::from(Span::styled(
                         "Edit     'e'",
+                        style().bg(if state.show_configure {
+                            THEME.highlight_unfocused()
+                        } else {
+                            THEME.background()
+                        }),
+                    )));
+
+                    right_info.push(Line::from(Span::styled(
+                        "Osc      'i'",
                         style()
-                            .bg(if state.show_configure {
+                            .bg(if oscillator.is_some() {
                                 THEME.highlight_unfocused()
                             } else {
                                 THEME.background()
                             })
-                            .fg(if state.configure_enabled() {
-                                THEME.text_normal()
-                            } else {
+                            .fg(if !chart_type.is_time_based() {
                                 THEME.gray()
+                            } else {
+                                THEME.text_normal()
                             }),
                     )));
                 }
@@ -879,38 +1638,45 @@ impl CachableWidget<StockState> for StockWidget {
         }
 
         // graph_chunks[0] = prices
-        // graph_chunks[1] = volume
-        let graph_chunks: Vec<Rect> = if show_volumes {
-            Layout::default()
-                .constraints([Constraint::Min(0), Constraint::Percentage(25)].as_ref())
-                .split(chunks[1])
-                .to_vec()
-        } else {
-            Layout::default()
-                .constraints([Constraint::Min(0)].as_ref())
-                .split(chunks[1])
-                .to_vec()
-        };
+        // graph_chunks[1] = (volume)
+        // graph_chunks[1 or 2] = (oscillator)
+        let mut graph_constraints = vec![Constraint::Min(0)];
+        if show_volumes {
+            graph_constraints.push(Constraint::Percentage(25));
+        }
+        if oscillator.is_some() {
+            graph_constraints.push(Constraint::Percentage(25));
+        }
+
+        let graph_chunks: Vec<Rect> = Layout::default()
+            .constraints(graph_constraints)
+            .split(chunks[1])
+            .to_vec();
 
         // Draw prices line chart
         match chart_type {
-            ChartType::Line => {
+            ChartType::Line | ChartType::Area => {
                 PricesLineChart {
                     data: &data,
                     enable_pre_post,
                     is_profit: pct_change >= 0.0,
                     is_summary: false,
+                    area: chart_type == ChartType::Area,
                     loaded,
                     show_x_labels,
+                    overlays: &overlays,
                 }
                 .render(graph_chunks[0], buf, state);
             }
-            ChartType::Candlestick => {
+            ChartType::Candlestick | ChartType::Ohlc | ChartType::HeikinAshi => {
                 PricesCandlestickChart {
                     data: &data,
                     loaded,
                     show_x_labels,
                     is_summary: false,
+                    heikin_ashi: chart_type == ChartType::HeikinAshi,
+                    ohlc: chart_type == ChartType::Ohlc,
+                    overlays: &overlays,
                 }
                 .render(graph_chunks[0], buf, state);
             }
@@ -924,6 +1690,58 @@ impl CachableWidget<StockState> for StockWidget {
                 }
                 .render(graph_chunks[0], buf, state);
             }
+            ChartType::Renko => {
+                PricesRenkoChart {
+                    data: &data,
+                    loaded,
+                    show_x_labels,
+                    is_summary: false,
+                    renko_options: state.chart_configuration.renko_options.clone(),
+                }
+                .render(graph_chunks[0], buf, state);
+            }
+            ChartType::PointFigure => {
+                PricesPointFigureChart {
+                    data: &data,
+                    loaded,
+                    show_x_labels,
+                    is_summary: false,
+                    pnf_options: state.chart_configuration.pnf_options.clone(),
+                }
+                .render(graph_chunks[0], buf, state);
+            }
+        }
+
+        // Draw readout of the price under the crosshair over the chart's top
+        // border
+        if let (true, Some(idx)) = (loaded, state.cursor_idx(&data)) {
+            let readout = cursor_readout(&data, idx, state.time_frame);
+
+            buf.set_line(
+                graph_chunks[0].left() + 1,
+                graph_chunks[0].top(),
+                &readout,
+                graph_chunks[0].width.saturating_sub(2),
+            );
+        }
+
+        // Draw legend of the compared symbols right aligned over the chart's
+        // top border, so long as it fits beside the readout
+        if loaded && state.is_comparing() {
+            let legend = comparison_legend(state, &data);
+            let readout_width = state
+                .cursor_idx(&data)
+                .map(|idx| cursor_readout(&data, idx, state.time_frame).width() as u16)
+                .unwrap_or_default();
+
+            if readout_width + legend.width() as u16 + 2 <= graph_chunks[0].width {
+                buf.set_line(
+                    graph_chunks[0].right() - 1 - legend.width() as u16,
+                    graph_chunks[0].top(),
+                    &legend,
+                    legend.width() as u16,
+                );
+            }
         }
 
         // Draw volumes bar chart
@@ -936,6 +1754,17 @@ impl CachableWidget<StockState> for StockWidget {
             .render(graph_chunks[1], buf, state);
         }
 
+        // Draw oscillator panel
+        if let Some(oscillator) = oscillator {
+            OscillatorChart {
+                data: &data,
+                loaded,
+                show_x_labels,
+                oscillator,
+            }
+            .render(graph_chunks[graph_chunks.len() - 1], buf, state);
+        }
+
         // Draw time frame tabs & optional chart scroll indicators
         {
             Block::default()
@@ -956,10 +1785,7 @@ impl CachableWidget<StockState> for StockWidget {
                 .split(chunks[2])
                 .to_vec();
 
-            let tab_names = TimeFrame::tab_names()
-                .iter()
-                .map(|s| Line::from(*s))
-                .collect();
+            let tab_names = TimeFrame::tab_names().into_iter().map(Line::from).collect();
 
             Tabs::new(tab_names)
                 .select(state.time_frame.idx())
@@ -1021,7 +1847,7 @@ pub fn get_chart_title(
     // Constraint the title length to the screen area less padding & dots if it is truncated
     let max_width = area.width as usize - padding - loading_indicator_overhead;
     if title.len() > max_width {
-        let width = (max_width - 3).max(0);
+        let width = max_width.saturating_sub(3);
         let truncated = &title[..width];
         let trimmed = truncated.trim_end();
 
@@ -1032,11 +1858,143 @@ pub fn get_chart_title(
         title = format!("{trimmed}...{trimmed_spaces}");
     }
 
-    // Add padding and the loading indicator
-    title = match state.loaded() {
-        true => format!(" {} ", title),
-        false => format!(" {} {} ", title, ICON_LOADING_TICKS[state.loading_tick]),
+    // Add padding and the loading indicator, unless loading has failed
+    title = match (state.loaded(), state.error()) {
+        (true, _) => format!(" {} ", title),
+        (false, Some(_)) => format!(" {} {} ", title, ICON_ERROR),
+        (false, None) => format!(" {} {} ", title, ICON_LOADING_TICKS[state.loading_tick]),
     };
 
     title
 }
+
+/// Each symbol on the chart in its color, with its change over the time frame
+fn comparison_legend(state: &StockState, data: &[Price]) -> Line<'static> {
+    let pct_change_span = |pct_change: Option<f64>| match pct_change {
+        Some(pct_change) => Span::styled(
+            format!(" {:+.2}%", pct_change * 100.0),
+            style().fg(if pct_change >= 0.0 {
+                THEME.profit()
+            } else {
+                THEME.loss()
+            }),
+        ),
+        None => Span::styled(" ...", style().fg(THEME.gray())),
+    };
+
+    let mut spans = vec![
+        Span::styled(" ", style()),
+        Span::styled(state.symbol.clone(), style().fg(THEME.text_primary())),
+        pct_change_span(Some(state.pct_change(data))),
+    ];
+
+    let baseline = state.baseline_price(data);
+
+    for (idx, comparison) in state.comparisons.iter().enumerate() {
+        let last_value = state
+            .comparison_overlay(idx, data)
+            .and_then(|overlay| overlay.values.into_iter().rev().flatten().next());
+
+        spans.push(Span::styled("  ", style()));
+        spans.push(Span::styled(
+            comparison.symbol.clone(),
+            style().fg(THEME.comparison(idx)),
+        ));
+        if comparison.error.is_some() {
+            spans.push(Span::styled(
+                format!(" {}", ICON_ERROR),
+                style().fg(THEME.loss()),
+            ));
+        } else {
+            spans.push(pct_change_span(
+                last_value
+                    .zip(baseline)
+                    .map(|(value, baseline)| value / baseline - 1.0),
+            ));
+        }
+    }
+
+    spans.push(Span::styled(" ", style()));
+
+    Line::from(spans)
+}
+
+/// Date, OHLC, volume and change from the prior bar of the price at `idx`
+fn cursor_readout(data: &[Price], idx: usize, time_frame: TimeFrame) -> Line<'static> {
+    let price = data[idx];
+
+    let mut spans = vec![Span::styled(
+        format!(" {} ", time_frame.format_time(price.date)),
+        style().fg(THEME.text_primary()),
+    )];
+
+    if price.close > 0.0 {
+        for (label, value) in [
+            ("O", price.open),
+            ("H", price.high),
+            ("L", price.low),
+            ("C", price.close),
+        ] {
+            spans.push(Span::styled(
+                format!(" {}: ", label),
+                style().fg(THEME.text_normal()),
+            ));
+            spans.push(Span::styled(
+                format_decimals(value),
+                style().fg(THEME.text_secondary()),
+            ));
+        }
+
+        spans.push(Span::styled(" V: ", style().fg(THEME.text_normal())));
+        spans.push(Span::styled(
+            format_volume(price.volume),
+            style().fg(THEME.text_secondary()),
+        ));
+
+        let prior_close = data[..idx].iter().rev().find(|p| p.close > 0.0);
+
+        if let Some(prior_close) = prior_close {
+            let pct_change = price.close / prior_close.close - 1.0;
+
+            spans.push(Span::styled(
+                format!("  {:.2}%", pct_change * 100.0),
+                style().fg(if pct_change >= 0.0 {
+                    THEME.profit()
+                } else {
+                    THEME.loss()
+                }),
+            ));
+        }
+    } else {
+        spans.push(Span::styled(" No data", style().fg(THEME.gray())));
+    }
+
+    spans.push(Span::styled(" ", style()));
+
+    Line::from(spans)
+}
+
+/// Stale badge followed by as much of the error as fits within `width`
+fn stale_line(state: &StockState, width: u16) -> Line<'static> {
+    let (Some(badge), Some(error)) = (state.stale_badge(), state.error()) else {
+        return Line::default();
+    };
+
+    let max_len = (width as usize).saturating_sub(badge.chars().count() + 2);
+
+    let error = if error.chars().count() > max_len {
+        let truncated = error
+            .chars()
+            .take(max_len.saturating_sub(3))
+            .collect::<String>();
+
+        format!("{}...", truncated.trim_end())
+    } else {
+        error.to_string()
+    };
+
+    Line::from(vec![
+        Span::styled(badge, style().add_modifier(Modifier::BOLD).fg(THEME.loss())),
+        Span::styled(format!(": {}", error), style().fg(THEME.text_secondary())),
+    ])
+}
@@ -4,10 +4,13 @@ use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, StatefulWidget, Widget};
 
-use super::chart::{PricesCandlestickChart, PricesKagiChart, PricesLineChart, VolumeBarChart};
+use super::chart::{
+    PricesCandlestickChart, PricesKagiChart, PricesLineChart, PricesPointFigureChart,
+    PricesRenkoChart, VolumeBarChart,
+};
 use super::stock::StockState;
 use super::{CachableWidget, CacheState};
-use crate::common::{format_decimals, ChartType};
+use crate::common::{format_decimals, format_volume, ChartType};
 use crate::draw::{add_padding, PaddingDirection};
 use crate::theme::style;
 use crate::widget::stock;
@@ -30,12 +33,13 @@ impl CachableWidget<StockState> for StockSummaryWidget {
 
     fn render(self, mut area: Rect, buf: &mut Buffer, state: &mut <Self as StatefulWidget>::State) {
         let data = state.prices().collect::<Vec<_>>();
+        let overlays = state.overlays(&data);
 
         let pct_change = state.pct_change(&data);
 
         let chart_type = state.chart_type;
         let enable_pre_post = *ENABLE_PRE_POST.read();
-        let show_volumes = *SHOW_VOLUMES.read() && chart_type != ChartType::Kagi;
+        let show_volumes = *SHOW_VOLUMES.read() && chart_type.is_time_based();
 
         let loaded = state.loaded();
 
@@ -64,11 +68,11 @@ impl CachableWidget<StockState> for StockSummaryWidget {
             layout[0] = add_padding(layout[0], 2, PaddingDirection::Right);
 
             let (high, low) = state.high_low(&data);
-            let current_fmt = format_decimals(state.current_price());
+            let current_fmt = format_decimals(state.end_price(&data));
             let high_fmt = format_decimals(high);
             let low_fmt = format_decimals(low);
 
-            let vol = state.reg_mkt_volume.clone().unwrap_or_default();
+            let vol = state.reg_mkt_volume.map(format_volume).unwrap_or_default();
 
             let prices = vec![
                 Line::from(vec![
@@ -98,7 +102,10 @@ impl CachableWidget<StockState> for StockSummaryWidget {
                         style().fg(THEME.text_secondary()),
                     ),
                 ]),
-                Line::default(),
+                Line::from(Span::styled(
+                    state.stale_badge().unwrap_or_default(),
+                    style().add_modifier(Modifier::BOLD).fg(THEME.loss()),
+                )),
                 Line::from(vec![
                     Span::styled("Volume: ", style().fg(THEME.text_normal())),
                     Span::styled(
@@ -150,23 +157,28 @@ impl CachableWidget<StockState> for StockSummaryWidget {
 
         // Draw prices line chart
         match chart_type {
-            ChartType::Line => {
+            ChartType::Line | ChartType::Area => {
                 PricesLineChart {
                     data: &data,
                     enable_pre_post,
                     is_profit: pct_change >= 0.0,
                     is_summary: true,
+                    area: chart_type == ChartType::Area,
                     loaded,
                     show_x_labels: false,
+                    overlays: &overlays,
                 }
                 .render(graph_chunks[0], buf, state);
             }
-            ChartType::Candlestick => {
+            ChartType::Candlestick | ChartType::Ohlc | ChartType::HeikinAshi => {
                 PricesCandlestickChart {
                     data: &data,
                     loaded,
                     show_x_labels: false,
                     is_summary: true,
+                    heikin_ashi: chart_type == ChartType::HeikinAshi,
+                    ohlc: chart_type == ChartType::Ohlc,
+                    overlays: &overlays,
                 }
                 .render(graph_chunks[0], buf, state);
             }
@@ -180,6 +192,26 @@ impl CachableWidget<StockState> for StockSummaryWidget {
                 }
                 .render(graph_chunks[0], buf, state);
             }
+            ChartType::Renko => {
+                PricesRenkoChart {
+                    data: &data,
+                    loaded,
+                    show_x_labels: false,
+                    is_summary: true,
+                    renko_options: state.chart_configuration.renko_options.clone(),
+                }
+                .render(graph_chunks[0], buf, state);
+            }
+            ChartType::PointFigure => {
+                PricesPointFigureChart {
+                    data: &data,
+                    loaded,
+                    show_x_labels: false,
+                    is_summary: true,
+                    pnf_options: state.chart_configuration.pnf_options.clone(),
+                }
+                .render(graph_chunks[0], buf, state);
+            }
         }
 
         // Draw volumes bar chart